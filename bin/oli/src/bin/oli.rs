@@ -77,10 +77,18 @@ async fn main() -> Result<()> {
             let cmd = oli::commands::cp::cli(new_cmd("ocp")?);
             oli::commands::cp::main(&cmd.get_matches()).await?;
         }
+        Some("odu") => {
+            let cmd = oli::commands::du::cli(new_cmd("odu")?);
+            oli::commands::du::main(&cmd.get_matches()).await?;
+        }
         Some("ols") => {
             let cmd = oli::commands::ls::cli(new_cmd("ols")?);
             oli::commands::ls::main(&cmd.get_matches()).await?;
         }
+        Some("opurge") => {
+            let cmd = oli::commands::purge::cli(new_cmd("opurge")?);
+            oli::commands::purge::main(&cmd.get_matches()).await?;
+        }
         Some("orm") => {
             let cmd = oli::commands::rm::cli(new_cmd("orm")?);
             oli::commands::rm::main(&cmd.get_matches()).await?;
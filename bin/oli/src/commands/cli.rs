@@ -24,7 +24,9 @@ pub async fn main(args: &ArgMatches) -> Result<()> {
     match args.subcommand() {
         Some(("cat", sub_args)) => super::cat::main(sub_args).await?,
         Some(("cp", sub_args)) => super::cp::main(sub_args).await?,
+        Some(("du", sub_args)) => super::du::main(sub_args).await?,
         Some(("ls", sub_args)) => super::ls::main(sub_args).await?,
+        Some(("purge", sub_args)) => super::purge::main(sub_args).await?,
         Some(("rm", sub_args)) => super::rm::main(sub_args).await?,
         Some(("stat", sub_args)) => super::stat::main(sub_args).await?,
         _ => return Err(anyhow!("not handled")),
@@ -41,7 +43,9 @@ pub fn cli(cmd: Command) -> Command {
     cmd.about("OpenDAL Command Line Interface")
         .subcommand(super::cat::cli(new_cmd("cat")))
         .subcommand(super::cp::cli(new_cmd("cp")))
+        .subcommand(super::du::cli(new_cmd("du")))
         .subcommand(super::ls::cli(new_cmd("ls")))
+        .subcommand(super::purge::cli(new_cmd("purge")))
         .subcommand(super::rm::cli(new_cmd("rm")))
         .subcommand(super::stat::cli(new_cmd("stat")))
 }
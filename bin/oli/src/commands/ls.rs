@@ -40,6 +40,14 @@ pub async fn main(args: &ArgMatches) -> Result<()> {
         .ok_or_else(|| anyhow!("missing target"))?;
     let (op, path) = cfg.parse_location(target)?;
 
+    if path.contains(['*', '?', '[']) {
+        let mut ds = op.glob(&path).await?;
+        while let Some(de) = ds.try_next().await? {
+            println!("{}", de.path());
+        }
+        return Ok(());
+    }
+
     if !recursive {
         let mut ds = op.lister(&path).await?;
         while let Some(de) = ds.try_next().await? {
@@ -56,12 +64,18 @@ pub async fn main(args: &ArgMatches) -> Result<()> {
 }
 
 pub fn cli(cmd: Command) -> Command {
-    cmd.about("ls").arg(Arg::new("target").required(true)).arg(
-        Arg::new("recursive")
-            .required(false)
-            .long("recursive")
-            .short('r')
-            .help("List recursively")
-            .action(ArgAction::SetTrue),
-    )
+    cmd.about("ls")
+        .arg(
+            Arg::new("target")
+                .required(true)
+                .help("The dir to list, or a glob pattern like `s3:/data/*.parquet`"),
+        )
+        .arg(
+            Arg::new("recursive")
+                .required(false)
+                .long("recursive")
+                .short('r')
+                .help("List recursively")
+                .action(ArgAction::SetTrue),
+        )
 }
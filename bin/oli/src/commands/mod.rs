@@ -36,6 +36,8 @@
 pub mod cat;
 pub mod cli;
 pub mod cp;
+pub mod du;
 pub mod ls;
+pub mod purge;
 pub mod rm;
 pub mod stat;
@@ -0,0 +1,143 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use clap::Arg;
+use clap::ArgAction;
+use clap::ArgMatches;
+use clap::Command;
+use futures::TryStreamExt;
+
+use crate::config::Config;
+
+pub async fn main(args: &ArgMatches) -> Result<()> {
+    let config_path = args
+        .get_one::<PathBuf>("config")
+        .ok_or_else(|| anyhow!("missing config path"))?;
+    let cfg = Config::load(config_path)?;
+
+    let older_than = *args
+        .get_one::<Duration>("older-than")
+        .ok_or_else(|| anyhow!("missing older-than"))?;
+    let dry_run = args.get_flag("dry-run");
+
+    let target = args
+        .get_one::<String>("target")
+        .ok_or_else(|| anyhow!("missing target"))?;
+    let (op, path) = cfg.parse_location(target)?;
+
+    let now = SystemTime::now();
+    let mut failed = 0;
+    let mut uploads = op.list_uploads(&path).await?;
+    while let Some(upload) = uploads.try_next().await? {
+        // Uploads without initiated time are kept since we can't tell their age.
+        let Some(initiated) = upload.initiated() else {
+            continue;
+        };
+        let age = now
+            .duration_since(SystemTime::from(initiated))
+            .unwrap_or_default();
+        if age < older_than {
+            continue;
+        }
+
+        println!("Abort: {}\t{}", upload.path(), upload.upload_id());
+        if dry_run {
+            continue;
+        }
+        // Keep purging other uploads, failures are reported at the end.
+        if let Err(err) = op.abort_upload(upload.path(), upload.upload_id()).await {
+            eprintln!("Failed: {}\t{}\t{err}", upload.path(), upload.upload_id());
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        return Err(anyhow!("failed to abort {failed} uploads"));
+    }
+    Ok(())
+}
+
+pub fn cli(cmd: Command) -> Command {
+    cmd.about("abort incomplete uploads older than given age")
+        .arg(Arg::new("target").required(true))
+        .arg(
+            Arg::new("older-than")
+                .required(true)
+                .long("older-than")
+                .help("Age of uploads to abort, like `30m`, `12h` or `7d`")
+                .value_parser(parse_age),
+        )
+        .arg(
+            Arg::new("dry-run")
+                .required(false)
+                .long("dry-run")
+                .help("Only print uploads that would be aborted")
+                .action(ArgAction::SetTrue),
+        )
+}
+
+/// Parse age like `90s`, `30m`, `12h` or `7d`, plain numbers are seconds.
+fn parse_age(s: &str) -> Result<Duration> {
+    let (num, unit) = match s.find(|c: char| !c.is_ascii_digit()) {
+        Some(idx) => s.split_at(idx),
+        None => (s, "s"),
+    };
+    let num: u64 = num.parse().map_err(|_| anyhow!("invalid age: {s}"))?;
+    let factor = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        _ => return Err(anyhow!("invalid age unit: {s}")),
+    };
+    let secs = num
+        .checked_mul(factor)
+        .ok_or_else(|| anyhow!("invalid age: {s}"))?;
+    Ok(Duration::from_secs(secs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_age() {
+        let cases = vec![
+            ("90", Some(90)),
+            ("90s", Some(90)),
+            ("30m", Some(30 * 60)),
+            ("12h", Some(12 * 60 * 60)),
+            ("7d", Some(7 * 24 * 60 * 60)),
+            ("7w", None),
+            ("999999999999999999d", None),
+            ("99999999999999999999", None),
+            ("d", None),
+            ("", None),
+        ];
+
+        for (input, expected) in cases {
+            let actual = parse_age(input).ok().map(|v| v.as_secs());
+            assert_eq!(actual, expected, "{input}");
+        }
+    }
+}
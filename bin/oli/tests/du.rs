@@ -0,0 +1,54 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use std::fs;
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+
+#[tokio::test]
+async fn test_basic_du() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    fs::create_dir(dir.path().join("sub"))?;
+    fs::write(dir.path().join("sub").join("dst_1.txt"), "hello")?;
+    fs::write(dir.path().join("sub").join("dst_2.txt"), "hello")?;
+    fs::write(dir.path().join("dst_3.txt"), "hello")?;
+
+    let mut cmd = Command::cargo_bin("oli")?;
+
+    let current_dir = dir.path().to_string_lossy().to_string() + "/";
+
+    cmd.arg("du").arg(&current_dir);
+    let res = cmd.assert().success();
+    let output = res.get_output().stdout.clone();
+
+    let output_stdout = String::from_utf8(output)?;
+
+    let lines: Vec<&str> = output_stdout.lines().collect();
+    assert!(lines
+        .iter()
+        .any(|l| l.starts_with("10\t2\t") && l.ends_with("/sub/")));
+    assert!(lines
+        .iter()
+        .any(|l| l.starts_with("5\t1\t") && l.ends_with("/dst_3.txt")));
+    assert_eq!(
+        lines.last(),
+        Some(&format!("15\t3\t{current_dir}").as_str())
+    );
+
+    Ok(())
+}
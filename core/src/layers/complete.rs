@@ -366,6 +366,63 @@ impl<A: Accessor> LayeredAccessor for CompleteAccessor<A> {
                 ),
             ));
         }
+        if args.offset().is_some() && !capability.write_with_offset {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with offset",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.offset().is_some() && args.append() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "write with offset can't be used together with append",
+            ));
+        }
+        if args.tags().is_some() && !capability.write_with_tags {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with tags",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.if_not_exists() && !capability.write_with_if_not_exists {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with if not exists",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.storage_class().is_some() && !capability.write_with_storage_class {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with storage class",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.resume().is_some() && !capability.write_with_resume {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with resume",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.resume().is_some() && args.append() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "write with resume can't be used together with append",
+            ));
+        }
 
         // Calculate buffer size.
         let buffer_size = args.buffer().map(|mut size| {
@@ -432,6 +489,9 @@ impl<A: Accessor> LayeredAccessor for CompleteAccessor<A> {
         if !capability.delete {
             return Err(self.new_unsupported_error(Operation::Delete));
         }
+        if args.recursive() && !capability.delete_with_recursive {
+            return Err(self.new_unsupported_error(Operation::Delete));
+        }
 
         self.inner().delete(path, args).await
     }
@@ -451,7 +511,38 @@ impl<A: Accessor> LayeredAccessor for CompleteAccessor<A> {
             return Err(self.new_unsupported_error(Operation::Batch));
         }
 
-        self.inner().batch(args).await
+        for (_, op) in args.operation() {
+            let supported = match op {
+                BatchOperation::Delete(_) => capability.batch_delete,
+                BatchOperation::Stat(_) => capability.batch_stat,
+                BatchOperation::Copy(_, _) => capability.batch_copy,
+            };
+            if !supported {
+                return Err(self
+                    .new_unsupported_error(Operation::Batch)
+                    .with_context("batch_operation", op.operation().into_static()));
+            }
+        }
+
+        let rp = self.inner().batch(args).await?;
+        let results = rp
+            .into_results()
+            .into_iter()
+            .map(|(path, result)| {
+                let result = result.map(|reply| match reply {
+                    BatchedReply::Stat(rp) => rp
+                        .map_metadata(|m| {
+                            let bit = m.metakey();
+                            m.with_metakey(bit | Metakey::Complete)
+                        })
+                        .into(),
+                    reply => reply,
+                });
+                (path, result)
+            })
+            .collect();
+
+        Ok(RpBatch::new(results))
     }
 
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
@@ -459,10 +550,117 @@ impl<A: Accessor> LayeredAccessor for CompleteAccessor<A> {
         if !capability.presign {
             return Err(self.new_unsupported_error(Operation::Presign));
         }
+        if let PresignOperation::WritePart(_) = args.operation() {
+            if !capability.presign_write_multipart {
+                return Err(self.new_unsupported_error(Operation::Presign));
+            }
+        }
 
         self.inner.presign(path, args).await
     }
 
+    async fn summary(&self, path: &str, args: OpSummary) -> Result<RpSummary> {
+        let capability = self.meta.full_capability();
+        if !capability.summary {
+            return Err(self.new_unsupported_error(Operation::Summary));
+        }
+
+        self.inner.summary(path, args).await
+    }
+
+    async fn watch(&self, path: &str, args: OpWatch) -> Result<(RpWatch, oio::Watcher)> {
+        let capability = self.meta.full_capability();
+        if !capability.watch {
+            return Err(self.new_unsupported_error(Operation::Watch));
+        }
+
+        self.inner.watch(path, args).await
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        let capability = self.meta.full_capability();
+        if !capability.get_tags {
+            return Err(self.new_unsupported_error(Operation::GetTags));
+        }
+
+        self.inner.get_tags(path, args).await
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        let capability = self.meta.full_capability();
+        if !capability.set_tags {
+            return Err(self.new_unsupported_error(Operation::SetTags));
+        }
+
+        self.inner.set_tags(path, args).await
+    }
+
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        let capability = self.meta.full_capability();
+        if !capability.restore {
+            return Err(self.new_unsupported_error(Operation::Restore));
+        }
+
+        self.inner.restore(path, args).await
+    }
+
+    async fn initiate_multipart(
+        &self,
+        path: &str,
+        args: OpInitiateMultipart,
+    ) -> Result<RpInitiateMultipart> {
+        let capability = self.meta.full_capability();
+        if !capability.presign_write_multipart {
+            return Err(self.new_unsupported_error(Operation::InitiateMultipart));
+        }
+
+        self.inner.initiate_multipart(path, args).await
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let capability = self.meta.full_capability();
+        if !capability.presign_write_multipart {
+            return Err(self.new_unsupported_error(Operation::CompleteMultipart));
+        }
+
+        self.inner.complete_multipart(path, args).await
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        let capability = self.meta.full_capability();
+        if !capability.presign_post {
+            return Err(self.new_unsupported_error(Operation::PresignPost));
+        }
+
+        self.inner.presign_post(path, args).await
+    }
+
+    async fn list_uploads(
+        &self,
+        path: &str,
+        args: OpListUploads,
+    ) -> Result<(RpListUploads, oio::UploadPager)> {
+        let capability = self.meta.full_capability();
+        if !capability.list_uploads {
+            return Err(self.new_unsupported_error(Operation::ListUploads));
+        }
+
+        self.inner.list_uploads(path, args).await
+    }
+
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        let capability = self.meta.full_capability();
+        if !capability.abort_upload {
+            return Err(self.new_unsupported_error(Operation::AbortUpload));
+        }
+
+        self.inner.abort_upload(path, args).await
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         let capability = self.meta.full_capability();
         if !capability.create_dir || !capability.blocking {
@@ -491,6 +689,48 @@ impl<A: Accessor> LayeredAccessor for CompleteAccessor<A> {
                 ),
             ));
         }
+        if args.offset().is_some() && !capability.write_with_offset {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with offset",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.offset().is_some() && args.append() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "write with offset can't be used together with append",
+            ));
+        }
+        if args.tags().is_some() && !capability.write_with_tags {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with tags",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.if_not_exists() && !capability.write_with_if_not_exists {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with if not exists",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.storage_class().is_some() && !capability.write_with_storage_class {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                &format!(
+                    "service {} doesn't support operation write with storage class",
+                    self.info().scheme()
+                ),
+            ));
+        }
 
         self.inner
             .blocking_write(path, args)
@@ -534,6 +774,9 @@ impl<A: Accessor> LayeredAccessor for CompleteAccessor<A> {
         if !capability.delete || !capability.blocking {
             return Err(self.new_unsupported_error(Operation::BlockingDelete));
         }
+        if args.recursive() && !capability.delete_with_recursive {
+            return Err(self.new_unsupported_error(Operation::BlockingDelete));
+        }
 
         self.inner().blocking_delete(path, args)
     }
@@ -729,6 +972,10 @@ where
 
         Poll::Ready(Ok(()))
     }
+
+    fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        self.inner.as_ref().and_then(|w| w.checkpoint())
+    }
 }
 
 impl<W> oio::BlockingWrite for CompleteWriter<W>
@@ -818,6 +1065,18 @@ mod tests {
             Ok((RpList {}, Box::new(())))
         }
 
+        async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+            let results = args
+                .into_operation()
+                .into_iter()
+                .map(|(path, _)| {
+                    let rp = RpStat::new(Metadata::new(EntryMode::Unknown));
+                    (path, Ok(rp.into()))
+                })
+                .collect();
+            Ok(RpBatch::new(results))
+        }
+
         async fn presign(&self, _: &str, _: OpPresign) -> Result<RpPresign> {
             Ok(RpPresign::new(PresignedRequest::new(
                 HttpMethod::POST,
@@ -967,4 +1226,27 @@ mod tests {
         let res = op.presign_read("path", Duration::from_secs(1)).await;
         assert!(res.is_ok())
     }
+
+    #[tokio::test]
+    async fn test_batch() {
+        let op = new_test_operator(Capability {
+            batch: true,
+            batch_delete: true,
+            ..Default::default()
+        });
+        let ops = vec![("path".to_string(), OpStat::new().into())];
+        let res = op.inner().batch(OpBatch::new(ops)).await;
+        assert!(res.is_err());
+        assert_eq!(res.err().unwrap().kind(), ErrorKind::Unsupported);
+
+        let op = new_test_operator(Capability {
+            batch: true,
+            batch_stat: true,
+            ..Default::default()
+        });
+        let res = op.stat_many(vec!["path".to_string()]).await;
+        assert!(res.is_ok());
+        let meta = res.unwrap().remove(0).1.expect("stat must succeed");
+        assert!(meta.metakey().contains(Metakey::Complete));
+    }
 }
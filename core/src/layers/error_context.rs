@@ -238,6 +238,104 @@ impl<A: Accessor> LayeredAccessor for ErrorContextAccessor<A> {
         })
     }
 
+    async fn summary(&self, path: &str, args: OpSummary) -> Result<RpSummary> {
+        self.inner.summary(path, args).await.map_err(|err| {
+            err.with_operation(Operation::Summary)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn watch(&self, path: &str, args: OpWatch) -> Result<(RpWatch, oio::Watcher)> {
+        self.inner.watch(path, args).await.map_err(|err| {
+            err.with_operation(Operation::Watch)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        self.inner.get_tags(path, args).await.map_err(|err| {
+            err.with_operation(Operation::GetTags)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        self.inner.set_tags(path, args).await.map_err(|err| {
+            err.with_operation(Operation::SetTags)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        self.inner.restore(path, args).await.map_err(|err| {
+            err.with_operation(Operation::Restore)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn initiate_multipart(
+        &self,
+        path: &str,
+        args: OpInitiateMultipart,
+    ) -> Result<RpInitiateMultipart> {
+        self.inner
+            .initiate_multipart(path, args)
+            .await
+            .map_err(|err| {
+                err.with_operation(Operation::InitiateMultipart)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        self.inner
+            .complete_multipart(path, args)
+            .await
+            .map_err(|err| {
+                err.with_operation(Operation::CompleteMultipart)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        self.inner.presign_post(path, args).await.map_err(|err| {
+            err.with_operation(Operation::PresignPost)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn list_uploads(
+        &self,
+        path: &str,
+        args: OpListUploads,
+    ) -> Result<(RpListUploads, oio::UploadPager)> {
+        self.inner.list_uploads(path, args).await.map_err(|err| {
+            err.with_operation(Operation::ListUploads)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        self.inner.abort_upload(path, args).await.map_err(|err| {
+            err.with_operation(Operation::AbortUpload)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.inner.blocking_create_dir(path, args).map_err(|err| {
             err.with_operation(Operation::BlockingCreateDir)
@@ -430,6 +528,10 @@ impl<T: oio::Write> oio::Write for ErrorContextWrapper<T> {
                 .with_context("path", &self.path)
         })
     }
+
+    fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        self.inner.checkpoint()
+    }
 }
 
 impl<T: oio::BlockingWrite> oio::BlockingWrite for ErrorContextWrapper<T> {
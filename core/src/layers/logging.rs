@@ -644,6 +644,246 @@ impl<A: Accessor> LayeredAccessor for LoggingAccessor<A> {
             .await
     }
 
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        debug!(
+            target: LOGGING_TARGET,
+            "service={} operation={} path={} -> started",
+            self.ctx.scheme,
+            Operation::Restore,
+            path
+        );
+
+        self.inner
+            .restore(path, args)
+            .await
+            .map(|v| {
+                debug!(
+                    target: LOGGING_TARGET,
+                    "service={} operation={} path={} -> finished: {v:?}",
+                    self.ctx.scheme,
+                    Operation::Restore,
+                    path
+                );
+                v
+            })
+            .map_err(|err| {
+                if let Some(lvl) = self.ctx.error_level(&err) {
+                    log!(
+                        target: LOGGING_TARGET,
+                        lvl,
+                        "service={} operation={} path={} -> {}",
+                        self.ctx.scheme,
+                        Operation::Restore,
+                        path,
+                        self.ctx.error_print(&err)
+                    );
+                }
+                err
+            })
+    }
+
+    async fn initiate_multipart(
+        &self,
+        path: &str,
+        args: OpInitiateMultipart,
+    ) -> Result<RpInitiateMultipart> {
+        debug!(
+            target: LOGGING_TARGET,
+            "service={} operation={} path={} -> started",
+            self.ctx.scheme,
+            Operation::InitiateMultipart,
+            path
+        );
+
+        self.inner
+            .initiate_multipart(path, args)
+            .await
+            .map(|v| {
+                debug!(
+                    target: LOGGING_TARGET,
+                    "service={} operation={} path={} -> finished: {v:?}",
+                    self.ctx.scheme,
+                    Operation::InitiateMultipart,
+                    path
+                );
+                v
+            })
+            .map_err(|err| {
+                if let Some(lvl) = self.ctx.error_level(&err) {
+                    log!(
+                        target: LOGGING_TARGET,
+                        lvl,
+                        "service={} operation={} path={} -> {}",
+                        self.ctx.scheme,
+                        Operation::InitiateMultipart,
+                        path,
+                        self.ctx.error_print(&err)
+                    );
+                }
+                err
+            })
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        debug!(
+            target: LOGGING_TARGET,
+            "service={} operation={} path={} -> started",
+            self.ctx.scheme,
+            Operation::CompleteMultipart,
+            path
+        );
+
+        self.inner
+            .complete_multipart(path, args)
+            .await
+            .map(|v| {
+                debug!(
+                    target: LOGGING_TARGET,
+                    "service={} operation={} path={} -> finished: {v:?}",
+                    self.ctx.scheme,
+                    Operation::CompleteMultipart,
+                    path
+                );
+                v
+            })
+            .map_err(|err| {
+                if let Some(lvl) = self.ctx.error_level(&err) {
+                    log!(
+                        target: LOGGING_TARGET,
+                        lvl,
+                        "service={} operation={} path={} -> {}",
+                        self.ctx.scheme,
+                        Operation::CompleteMultipart,
+                        path,
+                        self.ctx.error_print(&err)
+                    );
+                }
+                err
+            })
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        debug!(
+            target: LOGGING_TARGET,
+            "service={} operation={} path={} -> started",
+            self.ctx.scheme,
+            Operation::PresignPost,
+            path
+        );
+
+        self.inner
+            .presign_post(path, args)
+            .await
+            .map(|v| {
+                debug!(
+                    target: LOGGING_TARGET,
+                    "service={} operation={} path={} -> finished: {v:?}",
+                    self.ctx.scheme,
+                    Operation::PresignPost,
+                    path
+                );
+                v
+            })
+            .map_err(|err| {
+                if let Some(lvl) = self.ctx.error_level(&err) {
+                    log!(
+                        target: LOGGING_TARGET,
+                        lvl,
+                        "service={} operation={} path={} -> {}",
+                        self.ctx.scheme,
+                        Operation::PresignPost,
+                        path,
+                        self.ctx.error_print(&err)
+                    );
+                }
+                err
+            })
+    }
+
+    async fn list_uploads(
+        &self,
+        path: &str,
+        args: OpListUploads,
+    ) -> Result<(RpListUploads, oio::UploadPager)> {
+        debug!(
+            target: LOGGING_TARGET,
+            "service={} operation={} path={} -> started",
+            self.ctx.scheme,
+            Operation::ListUploads,
+            path
+        );
+
+        self.inner
+            .list_uploads(path, args)
+            .await
+            .map(|(rp, p)| {
+                debug!(
+                    target: LOGGING_TARGET,
+                    "service={} operation={} path={} -> start listing uploads",
+                    self.ctx.scheme,
+                    Operation::ListUploads,
+                    path
+                );
+                (rp, p)
+            })
+            .map_err(|err| {
+                if let Some(lvl) = self.ctx.error_level(&err) {
+                    log!(
+                        target: LOGGING_TARGET,
+                        lvl,
+                        "service={} operation={} path={} -> {}",
+                        self.ctx.scheme,
+                        Operation::ListUploads,
+                        path,
+                        self.ctx.error_print(&err)
+                    );
+                }
+                err
+            })
+    }
+
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        debug!(
+            target: LOGGING_TARGET,
+            "service={} operation={} path={} -> started",
+            self.ctx.scheme,
+            Operation::AbortUpload,
+            path
+        );
+
+        self.inner
+            .abort_upload(path, args)
+            .await
+            .map(|v| {
+                debug!(
+                    target: LOGGING_TARGET,
+                    "service={} operation={} path={} -> finished: {v:?}",
+                    self.ctx.scheme,
+                    Operation::AbortUpload,
+                    path
+                );
+                v
+            })
+            .map_err(|err| {
+                if let Some(lvl) = self.ctx.error_level(&err) {
+                    log!(
+                        target: LOGGING_TARGET,
+                        lvl,
+                        "service={} operation={} path={} -> {}",
+                        self.ctx.scheme,
+                        Operation::AbortUpload,
+                        path,
+                        self.ctx.error_print(&err)
+                    );
+                }
+                err
+            })
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         debug!(
             target: LOGGING_TARGET,
@@ -1332,6 +1572,10 @@ impl<W: oio::Write> oio::Write for LoggingWriter<W> {
         }
     }
 
+    fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        self.inner.checkpoint()
+    }
+
     fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
         match ready!(self.inner.poll_close(cx)) {
             Ok(_) => {
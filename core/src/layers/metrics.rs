@@ -165,6 +165,24 @@ struct MetricsHandler {
     requests_total_batch: Counter,
     requests_duration_seconds_batch: Histogram,
 
+    requests_total_restore: Counter,
+    requests_duration_seconds_restore: Histogram,
+
+    requests_total_initiate_multipart: Counter,
+    requests_duration_seconds_initiate_multipart: Histogram,
+
+    requests_total_complete_multipart: Counter,
+    requests_duration_seconds_complete_multipart: Histogram,
+
+    requests_total_presign_post: Counter,
+    requests_duration_seconds_presign_post: Histogram,
+
+    requests_total_list_uploads: Counter,
+    requests_duration_seconds_list_uploads: Histogram,
+
+    requests_total_abort_upload: Counter,
+    requests_duration_seconds_abort_upload: Histogram,
+
     requests_total_blocking_create: Counter,
     requests_duration_seconds_blocking_create: Histogram,
 
@@ -301,6 +319,72 @@ impl MetricsHandler {
                 LABEL_OPERATION => Operation::Batch.into_static(),
             ),
 
+            requests_total_restore: register_counter!(
+                METRIC_REQUESTS_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::Restore.into_static(),
+            ),
+            requests_duration_seconds_restore: register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::Restore.into_static(),
+            ),
+
+            requests_total_initiate_multipart: register_counter!(
+                METRIC_REQUESTS_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::InitiateMultipart.into_static(),
+            ),
+            requests_duration_seconds_initiate_multipart: register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::InitiateMultipart.into_static(),
+            ),
+
+            requests_total_complete_multipart: register_counter!(
+                METRIC_REQUESTS_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::CompleteMultipart.into_static(),
+            ),
+            requests_duration_seconds_complete_multipart: register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::CompleteMultipart.into_static(),
+            ),
+
+            requests_total_presign_post: register_counter!(
+                METRIC_REQUESTS_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::PresignPost.into_static(),
+            ),
+            requests_duration_seconds_presign_post: register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::PresignPost.into_static(),
+            ),
+
+            requests_total_list_uploads: register_counter!(
+                METRIC_REQUESTS_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::ListUploads.into_static(),
+            ),
+            requests_duration_seconds_list_uploads: register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::ListUploads.into_static(),
+            ),
+
+            requests_total_abort_upload: register_counter!(
+                METRIC_REQUESTS_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::AbortUpload.into_static(),
+            ),
+            requests_duration_seconds_abort_upload: register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::AbortUpload.into_static(),
+            ),
+
             requests_total_blocking_create: register_counter!(
                 METRIC_REQUESTS_TOTAL,
                 LABEL_SERVICE => service,
@@ -598,6 +682,124 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
         })
     }
 
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        self.handle.requests_total_restore.increment(1);
+
+        let start = Instant::now();
+        let result = self.inner.restore(path, args).await;
+        let dur = start.elapsed().as_secs_f64();
+
+        self.handle.requests_duration_seconds_restore.record(dur);
+
+        result.map_err(|e| {
+            self.handle
+                .increment_errors_total(Operation::Restore, e.kind());
+            e
+        })
+    }
+
+    async fn initiate_multipart(
+        &self,
+        path: &str,
+        args: OpInitiateMultipart,
+    ) -> Result<RpInitiateMultipart> {
+        self.handle.requests_total_initiate_multipart.increment(1);
+
+        let start = Instant::now();
+        let result = self.inner.initiate_multipart(path, args).await;
+        let dur = start.elapsed().as_secs_f64();
+
+        self.handle
+            .requests_duration_seconds_initiate_multipart
+            .record(dur);
+
+        result.map_err(|e| {
+            self.handle
+                .increment_errors_total(Operation::InitiateMultipart, e.kind());
+            e
+        })
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        self.handle.requests_total_complete_multipart.increment(1);
+
+        let start = Instant::now();
+        let result = self.inner.complete_multipart(path, args).await;
+        let dur = start.elapsed().as_secs_f64();
+
+        self.handle
+            .requests_duration_seconds_complete_multipart
+            .record(dur);
+
+        result.map_err(|e| {
+            self.handle
+                .increment_errors_total(Operation::CompleteMultipart, e.kind());
+            e
+        })
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        self.handle.requests_total_presign_post.increment(1);
+
+        let start = Instant::now();
+        let result = self.inner.presign_post(path, args).await;
+        let dur = start.elapsed().as_secs_f64();
+
+        self.handle
+            .requests_duration_seconds_presign_post
+            .record(dur);
+
+        result.map_err(|e| {
+            self.handle
+                .increment_errors_total(Operation::PresignPost, e.kind());
+            e
+        })
+    }
+
+    async fn list_uploads(
+        &self,
+        path: &str,
+        args: OpListUploads,
+    ) -> Result<(RpListUploads, oio::UploadPager)> {
+        self.handle.requests_total_list_uploads.increment(1);
+
+        let start = Instant::now();
+        let result = self.inner.list_uploads(path, args).await;
+        let dur = start.elapsed().as_secs_f64();
+
+        self.handle
+            .requests_duration_seconds_list_uploads
+            .record(dur);
+
+        result.map_err(|e| {
+            self.handle
+                .increment_errors_total(Operation::ListUploads, e.kind());
+            e
+        })
+    }
+
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        self.handle.requests_total_abort_upload.increment(1);
+
+        let start = Instant::now();
+        let result = self.inner.abort_upload(path, args).await;
+        let dur = start.elapsed().as_secs_f64();
+
+        self.handle
+            .requests_duration_seconds_abort_upload
+            .record(dur);
+
+        result.map_err(|e| {
+            self.handle
+                .increment_errors_total(Operation::AbortUpload, e.kind());
+            e
+        })
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.handle.requests_total_blocking_create.increment(1);
 
@@ -867,6 +1069,10 @@ impl<R: oio::Write> oio::Write for MetricWrapper<R> {
         })
     }
 
+    fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        self.inner.checkpoint()
+    }
+
     fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
         self.inner.poll_close(cx).map_err(|err| {
             self.handle.increment_errors_total(self.op, err.kind());
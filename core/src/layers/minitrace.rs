@@ -228,6 +228,48 @@ impl<A: Accessor> LayeredAccessor for MinitraceAccessor<A> {
         self.inner.batch(args).await
     }
 
+    #[trace(enter_on_poll = true)]
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        self.inner.restore(path, args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn initiate_multipart(
+        &self,
+        path: &str,
+        args: OpInitiateMultipart,
+    ) -> Result<RpInitiateMultipart> {
+        self.inner.initiate_multipart(path, args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        self.inner.complete_multipart(path, args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        self.inner.presign_post(path, args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn list_uploads(
+        &self,
+        path: &str,
+        args: OpListUploads,
+    ) -> Result<(RpListUploads, oio::UploadPager)> {
+        self.inner.list_uploads(path, args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        self.inner.abort_upload(path, args).await
+    }
+
     #[trace]
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.inner.blocking_create_dir(path, args)
@@ -349,6 +391,10 @@ impl<R: oio::Write> oio::Write for MinitraceWrapper<R> {
         self.inner.poll_abort(cx)
     }
 
+    fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        self.inner.checkpoint()
+    }
+
     fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
         let _g = self.span.set_local_parent();
         let _span = LocalSpan::enter_with_local_parent(WriteOperation::Close.into_static());
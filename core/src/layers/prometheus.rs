@@ -498,6 +498,156 @@ impl<A: Accessor> LayeredAccessor for PrometheusAccessor<A> {
         })
     }
 
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        let labels = self.stats.generate_metric_label(
+            self.scheme.into_static(),
+            Operation::Restore.into_static(),
+            path,
+        );
+        self.stats.requests_total.with_label_values(&labels).inc();
+
+        let timer = self
+            .stats
+            .requests_duration_seconds
+            .with_label_values(&labels)
+            .start_timer();
+        let result = self.inner.restore(path, args).await;
+        timer.observe_duration();
+
+        result.map_err(|e| {
+            self.stats
+                .increment_errors_total(Operation::Restore, e.kind());
+            e
+        })
+    }
+
+    async fn initiate_multipart(
+        &self,
+        path: &str,
+        args: OpInitiateMultipart,
+    ) -> Result<RpInitiateMultipart> {
+        let labels = self.stats.generate_metric_label(
+            self.scheme.into_static(),
+            Operation::InitiateMultipart.into_static(),
+            path,
+        );
+        self.stats.requests_total.with_label_values(&labels).inc();
+
+        let timer = self
+            .stats
+            .requests_duration_seconds
+            .with_label_values(&labels)
+            .start_timer();
+        let result = self.inner.initiate_multipart(path, args).await;
+        timer.observe_duration();
+
+        result.map_err(|e| {
+            self.stats
+                .increment_errors_total(Operation::InitiateMultipart, e.kind());
+            e
+        })
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let labels = self.stats.generate_metric_label(
+            self.scheme.into_static(),
+            Operation::CompleteMultipart.into_static(),
+            path,
+        );
+        self.stats.requests_total.with_label_values(&labels).inc();
+
+        let timer = self
+            .stats
+            .requests_duration_seconds
+            .with_label_values(&labels)
+            .start_timer();
+        let result = self.inner.complete_multipart(path, args).await;
+        timer.observe_duration();
+
+        result.map_err(|e| {
+            self.stats
+                .increment_errors_total(Operation::CompleteMultipart, e.kind());
+            e
+        })
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        let labels = self.stats.generate_metric_label(
+            self.scheme.into_static(),
+            Operation::PresignPost.into_static(),
+            path,
+        );
+        self.stats.requests_total.with_label_values(&labels).inc();
+
+        let timer = self
+            .stats
+            .requests_duration_seconds
+            .with_label_values(&labels)
+            .start_timer();
+        let result = self.inner.presign_post(path, args).await;
+        timer.observe_duration();
+
+        result.map_err(|e| {
+            self.stats
+                .increment_errors_total(Operation::PresignPost, e.kind());
+            e
+        })
+    }
+
+    async fn list_uploads(
+        &self,
+        path: &str,
+        args: OpListUploads,
+    ) -> Result<(RpListUploads, oio::UploadPager)> {
+        let labels = self.stats.generate_metric_label(
+            self.scheme.into_static(),
+            Operation::ListUploads.into_static(),
+            path,
+        );
+        self.stats.requests_total.with_label_values(&labels).inc();
+
+        let timer = self
+            .stats
+            .requests_duration_seconds
+            .with_label_values(&labels)
+            .start_timer();
+        let result = self.inner.list_uploads(path, args).await;
+        timer.observe_duration();
+
+        result.map_err(|e| {
+            self.stats
+                .increment_errors_total(Operation::ListUploads, e.kind());
+            e
+        })
+    }
+
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        let labels = self.stats.generate_metric_label(
+            self.scheme.into_static(),
+            Operation::AbortUpload.into_static(),
+            path,
+        );
+        self.stats.requests_total.with_label_values(&labels).inc();
+
+        let timer = self
+            .stats
+            .requests_duration_seconds
+            .with_label_values(&labels)
+            .start_timer();
+        let result = self.inner.abort_upload(path, args).await;
+        timer.observe_duration();
+
+        result.map_err(|e| {
+            self.stats
+                .increment_errors_total(Operation::AbortUpload, e.kind());
+            e
+        })
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         let labels = self.stats.generate_metric_label(
             self.scheme.into_static(),
@@ -820,6 +970,10 @@ impl<R: oio::Write> oio::Write for PrometheusMetricWrapper<R> {
         })
     }
 
+    fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        self.inner.checkpoint()
+    }
+
     fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
         self.inner.poll_close(cx).map_err(|err| {
             self.stats.increment_errors_total(self.op, err.kind());
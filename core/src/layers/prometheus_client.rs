@@ -640,6 +640,10 @@ impl<R: oio::Write> oio::Write for PrometheusMetricWrapper<R> {
         })
     }
 
+    fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        self.inner.checkpoint()
+    }
+
     fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
         self.inner.poll_close(cx).map_err(|err| {
             self.metrics
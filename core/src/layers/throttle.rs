@@ -247,6 +247,10 @@ impl<R: oio::Write> oio::Write for ThrottleWrapper<R> {
         self.inner.poll_abort(cx)
     }
 
+    fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        self.inner.checkpoint()
+    }
+
     fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
         self.inner.poll_close(cx)
     }
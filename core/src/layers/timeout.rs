@@ -384,6 +384,10 @@ impl<R: oio::Write> oio::Write for TimeoutWrapper<R> {
         }
     }
 
+    fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        self.inner.checkpoint()
+    }
+
     fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
         match self.start {
             Some(start) => {
@@ -208,6 +208,48 @@ impl<A: Accessor> LayeredAccessor for TracingAccessor<A> {
         self.inner.batch(args).await
     }
 
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        self.inner.restore(path, args).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn initiate_multipart(
+        &self,
+        path: &str,
+        args: OpInitiateMultipart,
+    ) -> Result<RpInitiateMultipart> {
+        self.inner.initiate_multipart(path, args).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        self.inner.complete_multipart(path, args).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        self.inner.presign_post(path, args).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn list_uploads(
+        &self,
+        path: &str,
+        args: OpListUploads,
+    ) -> Result<(RpListUploads, oio::UploadPager)> {
+        self.inner.list_uploads(path, args).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        self.inner.abort_upload(path, args).await
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.inner.blocking_create_dir(path, args)
@@ -336,6 +378,10 @@ impl<R: oio::Write> oio::Write for TracingWrapper<R> {
         self.inner.poll_abort(cx)
     }
 
+    fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        self.inner.checkpoint()
+    }
+
     #[tracing::instrument(
         parent = &self.span,
         level = "trace",
@@ -246,6 +246,196 @@ pub trait Accessor: Send + Sync + Debug + Unpin + 'static {
         ))
     }
 
+    /// Invoke the `summary` operation on the specified dir.
+    ///
+    /// Require [`Capability::summary`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be dir path, DON'T NEED to check mode.
+    /// - Summary non-exist dir should return zero.
+    /// - This API is optional, services should only implement it when they
+    ///   have native aggregate endpoints.
+    async fn summary(&self, path: &str, args: OpSummary) -> Result<RpSummary> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `watch` operation on the specified dir.
+    ///
+    /// Require [`Capability::watch`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be dir path, DON'T NEED to check mode.
+    /// - Only changes of files under the dir (recursively) should be returned.
+    /// - Paths of events MUST be relative to the root of service.
+    /// - This API is optional, services should only implement it when they
+    ///   can receive changes natively.
+    async fn watch(&self, path: &str, args: OpWatch) -> Result<(RpWatch, oio::Watcher)> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `get_tags` operation on the specified path.
+    ///
+    /// Require [`Capability::get_tags`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be file path, DON'T NEED to check mode.
+    /// - Tags are key/value pairs stored separately from the metadata of file.
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `set_tags` operation on the specified path.
+    ///
+    /// Require [`Capability::set_tags`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be file path, DON'T NEED to check mode.
+    /// - All existing tags of the file MUST be replaced by given tags.
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `restore` operation on the specified path.
+    ///
+    /// Require [`Capability::restore`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be file path, DON'T NEED to check mode.
+    /// - Restore a file that is not archived should return an error.
+    /// - Services that restore files by changing their storage class permanently
+    ///   (like Azblob rehydrate) could ignore `days`.
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `initiate_multipart` operation on the specified path.
+    ///
+    /// Require [`Capability::presign_write_multipart`]
+    ///
+    /// # Behavior
+    ///
+    /// - Initiate a multipart upload on the path and return its upload id.
+    /// - Parts of this upload could be written via presigned requests.
+    async fn initiate_multipart(
+        &self,
+        path: &str,
+        args: OpInitiateMultipart,
+    ) -> Result<RpInitiateMultipart> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `complete_multipart` operation on the specified path.
+    ///
+    /// Require [`Capability::presign_write_multipart`]
+    ///
+    /// # Behavior
+    ///
+    /// - Complete the multipart upload with etags of all parts in order.
+    /// - The object will be visible at path after completion.
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `presign_post` operation on the specified path.
+    ///
+    /// Require [`Capability::presign_post`]
+    ///
+    /// # Behavior
+    ///
+    /// The path is the prefix that uploaded keys must start with.
+    /// Services should sign a POST policy enforcing the conditions in args,
+    /// returning the url and form fields that clients can upload with.
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `list_uploads` operation on the specified path.
+    ///
+    /// Require [`Capability::list_uploads`]
+    ///
+    /// # Behavior
+    ///
+    /// - List all incomplete uploads whose path starts with the given prefix.
+    /// - Services should fetch all pages and return uploads in the order of path.
+    async fn list_uploads(
+        &self,
+        path: &str,
+        args: OpListUploads,
+    ) -> Result<(RpListUploads, oio::UploadPager)> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `abort_upload` operation on the specified path.
+    ///
+    /// Require [`Capability::abort_upload`]
+    ///
+    /// # Behavior
+    ///
+    /// - Abort the incomplete upload and purge all uploaded parts.
+    /// - Aborting an upload that doesn't exist should return `NotFound`.
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
     /// Invoke the `blocking_create` operation on the specified path.
     ///
     /// This operation is the blocking version of [`Accessor::create_dir`]
@@ -436,6 +626,58 @@ impl<T: Accessor + ?Sized> Accessor for Arc<T> {
         self.as_ref().presign(path, args).await
     }
 
+    async fn summary(&self, path: &str, args: OpSummary) -> Result<RpSummary> {
+        self.as_ref().summary(path, args).await
+    }
+
+    async fn watch(&self, path: &str, args: OpWatch) -> Result<(RpWatch, oio::Watcher)> {
+        self.as_ref().watch(path, args).await
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        self.as_ref().get_tags(path, args).await
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        self.as_ref().set_tags(path, args).await
+    }
+
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        self.as_ref().restore(path, args).await
+    }
+
+    async fn initiate_multipart(
+        &self,
+        path: &str,
+        args: OpInitiateMultipart,
+    ) -> Result<RpInitiateMultipart> {
+        self.as_ref().initiate_multipart(path, args).await
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        self.as_ref().complete_multipart(path, args).await
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        self.as_ref().presign_post(path, args).await
+    }
+
+    async fn list_uploads(
+        &self,
+        path: &str,
+        args: OpListUploads,
+    ) -> Result<(RpListUploads, oio::UploadPager)> {
+        self.as_ref().list_uploads(path, args).await
+    }
+
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        self.as_ref().abort_upload(path, args).await
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.as_ref().blocking_create_dir(path, args)
     }
@@ -129,6 +129,20 @@ pub trait Adapter: Send + Sync + Debug + Unpin + 'static {
         )
         .with_operation("kv::Adapter::blocking_append"))
     }
+
+    /// Watch the changes of keys with given prefix.
+    ///
+    /// Paths of returned events are full keys, and keys of dirs which end
+    /// with `/` will be ignored.
+    async fn watch(&self, path: &str) -> Result<oio::Watcher> {
+        let _ = path;
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "kv adapter doesn't support this operation",
+        )
+        .with_operation("kv::Adapter::watch"))
+    }
 }
 
 /// Metadata for this key value accessor.
@@ -41,6 +41,7 @@ use crate::*;
 pub struct Backend<S: Adapter> {
     kv: Arc<S>,
     root: String,
+    append_emulation: bool,
 }
 
 impl<S> Backend<S>
@@ -52,6 +53,7 @@ where
         Self {
             kv: Arc::new(kv),
             root: "/".to_string(),
+            append_emulation: false,
         }
     }
 
@@ -60,6 +62,16 @@ where
         self.root = normalize_root(root);
         self
     }
+
+    /// Emulate append by read-modify-write if the adapter doesn't support
+    /// append natively.
+    ///
+    /// The emulation is NOT atomic: concurrent writes to the same key
+    /// between the read and the write could be lost.
+    pub fn with_append_emulation(mut self, enabled: bool) -> Self {
+        self.append_emulation = enabled;
+        self
+    }
 }
 
 #[async_trait]
@@ -91,6 +103,10 @@ impl<S: Adapter> Accessor for Backend<S> {
 
         if cap.read && cap.write {
             cap.copy = true;
+
+            if self.append_emulation {
+                cap.write_can_append = true;
+            }
         }
 
         if cap.read && cap.write && cap.delete {
@@ -144,16 +160,16 @@ impl<S: Adapter> Accessor for Backend<S> {
         Ok((RpRead::new(), oio::Cursor::from(bs)))
     }
 
-    async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let p = build_abs_path(&self.root, path);
 
-        Ok((RpWrite::new(), KvWriter::new(self.kv.clone(), p)))
+        Ok((RpWrite::new(), self.new_writer(p, args.append())))
     }
 
-    fn blocking_write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
         let p = build_abs_path(&self.root, path);
 
-        Ok((RpWrite::new(), KvWriter::new(self.kv.clone(), p)))
+        Ok((RpWrite::new(), self.new_writer(p, args.append())))
     }
 
     async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
@@ -217,6 +233,13 @@ impl<S: Adapter> Accessor for Backend<S> {
         Ok((RpList::default(), pager))
     }
 
+    async fn watch(&self, path: &str, _: OpWatch) -> Result<(RpWatch, oio::Watcher)> {
+        let p = build_abs_path(&self.root, path);
+        let w = self.kv.watch(&p).await?;
+
+        Ok((RpWatch::default(), Box::new(KvWatcher::new(&self.root, w))))
+    }
+
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
         if !args.delimiter().is_empty() {
             return Err(Error::new(
@@ -299,6 +322,18 @@ impl<S> Backend<S>
 where
     S: Adapter,
 {
+    fn new_writer(&self, path: String, append: bool) -> KvWriter<S> {
+        let mode = if !append {
+            WriteMode::Set
+        } else if self.kv.metadata().capabilities().write_can_append {
+            WriteMode::Append
+        } else {
+            WriteMode::ReadModifyWrite
+        };
+
+        KvWriter::new(self.kv.clone(), path, mode)
+    }
+
     fn apply_range(&self, mut bs: Vec<u8>, br: BytesRange) -> Vec<u8> {
         match (br.offset(), br.size()) {
             (Some(offset), Some(size)) => {
@@ -361,25 +396,69 @@ impl oio::BlockingPage for KvPager {
     }
 }
 
+/// KvWatcher converts the keys of events into paths relative to root.
+pub struct KvWatcher {
+    root: String,
+    inner: oio::Watcher,
+}
+
+impl KvWatcher {
+    fn new(root: &str, inner: oio::Watcher) -> Self {
+        Self {
+            root: root.to_string(),
+            inner,
+        }
+    }
+}
+
+#[async_trait]
+impl oio::Watch for KvWatcher {
+    async fn next(&mut self) -> Result<Option<Vec<WatchEvent>>> {
+        let Some(events) = self.inner.next().await? else {
+            return Ok(None);
+        };
+
+        let events = events
+            .into_iter()
+            .filter(|e| !e.path().ends_with('/'))
+            .map(|e| WatchEvent::new(e.kind(), &build_rel_path(&self.root, e.path())))
+            .collect();
+        Ok(Some(events))
+    }
+}
+
 pub struct KvWriter<S> {
     kv: Arc<S>,
     path: String,
+    mode: WriteMode,
 
     buffer: Buffer,
     future: Option<BoxFuture<'static, Result<()>>>,
 }
 
 impl<S> KvWriter<S> {
-    fn new(kv: Arc<S>, path: String) -> Self {
+    fn new(kv: Arc<S>, path: String, mode: WriteMode) -> Self {
         KvWriter {
             kv,
             path,
+            mode,
             buffer: Buffer::Active(BytesMut::new()),
             future: None,
         }
     }
 }
 
+/// WriteMode decides how the buffered content will be flushed into kv.
+#[derive(Debug, Clone, Copy)]
+enum WriteMode {
+    /// Overwrite the value of key.
+    Set,
+    /// Append to the value of key by the adapter's native append.
+    Append,
+    /// Emulate append by reading the old value and setting the joined one.
+    ReadModifyWrite,
+}
+
 enum Buffer {
     Active(BytesMut),
     Frozen(Bytes),
@@ -430,7 +509,19 @@ impl<S: Adapter> oio::Write for KvWriter<S> {
                         Buffer::Frozen(buf) => buf.clone(),
                     };
 
-                    let fut = async move { kv.set(&path, &buf).await };
+                    let mode = self.mode;
+
+                    let fut = async move {
+                        match mode {
+                            WriteMode::Set => kv.set(&path, &buf).await,
+                            WriteMode::Append => kv.append(&path, &buf).await,
+                            WriteMode::ReadModifyWrite => {
+                                let mut value = kv.get(&path).await?.unwrap_or_default();
+                                value.extend_from_slice(&buf);
+                                kv.set(&path, &value).await
+                            }
+                        }
+                    };
                     self.future = Some(Box::pin(fut));
                 }
             }
@@ -472,7 +563,15 @@ impl<S: Adapter> oio::BlockingWrite for KvWriter<S> {
             Buffer::Frozen(buf) => buf.clone(),
         };
 
-        self.kv.blocking_set(&self.path, &buf)?;
+        match self.mode {
+            WriteMode::Set => self.kv.blocking_set(&self.path, &buf)?,
+            WriteMode::Append => self.kv.blocking_append(&self.path, &buf)?,
+            WriteMode::ReadModifyWrite => {
+                let mut value = self.kv.blocking_get(&self.path)?.unwrap_or_default();
+                value.extend_from_slice(&buf);
+                self.kv.blocking_set(&self.path, &value)?
+            }
+        }
         Ok(())
     }
 }
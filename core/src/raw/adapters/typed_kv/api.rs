@@ -59,6 +59,30 @@ pub trait Adapter: Send + Sync + Debug + Unpin + 'static {
     /// Set a value into adapter.
     fn blocking_set(&self, path: &str, value: Value) -> Result<()>;
 
+    /// Set a value into adapter only if the path doesn't exist, returns
+    /// `false` if the path exists already.
+    async fn set_if_absent(&self, path: &str, value: Value) -> Result<bool> {
+        let _ = (path, value);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "typed_kv adapter doesn't support this operation",
+        )
+        .with_operation("typed_kv::Adapter::set_if_absent"))
+    }
+
+    /// Set a value into adapter only if the path doesn't exist in blocking
+    /// way, returns `false` if the path exists already.
+    fn blocking_set_if_absent(&self, path: &str, value: Value) -> Result<bool> {
+        let _ = (path, value);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "typed_kv adapter doesn't support this operation",
+        )
+        .with_operation("typed_kv::Adapter::blocking_set_if_absent"))
+    }
+
     /// Delete a value from adapter.
     async fn delete(&self, path: &str) -> Result<()>;
 
@@ -125,6 +149,8 @@ pub struct Capability {
     pub get: bool,
     /// If typed_kv operator supports set natively.
     pub set: bool,
+    /// If typed_kv operator supports set if absent natively.
+    pub set_if_absent: bool,
     /// If typed_kv operator supports delete natively.
     pub delete: bool,
     /// If typed_kv operator supports scan natively.
@@ -141,6 +167,9 @@ impl Debug for Capability {
         if self.set {
             s.push("Set");
         }
+        if self.set_if_absent {
+            s.push("SetIfAbsent");
+        }
         if self.delete {
             s.push("Delete");
         }
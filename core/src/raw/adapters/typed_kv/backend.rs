@@ -22,6 +22,7 @@ use std::task::Poll;
 
 use async_trait::async_trait;
 use bytes::Bytes;
+use chrono::Utc;
 use futures::future::BoxFuture;
 use futures::FutureExt;
 
@@ -88,6 +89,10 @@ impl<S: Adapter> Accessor for Backend<S> {
             cap.create_dir = true;
         }
 
+        if kv_cap.set_if_absent {
+            cap.write_with_if_not_exists = true;
+        }
+
         if kv_cap.delete {
             cap.delete = true;
         }
@@ -394,6 +399,7 @@ impl<S> KvWriter<S> {
 
         let mut metadata = Metadata::new(EntryMode::FILE);
         metadata.set_content_length(value.len() as u64);
+        metadata.set_last_modified(Utc::now());
 
         if let Some(v) = self.op.cache_control() {
             metadata.set_cache_control(v);
@@ -450,7 +456,17 @@ impl<S: Adapter> oio::Write for KvWriter<S> {
                         }
                     };
 
-                    let fut = async move { kv.set(&path, value).await };
+                    let if_not_exists = self.op.if_not_exists();
+                    let fut = async move {
+                        if !if_not_exists {
+                            return kv.set(&path, value).await;
+                        }
+                        if kv.set_if_absent(&path, value).await? {
+                            Ok(())
+                        } else {
+                            Err(new_file_exists_error(&path))
+                        }
+                    };
                     self.future = Some(Box::pin(fut));
                 }
             }
@@ -494,7 +510,19 @@ impl<S: Adapter> oio::BlockingWrite for KvWriter<S> {
             }
         };
 
-        kv.blocking_set(&self.path, value)?;
-        Ok(())
+        if !self.op.if_not_exists() {
+            return kv.blocking_set(&self.path, value);
+        }
+        if kv.blocking_set_if_absent(&self.path, value)? {
+            Ok(())
+        } else {
+            Err(new_file_exists_error(&self.path))
+        }
     }
 }
+
+fn new_file_exists_error(path: &str) -> Error {
+    Error::new(ErrorKind::ConditionNotMatch, "file already exists")
+        .with_operation("typed_kv::Writer::close")
+        .with_context("path", path)
+}
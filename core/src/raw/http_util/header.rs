@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
+
 use base64::engine::general_purpose;
 use base64::Engine;
 use chrono::DateTime;
@@ -31,6 +33,9 @@ use http::header::LOCATION;
 use http::HeaderMap;
 use http::HeaderValue;
 use md5::Digest;
+use percent_encoding::utf8_percent_encode;
+use percent_encoding::AsciiSet;
+use percent_encoding::NON_ALPHANUMERIC;
 
 use crate::raw::*;
 use crate::EntryMode;
@@ -131,6 +136,22 @@ pub fn parse_content_type(headers: &HeaderMap) -> Result<Option<&str>> {
     }
 }
 
+/// Parse header value of given name into string.
+pub fn parse_header_to_str<'a>(headers: &'a HeaderMap, name: &str) -> Result<Option<&'a str>> {
+    match headers.get(name) {
+        None => Ok(None),
+        Some(v) => Ok(Some(v.to_str().map_err(|e| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "header value is not valid utf-8 string",
+            )
+            .with_operation("http_util::parse_header_to_str")
+            .with_context("header_name", name)
+            .set_source(e)
+        })?)),
+    }
+}
+
 /// Parse content range from header map.
 pub fn parse_content_range(headers: &HeaderMap) -> Result<Option<BytesContentRange>> {
     match headers.get(CONTENT_RANGE) {
@@ -257,6 +278,34 @@ pub fn format_content_md5(bs: &[u8]) -> String {
     general_purpose::STANDARD.encode(hasher.finalize())
 }
 
+/// TAGGING_ENCODE_SET is the encode set for tagging header, which only
+/// keeps the unreserved characters `A-Z a-z 0-9 - _ . ~`.
+static TAGGING_ENCODE_SET: AsciiSet = NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// format tagging header like `x-amz-tagging` by given tags.
+///
+/// Tags are encoded as url query parameters and sorted by key, for example:
+/// `project=opendal&team=storage`.
+pub fn format_tagging(tags: &HashMap<String, String>) -> String {
+    let mut tags: Vec<_> = tags.iter().collect();
+    tags.sort();
+
+    tags.into_iter()
+        .map(|(k, v)| {
+            format!(
+                "{}={}",
+                utf8_percent_encode(k, &TAGGING_ENCODE_SET),
+                utf8_percent_encode(v, &TAGGING_ENCODE_SET)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
 /// format authorization header by basic auth.
 ///
 /// # Errors
@@ -367,4 +416,17 @@ mod tests {
             assert_eq!(actual, expected)
         }
     }
+
+    #[test]
+    fn test_format_tagging() {
+        let tags = HashMap::from([
+            ("team".to_string(), "storage".to_string()),
+            ("project".to_string(), "open dal&s3=1".to_string()),
+        ]);
+
+        assert_eq!(
+            format_tagging(&tags),
+            "project=open%20dal%26s3%3D1&team=storage"
+        );
+    }
 }
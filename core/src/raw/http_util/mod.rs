@@ -34,12 +34,14 @@ pub use header::build_header_value;
 pub use header::format_authorization_by_basic;
 pub use header::format_authorization_by_bearer;
 pub use header::format_content_md5;
+pub use header::format_tagging;
 pub use header::parse_content_disposition;
 pub use header::parse_content_length;
 pub use header::parse_content_md5;
 pub use header::parse_content_range;
 pub use header::parse_content_type;
 pub use header::parse_etag;
+pub use header::parse_header_to_str;
 pub use header::parse_into_metadata;
 pub use header::parse_last_modified;
 pub use header::parse_location;
@@ -60,6 +62,9 @@ pub use bytes_range::BytesRange;
 mod bytes_content_range;
 pub use bytes_content_range::BytesContentRange;
 
+mod post_policy;
+pub use post_policy::PostPolicy;
+
 mod multipart;
 pub use multipart::FormDataPart;
 pub use multipart::MixedPart;
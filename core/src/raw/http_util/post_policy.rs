@@ -0,0 +1,109 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use chrono::DateTime;
+use chrono::Utc;
+use serde_json::json;
+use serde_json::Value;
+
+use crate::*;
+
+/// PostPolicy is the policy document of a presigned POST upload.
+///
+/// The document format is shared by s3 compatible services and gcs:
+///
+/// ```json
+/// {
+///   "expiration": "2023-10-01T12:00:00.000Z",
+///   "conditions": [
+///     ["starts-with", "$key", "path/to/"],
+///     ["content-length-range", 0, 1024]
+///   ]
+/// }
+/// ```
+///
+/// Services should push their own auth fields before signing.
+#[derive(Debug, Clone)]
+pub struct PostPolicy {
+    expiration: DateTime<Utc>,
+    conditions: Vec<Value>,
+}
+
+impl PostPolicy {
+    /// Create a new policy which allows keys starting with `key_prefix`.
+    pub fn new(key_prefix: &str, conditions: &PostConditions, expiration: DateTime<Utc>) -> Self {
+        let mut cs = vec![json!(["starts-with", "$key", key_prefix])];
+        if let Some((min, max)) = conditions.content_length_range() {
+            cs.push(json!(["content-length-range", min, max]));
+        }
+        if let Some(v) = conditions.content_type() {
+            cs.push(json!(["eq", "$Content-Type", v]));
+        }
+        if let Some(v) = conditions.content_type_prefix() {
+            cs.push(json!(["starts-with", "$Content-Type", v]));
+        }
+
+        Self {
+            expiration,
+            conditions: cs,
+        }
+    }
+
+    /// Require the form field `name` to be exactly `value`.
+    pub fn push_field(&mut self, name: &str, value: &str) {
+        self.conditions.push(json!({ name: value }));
+    }
+
+    /// Build the policy document in json.
+    pub fn to_json(&self) -> String {
+        json!({
+            "expiration": self.expiration.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+            "conditions": self.conditions,
+        })
+        .to_string()
+    }
+
+    /// Build the policy document in base64 encoded json which will be sent
+    /// as the `policy` field.
+    pub fn to_base64(&self) -> String {
+        BASE64_STANDARD.encode(self.to_json())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_post_policy() {
+        let expiration = Utc.with_ymd_and_hms(2023, 10, 1, 12, 0, 0).unwrap();
+        let conditions = PostConditions::new()
+            .with_content_length_range(1, 1024)
+            .with_content_type_prefix("image/");
+
+        let mut policy = PostPolicy::new("path/to/", &conditions, expiration);
+        policy.push_field("bucket", "test");
+
+        assert_eq!(
+            policy.to_json(),
+            r#"{"conditions":[["starts-with","$key","path/to/"],["content-length-range",1,1024],["starts-with","$Content-Type","image/"],{"bucket":"test"}],"expiration":"2023-10-01T12:00:00.000Z"}"#
+        );
+    }
+}
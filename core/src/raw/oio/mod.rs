@@ -34,6 +34,12 @@ pub use stream::*;
 mod page;
 pub use page::*;
 
+mod watch;
+pub use watch::*;
+
+mod upload;
+pub use upload::*;
+
 mod cursor;
 pub use cursor::Cursor;
 
@@ -0,0 +1,228 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use async_trait::async_trait;
+use futures::future::try_join_all;
+
+use crate::raw::*;
+use crate::*;
+
+/// into_merge_page is used to merge pages from different pagers into one.
+pub fn into_merge_page<P>(pagers: Vec<P>) -> MergePager<P> {
+    let bufs = pagers.iter().map(|_| VecDeque::new()).collect();
+
+    MergePager {
+        pagers: pagers.into_iter().map(Some).collect(),
+        bufs,
+        seen: HashSet::new(),
+    }
+}
+
+/// MergePager merges the pages returned by different pagers by path.
+///
+/// # Notes
+///
+/// Every page will be sorted before merging, and entries with the same path
+/// will only be returned once, even if they are returned in different pages.
+/// The output is sorted only if all pagers return sorted pages.
+///
+/// All returned paths are kept in memory to remove duplicates.
+pub struct MergePager<P> {
+    /// `None` means this pager has been exhausted.
+    pagers: Vec<Option<P>>,
+    bufs: Vec<VecDeque<oio::Entry>>,
+    /// Paths of all returned entries, used to remove duplicates.
+    seen: HashSet<String>,
+}
+
+impl<P> MergePager<P> {
+    /// Returns true if any pager is still active but has nothing buffered.
+    fn need_fill(&self) -> bool {
+        self.pagers
+            .iter()
+            .zip(self.bufs.iter())
+            .any(|(p, buf)| p.is_some() && buf.is_empty())
+    }
+
+    /// Pop entries while all active pagers have buffered entries, otherwise
+    /// we can't decide which one is the smallest.
+    fn merge_entries(&mut self) -> Vec<oio::Entry> {
+        let mut entries = vec![];
+
+        while !self.need_fill() {
+            let idx = self
+                .bufs
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, buf)| buf.front().map(|e| (idx, e.path())))
+                .min_by(|a, b| a.1.cmp(b.1))
+                .map(|(idx, _)| idx);
+            let Some(idx) = idx else {
+                break;
+            };
+
+            let entry = self.bufs[idx]
+                .pop_front()
+                .expect("buffer must have entries");
+            if self.seen.insert(entry.path().to_string()) {
+                entries.push(entry);
+            }
+        }
+
+        entries
+    }
+
+    fn fill_page(buf: &mut VecDeque<oio::Entry>, entries: Option<Vec<oio::Entry>>) -> bool {
+        match entries {
+            Some(mut entries) => {
+                entries.sort_by(|a, b| a.path().cmp(b.path()));
+                buf.extend(entries);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[async_trait]
+impl<P> oio::Page for MergePager<P>
+where
+    P: oio::Page,
+{
+    async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        loop {
+            if self.need_fill() {
+                let futs = self
+                    .pagers
+                    .iter_mut()
+                    .zip(self.bufs.iter_mut())
+                    .filter(|(p, buf)| p.is_some() && buf.is_empty())
+                    .map(|(p, buf)| async move {
+                        let pager = p.as_mut().expect("pager must be valid");
+                        if !Self::fill_page(buf, pager.next().await?) {
+                            *p = None;
+                        }
+                        Ok::<(), Error>(())
+                    });
+                try_join_all(futs).await?;
+                continue;
+            }
+
+            let entries = self.merge_entries();
+            if !entries.is_empty() {
+                return Ok(Some(entries));
+            }
+            if self.pagers.iter().all(Option::is_none) {
+                return Ok(None);
+            }
+        }
+    }
+}
+
+impl<P> oio::BlockingPage for MergePager<P>
+where
+    P: oio::BlockingPage,
+{
+    fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        loop {
+            if self.need_fill() {
+                for (p, buf) in self.pagers.iter_mut().zip(self.bufs.iter_mut()) {
+                    if let Some(pager) = p.as_mut() {
+                        if buf.is_empty() && !Self::fill_page(buf, pager.next()?) {
+                            *p = None;
+                        }
+                    }
+                }
+                continue;
+            }
+
+            let entries = self.merge_entries();
+            if !entries.is_empty() {
+                return Ok(Some(entries));
+            }
+            if self.pagers.iter().all(Option::is_none) {
+                return Ok(None);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockPager {
+        pages: VecDeque<Vec<&'static str>>,
+    }
+
+    impl oio::BlockingPage for MockPager {
+        fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+            Ok(self.pages.pop_front().map(|page| {
+                page.into_iter()
+                    .map(|path| oio::Entry::new(path, Metadata::new(EntryMode::FILE)))
+                    .collect()
+            }))
+        }
+    }
+
+    #[test]
+    fn test_blocking_merge_page() -> Result<()> {
+        let pagers = vec![
+            MockPager {
+                pages: vec![vec!["a", "d"], vec!["f"]].into(),
+            },
+            MockPager {
+                pages: vec![vec!["d", "b"], vec![], vec!["e", "g"]].into(),
+            },
+        ];
+
+        let mut pager = into_merge_page(pagers);
+        let mut paths = vec![];
+        while let Some(entries) = oio::BlockingPage::next(&mut pager)? {
+            paths.extend(entries.into_iter().map(|e| e.path().to_string()));
+        }
+
+        assert_eq!(paths, vec!["a", "b", "d", "e", "f", "g"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_blocking_merge_page_dedup_across_pages() -> Result<()> {
+        // Pages are not sorted across each other.
+        let pagers = vec![
+            MockPager {
+                pages: vec![vec!["c"], vec!["a", "b"]].into(),
+            },
+            MockPager {
+                pages: vec![vec!["a", "c"], vec!["c"]].into(),
+            },
+        ];
+
+        let mut pager = into_merge_page(pagers);
+        let mut paths = vec![];
+        while let Some(entries) = oio::BlockingPage::next(&mut pager)? {
+            paths.extend(entries.into_iter().map(|e| e.path().to_string()));
+        }
+
+        paths.sort();
+        assert_eq!(paths, vec!["a", "b", "c"]);
+        Ok(())
+    }
+}
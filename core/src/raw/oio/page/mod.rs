@@ -29,3 +29,7 @@ pub use into_flat_page::FlatPager;
 mod into_hierarchy_pager;
 pub use into_hierarchy_pager::into_hierarchy_page;
 pub use into_hierarchy_pager::HierarchyPager;
+
+mod into_merge_page;
+pub use into_merge_page::into_merge_page;
+pub use into_merge_page::MergePager;
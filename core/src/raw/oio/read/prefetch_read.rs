@@ -0,0 +1,671 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::cmp;
+use std::io::SeekFrom;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Buf;
+use bytes::Bytes;
+use bytes::BytesMut;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
+use crate::raw::oio::ReadExt;
+use crate::raw::*;
+use crate::*;
+
+/// The max size of data to read from inner reader while caller is waiting.
+const MAX_CHUNK_SIZE: usize = 256 * 1024;
+
+/// PrefetchReader keeps a read ahead window of data over the inner reader.
+///
+/// - While serving reads, PrefetchReader starts reading from inner reader once
+///   less than half of `window` bytes ahead of current position are buffered,
+///   and fills the window again.
+/// - With `rt-tokio` feature enabled, the read ahead is driven by a spawned task
+///   if there is a tokio runtime in current context, so it makes progress while
+///   caller is not polling. Otherwise it's only driven by `poll_read` and `poll_next`.
+/// - Seeks within the buffer are served from memory.
+/// - Seeks forward no more than `window` bytes beyond the buffer are coalesced
+///   by reading through the inner reader instead of seeking it, which avoids
+///   starting a new range request for services like s3.
+/// - Other seeks will invalidate the buffer.
+///
+/// At most `window` bytes before current position are kept for backward seeks,
+/// so the buffer is bounded by about twice of `window`.
+///
+/// For blocking reader, the read ahead happens in the same thread when the
+/// buffered data has been consumed.
+pub struct PrefetchReader<R> {
+    state: State<R>,
+    window: usize,
+
+    /// Current position of this reader.
+    cur: u64,
+    /// The position of the first byte in `buf`.
+    start: u64,
+    buf: BytesMut,
+    /// Whether inner reader should seek to the end of `buf` before next read.
+    need_seek: bool,
+    eof: bool,
+    /// Error returned while prefetching, will be returned after buffered data
+    /// has been consumed.
+    err: Option<Error>,
+}
+
+enum State<R> {
+    Idle(R),
+    /// There is an unfinished fill that owns inner reader. It must be polled
+    /// to complete before seeking inner reader.
+    Fill(BoxFuture<'static, Result<(R, Fill)>>),
+    /// Inner reader has been lost since the fill task failed.
+    Failed,
+}
+
+/// Fill is the data read from inner reader by a fill.
+struct Fill {
+    data: BytesMut,
+    eof: bool,
+    err: Option<Error>,
+}
+
+/// # Safety
+///
+/// We will only take `&mut Self` reference for PrefetchReader.
+unsafe impl<R: Sync> Sync for PrefetchReader<R> {}
+
+impl<R> PrefetchReader<R> {
+    /// Create a new PrefetchReader with given read ahead window.
+    pub fn new(inner: R, window: usize) -> Self {
+        Self {
+            state: State::Idle(inner),
+            window,
+
+            cur: 0,
+            start: 0,
+            buf: BytesMut::new(),
+            need_seek: false,
+            eof: false,
+            err: None,
+        }
+    }
+
+    fn end(&self) -> u64 {
+        self.start + self.buf.len() as u64
+    }
+
+    fn chunk_size(&self) -> usize {
+        cmp::min(self.window, MAX_CHUNK_SIZE).max(1)
+    }
+
+    /// Returns the size to read ahead, `None` means there are enough data
+    /// buffered ahead of current position.
+    fn ahead_size(&self) -> Option<usize> {
+        let ahead = self.end().saturating_sub(self.cur);
+        if self.eof || self.err.is_some() || ahead > self.window as u64 / 2 {
+            return None;
+        }
+
+        Some(self.window - ahead as usize)
+    }
+
+    /// Returns true if current position is buffered or reader has reached the end.
+    fn is_ready(&self) -> bool {
+        (self.cur >= self.start && self.cur < self.end()) || (self.eof && self.cur >= self.end())
+    }
+
+    /// Invalidate the buffer if current position is out of the window.
+    fn invalidate(&mut self) {
+        if self.cur < self.start || self.cur > self.end() + self.window as u64 {
+            self.start = self.cur;
+            self.buf.clear();
+            self.need_seek = true;
+            self.eof = false;
+        }
+    }
+
+    /// Drop the buffered data that more than `window` bytes behind current position.
+    fn trim(&mut self) {
+        let behind = self.cur.saturating_sub(self.start);
+        if behind > self.window as u64 {
+            let amt = cmp::min(behind - self.window as u64, self.buf.len() as u64);
+            self.buf.advance(amt as usize);
+            self.start += amt;
+        }
+    }
+
+    /// Append the filled data into buffer.
+    fn on_fill(&mut self, fill: Fill) -> Result<usize> {
+        let n = fill.data.len();
+        self.buf.extend_from_slice(&fill.data);
+        if fill.eof {
+            self.eof = true;
+        }
+
+        match fill.err {
+            Some(err) if n == 0 => Err(err),
+            Some(err) => {
+                self.err = Some(err);
+                Ok(n)
+            }
+            None => Ok(n),
+        }
+    }
+
+    fn failed_error() -> Error {
+        Error::new(
+            ErrorKind::Unexpected,
+            "inner reader has been lost since prefetch failed",
+        )
+    }
+}
+
+impl<R: oio::Read + 'static> PrefetchReader<R> {
+    /// Read more data from inner reader into buffer.
+    ///
+    /// If there is no in-flight fill, a new fill of `size` bytes will be started.
+    fn poll_fill(&mut self, cx: &mut Context<'_>, size: usize) -> Poll<Result<usize>> {
+        if let State::Idle(_) = self.state {
+            let State::Idle(r) = std::mem::replace(&mut self.state, State::Failed) else {
+                unreachable!()
+            };
+            let seek = self.need_seek.then(|| self.end());
+            self.need_seek = false;
+            self.state = State::Fill(spawn_fill(r, seek, size));
+        }
+
+        let State::Fill(fut) = &mut self.state else {
+            return Poll::Ready(Err(Self::failed_error()));
+        };
+        match ready!(fut.poll_unpin(cx)) {
+            Ok((r, fill)) => {
+                self.state = State::Idle(r);
+                Poll::Ready(self.on_fill(fill))
+            }
+            Err(err) => {
+                self.state = State::Failed;
+                Poll::Ready(Err(err))
+            }
+        }
+    }
+
+    /// Make sure the data at current position is buffered, or reader has
+    /// reached the end.
+    fn poll_prepare(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        loop {
+            if self.is_ready() {
+                return Poll::Ready(Ok(()));
+            }
+            if let Some(err) = self.err.take() {
+                return Poll::Ready(Err(err));
+            }
+
+            if self.cur < self.start || self.cur > self.end() + self.window as u64 {
+                // Finish the in-flight fill before seeking inner reader, its
+                // data will be dropped so we don't care about the result.
+                if let State::Fill(_) = self.state {
+                    let _ = ready!(self.poll_fill(cx, 0));
+                    self.err = None;
+                }
+                self.invalidate();
+            }
+
+            ready!(self.poll_fill(cx, self.chunk_size()))?;
+            self.trim();
+        }
+    }
+
+    /// Start reading ahead if the window is not full.
+    ///
+    /// Errors will be kept and returned to the caller later.
+    fn prefetch(&mut self, cx: &mut Context<'_>) {
+        while let Some(size) = self.ahead_size() {
+            match self.poll_fill(cx, size) {
+                Poll::Ready(Ok(_)) => {}
+                Poll::Ready(Err(err)) => self.err = Some(err),
+                Poll::Pending => break,
+            }
+        }
+    }
+
+    fn consume(&mut self, cx: &mut Context<'_>, n: usize) {
+        self.cur += n as u64;
+        self.trim();
+        self.prefetch(cx);
+    }
+}
+
+/// Start a fill on inner reader.
+///
+/// The fill will be spawned as a task if `rt-tokio` is enabled and we are
+/// inside a tokio runtime.
+fn spawn_fill<R: oio::Read + 'static>(
+    r: R,
+    seek: Option<u64>,
+    size: usize,
+) -> BoxFuture<'static, Result<(R, Fill)>> {
+    let fut = fill(r, seek, size);
+
+    #[cfg(feature = "rt-tokio")]
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+        return Box::pin(task::FillTask(handle.spawn(fut)));
+    }
+    Box::pin(fut.map(Ok))
+}
+
+/// Read at most `size` bytes from inner reader.
+async fn fill<R: oio::Read>(mut r: R, seek: Option<u64>, size: usize) -> (R, Fill) {
+    let mut fill = Fill {
+        data: BytesMut::with_capacity(size),
+        eof: false,
+        err: None,
+    };
+
+    if let Some(pos) = seek {
+        if let Err(err) = r.seek(SeekFrom::Start(pos)).await {
+            fill.err = Some(err);
+            return (r, fill);
+        }
+    }
+
+    while fill.data.len() < size {
+        let len = fill.data.len();
+        fill.data
+            .resize(len + cmp::min(size - len, MAX_CHUNK_SIZE), 0);
+
+        let res = r.read(&mut fill.data[len..]).await;
+        match res {
+            Ok(0) => {
+                fill.data.truncate(len);
+                fill.eof = true;
+                break;
+            }
+            Ok(n) => fill.data.truncate(len + n),
+            Err(err) => {
+                fill.data.truncate(len);
+                fill.err = Some(err);
+                break;
+            }
+        }
+    }
+
+    (r, fill)
+}
+
+#[cfg(feature = "rt-tokio")]
+mod task {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::ready;
+    use std::task::Context;
+    use std::task::Poll;
+
+    use tokio::task::JoinHandle;
+
+    use super::Fill;
+    use crate::*;
+
+    /// FillTask is the spawned fill which will be aborted while dropping.
+    pub(super) struct FillTask<R>(pub(super) JoinHandle<(R, Fill)>);
+
+    impl<R> Future for FillTask<R> {
+        type Output = Result<(R, Fill)>;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let res = ready!(Pin::new(&mut self.0).poll(cx));
+            Poll::Ready(res.map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "prefetch task failed").set_source(err)
+            }))
+        }
+    }
+
+    impl<R> Drop for FillTask<R> {
+        fn drop(&mut self) {
+            self.0.abort();
+        }
+    }
+}
+
+impl<R: oio::Read + 'static> oio::Read for PrefetchReader<R> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        ready!(self.poll_prepare(cx))?;
+        if self.cur >= self.end() {
+            return Poll::Ready(Ok(0));
+        }
+
+        let offset = (self.cur - self.start) as usize;
+        let n = cmp::min(buf.len(), self.buf.len() - offset);
+        buf[..n].copy_from_slice(&self.buf[offset..offset + n]);
+
+        self.consume(cx, n);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        let (base, amt) = match pos {
+            SeekFrom::Start(n) => (0, n as i64),
+            SeekFrom::Current(n) => (self.cur as i64, n),
+            SeekFrom::End(n) => {
+                // Finish the in-flight fill before seeking inner reader.
+                if let State::Fill(_) = self.state {
+                    ready!(self.poll_fill(cx, 0))?;
+                }
+                let State::Idle(r) = &mut self.state else {
+                    return Poll::Ready(Err(Self::failed_error()));
+                };
+
+                // Let inner reader resolve the position, the buffered data is
+                // still valid since we will seek inner reader back before next fill.
+                let pos = ready!(r.poll_seek(cx, SeekFrom::End(n)))?;
+                self.need_seek = true;
+                (pos as i64, 0)
+            }
+        };
+
+        seek_to(&mut self.cur, base, amt).into()
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        if let Err(err) = ready!(self.poll_prepare(cx)) {
+            return Poll::Ready(Some(Err(err)));
+        }
+        if self.cur >= self.end() {
+            return Poll::Ready(None);
+        }
+
+        let offset = (self.cur - self.start) as usize;
+        let bs = Bytes::copy_from_slice(&self.buf[offset..]);
+
+        self.consume(cx, bs.len());
+        Poll::Ready(Some(Ok(bs)))
+    }
+}
+
+impl<R: oio::BlockingRead> PrefetchReader<R> {
+    /// Read ahead from inner reader until the window is full.
+    fn blocking_fill(&mut self) -> Result<usize> {
+        let size = cmp::max(self.ahead_size().unwrap_or_default(), self.chunk_size());
+        let seek = self.need_seek.then(|| self.end());
+        let State::Idle(r) = &mut self.state else {
+            return Err(Self::failed_error());
+        };
+
+        if let Some(pos) = seek {
+            r.seek(SeekFrom::Start(pos))?;
+            self.need_seek = false;
+        }
+
+        let mut fill = Fill {
+            data: BytesMut::new(),
+            eof: false,
+            err: None,
+        };
+        while fill.data.len() < size {
+            let len = fill.data.len();
+            fill.data.resize(size, 0);
+
+            match r.read(&mut fill.data[len..]) {
+                Ok(0) => {
+                    fill.data.truncate(len);
+                    fill.eof = true;
+                    break;
+                }
+                Ok(n) => fill.data.truncate(len + n),
+                Err(err) => {
+                    fill.data.truncate(len);
+                    fill.err = Some(err);
+                    break;
+                }
+            }
+        }
+
+        self.on_fill(fill)
+    }
+
+    /// Make sure the data at current position is buffered, or reader has
+    /// reached the end.
+    fn blocking_prepare(&mut self) -> Result<()> {
+        loop {
+            if self.is_ready() {
+                return Ok(());
+            }
+            if let Some(err) = self.err.take() {
+                return Err(err);
+            }
+
+            self.invalidate();
+            self.blocking_fill()?;
+            self.trim();
+        }
+    }
+}
+
+impl<R: oio::BlockingRead> oio::BlockingRead for PrefetchReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        self.blocking_prepare()?;
+        if self.cur >= self.end() {
+            return Ok(0);
+        }
+
+        let offset = (self.cur - self.start) as usize;
+        let n = cmp::min(buf.len(), self.buf.len() - offset);
+        buf[..n].copy_from_slice(&self.buf[offset..offset + n]);
+
+        self.cur += n as u64;
+        self.trim();
+        Ok(n)
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let (base, amt) = match pos {
+            SeekFrom::Start(n) => (0, n as i64),
+            SeekFrom::Current(n) => (self.cur as i64, n),
+            SeekFrom::End(n) => {
+                let State::Idle(r) = &mut self.state else {
+                    return Err(Self::failed_error());
+                };
+
+                let pos = r.seek(SeekFrom::End(n))?;
+                self.need_seek = true;
+                (pos as i64, 0)
+            }
+        };
+
+        seek_to(&mut self.cur, base, amt)
+    }
+
+    fn next(&mut self) -> Option<Result<Bytes>> {
+        if let Err(err) = self.blocking_prepare() {
+            return Some(Err(err));
+        }
+        if self.cur >= self.end() {
+            return None;
+        }
+
+        let offset = (self.cur - self.start) as usize;
+        let bs = Bytes::copy_from_slice(&self.buf[offset..]);
+
+        self.cur += bs.len() as u64;
+        self.trim();
+        Some(Ok(bs))
+    }
+}
+
+fn seek_to(cur: &mut u64, base: i64, amt: i64) -> Result<u64> {
+    match base.checked_add(amt) {
+        Some(n) if n >= 0 => {
+            *cur = n as u64;
+            Ok(*cur)
+        }
+        _ => Err(Error::new(
+            ErrorKind::InvalidInput,
+            "invalid seek to a negative or overflowing position",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::SeekFrom;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    use rand::prelude::*;
+
+    use super::*;
+    use crate::raw::oio::ReadExt;
+
+    /// SeekCountReader counts the seeks that hit the inner reader.
+    struct SeekCountReader {
+        inner: oio::Cursor,
+        seeks: Arc<AtomicUsize>,
+    }
+
+    impl oio::Read for SeekCountReader {
+        fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+            self.inner.poll_read(cx, buf)
+        }
+
+        fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+            self.seeks.fetch_add(1, Ordering::Relaxed);
+            self.inner.poll_seek(cx, pos)
+        }
+
+        fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+            self.inner.poll_next(cx)
+        }
+    }
+
+    fn gen_reader(size: usize) -> (Vec<u8>, PrefetchReader<SeekCountReader>, Arc<AtomicUsize>) {
+        let mut rng = thread_rng();
+        let mut content = vec![0; size];
+        rng.fill_bytes(&mut content);
+
+        let seeks = Arc::new(AtomicUsize::new(0));
+        let r = SeekCountReader {
+            inner: oio::Cursor::from(content.clone()),
+            seeks: seeks.clone(),
+        };
+
+        (content, PrefetchReader::new(r, 1024), seeks)
+    }
+
+    #[tokio::test]
+    async fn test_sequential_read() -> Result<()> {
+        let (content, mut r, seeks) = gen_reader(10 * 1024 + 7);
+
+        let mut bs = Vec::new();
+        let mut buf = vec![0; 100];
+        loop {
+            let n = r.read(&mut buf).await?;
+            if n == 0 {
+                break;
+            }
+            bs.extend_from_slice(&buf[..n]);
+        }
+
+        assert_eq!(bs, content);
+        assert_eq!(seeks.load(Ordering::Relaxed), 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_seek_in_window() -> Result<()> {
+        let (content, mut r, seeks) = gen_reader(10 * 1024);
+
+        let mut buf = vec![0; 16];
+
+        // Forward seek near the buffer should be coalesced.
+        for pos in [0, 100, 500, 1500, 1200, 2000] {
+            r.seek(SeekFrom::Start(pos)).await?;
+            r.read(&mut buf).await?;
+            assert_eq!(buf, content[pos as usize..pos as usize + 16]);
+        }
+        assert_eq!(seeks.load(Ordering::Relaxed), 0);
+
+        // Seek outside the window should invalidate the buffer.
+        r.seek(SeekFrom::Start(8000)).await?;
+        r.read(&mut buf).await?;
+        assert_eq!(buf, content[8000..8016]);
+        r.seek(SeekFrom::Start(10)).await?;
+        r.read(&mut buf).await?;
+        assert_eq!(buf, content[10..26]);
+        assert_eq!(seeks.load(Ordering::Relaxed), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_seek_from_end() -> Result<()> {
+        let (content, mut r, _) = gen_reader(10 * 1024);
+
+        let pos = r.seek(SeekFrom::End(-8)).await?;
+        assert_eq!(pos, 10 * 1024 - 8);
+
+        let mut bs = Vec::new();
+        r.read_to_end(&mut bs).await?;
+        assert_eq!(bs, content[10 * 1024 - 8..]);
+
+        let bs = r.next().await.transpose()?;
+        assert!(bs.is_none());
+        Ok(())
+    }
+
+    #[cfg(feature = "rt-tokio")]
+    #[tokio::test]
+    async fn test_prefetch_in_background() -> Result<()> {
+        let (content, mut r, _) = gen_reader(10 * 1024);
+
+        let mut buf = vec![0; 1000];
+        r.read(&mut buf).await?;
+        assert_eq!(buf, content[..1000]);
+
+        // Read ahead should keep going without polling the reader.
+        for _ in 0..16 {
+            tokio::task::yield_now().await;
+        }
+        assert!(matches!(r.state, State::Fill(_)));
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+        assert!(matches!(r.poll_fill(&mut cx, 0), Poll::Ready(Ok(1000))));
+        assert_eq!(r.end(), 2024);
+        Ok(())
+    }
+
+    #[test]
+    fn test_blocking_read() -> Result<()> {
+        let mut rng = thread_rng();
+        let mut content = vec![0; 10 * 1024 + 7];
+        rng.fill_bytes(&mut content);
+        let mut r = PrefetchReader::new(oio::Cursor::from(content.clone()), 1024);
+
+        let mut buf = vec![0; 16];
+        for pos in [0, 100, 1500, 1200, 8000, 10] {
+            oio::BlockingRead::seek(&mut r, SeekFrom::Start(pos))?;
+            oio::BlockingRead::read(&mut r, &mut buf)?;
+            assert_eq!(buf, content[pos as usize..pos as usize + 16]);
+        }
+
+        oio::BlockingRead::seek(&mut r, SeekFrom::End(-8))?;
+        let bs = oio::BlockingRead::next(&mut r).transpose()?;
+        assert_eq!(bs.as_deref(), Some(&content[10 * 1024 - 1..]));
+        Ok(())
+    }
+}
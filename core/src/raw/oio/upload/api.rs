@@ -0,0 +1,47 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use async_trait::async_trait;
+
+use crate::*;
+
+/// UploadPage is used by [`raw::Accessor`] to implement `list_uploads`.
+#[async_trait]
+pub trait UploadPage: Send + Sync + 'static {
+    /// Fetch a new page of [`Upload`]
+    ///
+    /// `Ok(None)` means all pages have been returned. Any following call
+    /// to `next` will always get the same result.
+    async fn next(&mut self) -> Result<Option<Vec<Upload>>>;
+}
+
+/// The boxed version of [`UploadPage`]
+pub type UploadPager = Box<dyn UploadPage>;
+
+#[async_trait]
+impl<P: UploadPage + ?Sized> UploadPage for Box<P> {
+    async fn next(&mut self) -> Result<Option<Vec<Upload>>> {
+        (**self).next().await
+    }
+}
+
+#[async_trait]
+impl UploadPage for () {
+    async fn next(&mut self) -> Result<Option<Vec<Upload>>> {
+        Ok(None)
+    }
+}
@@ -0,0 +1,290 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::raw::*;
+use crate::*;
+
+/// MultipartUploadList is used to implement [`oio::UploadPage`] based on
+/// `ListMultipartUploads` like APIs. By implementing MultipartUploadList,
+/// services don't need to care about the details of paging.
+///
+/// # Architecture
+///
+/// - Services impl `MultipartUploadList`
+/// - `MultipartUploadPager` impl `UploadPage`
+/// - Return `MultipartUploadPager` in `Accessor::list_uploads`
+#[async_trait]
+pub trait MultipartUploadList: Send + Sync + 'static {
+    /// list_multipart_uploads will list a page of incomplete uploads after
+    /// given markers.
+    ///
+    /// Markers are empty for the first page.
+    async fn list_multipart_uploads(
+        &self,
+        key_marker: &str,
+        upload_id_marker: &str,
+    ) -> Result<ListMultipartUploadsResult>;
+}
+
+/// Result of ListMultipartUploads.
+///
+/// S3 and S3 compatible services like oss, cos and obs share the same
+/// response format.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListMultipartUploadsResult {
+    /// Whether there are more uploads after this page.
+    pub is_truncated: bool,
+    /// The key marker of next page.
+    pub next_key_marker: String,
+    /// The upload id marker of next page.
+    pub next_upload_id_marker: String,
+    /// Uploads in this page.
+    pub upload: Vec<ListMultipartUploadsResultUpload>,
+}
+
+/// Upload in [`ListMultipartUploadsResult`].
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListMultipartUploadsResultUpload {
+    /// The absolute key of this upload.
+    pub key: String,
+    /// The id of this upload.
+    pub upload_id: String,
+    /// The initiated time of this upload in RFC 3339.
+    pub initiated: String,
+}
+
+/// MultipartUploadPager will implement [`oio::UploadPage`] based on
+/// [`MultipartUploadList`].
+pub struct MultipartUploadPager<L: MultipartUploadList> {
+    inner: L,
+    root: String,
+
+    key_marker: String,
+    upload_id_marker: String,
+    done: bool,
+}
+
+impl<L: MultipartUploadList> MultipartUploadPager<L> {
+    /// Create a new MultipartUploadPager.
+    ///
+    /// Keys returned by services will be converted to paths relative to `root`.
+    pub fn new(inner: L, root: &str) -> Self {
+        Self {
+            inner,
+            root: root.to_string(),
+
+            key_marker: String::new(),
+            upload_id_marker: String::new(),
+            done: false,
+        }
+    }
+}
+
+#[async_trait]
+impl<L: MultipartUploadList> oio::UploadPage for MultipartUploadPager<L> {
+    async fn next(&mut self) -> Result<Option<Vec<Upload>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let output = self
+            .inner
+            .list_multipart_uploads(&self.key_marker, &self.upload_id_marker)
+            .await?;
+
+        let uploads = output
+            .upload
+            .into_iter()
+            .map(|upload| {
+                let path = build_rel_path(&self.root, &upload.key);
+                let initiated = parse_datetime_from_rfc3339(&upload.initiated)?;
+                Ok(Upload::new(&path, &upload.upload_id).with_initiated(initiated))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if output.is_truncated {
+            self.key_marker = output.next_key_marker;
+            self.upload_id_marker = output.next_upload_id_marker;
+        } else {
+            self.done = true;
+        }
+
+        Ok(Some(uploads))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Buf;
+    use bytes::Bytes;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::raw::oio::UploadPage;
+
+    /// MockList returns one upload per page and uses upload id as marker.
+    struct MockList {
+        uploads: Vec<(&'static str, &'static str)>,
+    }
+
+    #[async_trait]
+    impl MultipartUploadList for MockList {
+        async fn list_multipart_uploads(
+            &self,
+            key_marker: &str,
+            upload_id_marker: &str,
+        ) -> Result<ListMultipartUploadsResult> {
+            let idx = self
+                .uploads
+                .iter()
+                .position(|(_, id)| *id == upload_id_marker)
+                .map(|v| v + 1)
+                .unwrap_or_default();
+            if idx > 0 {
+                assert_eq!(self.uploads[idx - 1].0, key_marker);
+            }
+
+            let (key, id) = self.uploads[idx];
+            Ok(ListMultipartUploadsResult {
+                is_truncated: idx + 1 < self.uploads.len(),
+                next_key_marker: key.to_string(),
+                next_upload_id_marker: id.to_string(),
+                upload: vec![ListMultipartUploadsResultUpload {
+                    key: key.to_string(),
+                    upload_id: id.to_string(),
+                    initiated: "2010-11-10T20:48:33.000Z".to_string(),
+                }],
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_multipart_upload_pager() -> Result<()> {
+        let mut p = MultipartUploadPager::new(
+            MockList {
+                uploads: vec![("root/a", "1"), ("root/a", "2"), ("root/b", "3")],
+            },
+            "/root/",
+        );
+
+        let mut uploads = vec![];
+        while let Some(page) = p.next().await? {
+            uploads.extend(page);
+        }
+
+        let initiated = parse_datetime_from_rfc3339("2010-11-10T20:48:33.000Z")?;
+        assert_eq!(
+            uploads,
+            vec![
+                Upload::new("a", "1").with_initiated(initiated),
+                Upload::new("a", "2").with_initiated(initiated),
+                Upload::new("b", "3").with_initiated(initiated),
+            ]
+        );
+        assert!(p.next().await?.is_none());
+        Ok(())
+    }
+
+    /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_ListMultipartUploads.html#API_ListMultipartUploads_Examples
+    #[test]
+    fn test_deserialize_s3_list_multipart_uploads_result() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <ListMultipartUploadsResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+              <Bucket>bucket</Bucket>
+              <KeyMarker></KeyMarker>
+              <UploadIdMarker></UploadIdMarker>
+              <NextKeyMarker>my-movie.m2ts</NextKeyMarker>
+              <NextUploadIdMarker>YW55IGlkZWEgd2h5IGVsdmluZydzIHVwbG9hZCBmYWlsZWQ</NextUploadIdMarker>
+              <MaxUploads>3</MaxUploads>
+              <IsTruncated>true</IsTruncated>
+              <Upload>
+                <Key>my-divisor</Key>
+                <UploadId>XMgbGlrZSBlbHZpbmcncyBub3QgaGF2aW5nIG11Y2ggbHVjaw</UploadId>
+                <StorageClass>STANDARD</StorageClass>
+                <Initiated>2010-11-10T20:48:33.000Z</Initiated>
+              </Upload>
+              <Upload>
+                <Key>my-movie.m2ts</Key>
+                <UploadId>VXBsb2FkIElEIGZvciBlbHZpbmcncyBteS1tb3ZpZS5tMnRzIHVwbG9hZA</UploadId>
+                <StorageClass>STANDARD</StorageClass>
+                <Initiated>2010-11-10T20:48:33.000Z</Initiated>
+              </Upload>
+            </ListMultipartUploadsResult>"#,
+        );
+
+        let out: ListMultipartUploadsResult =
+            quick_xml::de::from_reader(bs.reader()).expect("must success");
+
+        assert!(out.is_truncated);
+        assert_eq!(out.next_key_marker, "my-movie.m2ts");
+        assert_eq!(
+            out.next_upload_id_marker,
+            "YW55IGlkZWEgd2h5IGVsdmluZydzIHVwbG9hZCBmYWlsZWQ"
+        );
+        assert_eq!(out.upload.len(), 2);
+        assert_eq!(out.upload[0].key, "my-divisor");
+        assert_eq!(
+            out.upload[0].upload_id,
+            "XMgbGlrZSBlbHZpbmcncyBub3QgaGF2aW5nIG11Y2ggbHVjaw"
+        );
+        assert_eq!(out.upload[1].initiated, "2010-11-10T20:48:33.000Z");
+    }
+
+    /// This example is from https://www.alibabacloud.com/help/en/oss/developer-reference/listmultipartuploads
+    #[test]
+    fn test_deserialize_oss_list_multipart_uploads_result() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <ListMultipartUploadsResult xmlns="http://doc.oss-cn-hangzhou.aliyuncs.com">
+              <Bucket>oss-example</Bucket>
+              <KeyMarker></KeyMarker>
+              <UploadIdMarker></UploadIdMarker>
+              <NextKeyMarker>oss.avi</NextKeyMarker>
+              <NextUploadIdMarker>0004B99B8E707874FC2D692FA5D77D3F</NextUploadIdMarker>
+              <Delimiter></Delimiter>
+              <Prefix></Prefix>
+              <MaxUploads>1000</MaxUploads>
+              <IsTruncated>false</IsTruncated>
+              <Upload>
+                <Key>multipart.data</Key>
+                <UploadId>0004B999EF518A1FE585B0C9360DC4C8</UploadId>
+                <Initiated>2012-02-23T04:18:23.000Z</Initiated>
+              </Upload>
+              <Upload>
+                <Key>oss.avi</Key>
+                <UploadId>0004B99B8E707874FC2D692FA5D77D3F</UploadId>
+                <Initiated>2012-02-23T06:14:27.000Z</Initiated>
+              </Upload>
+            </ListMultipartUploadsResult>"#,
+        );
+
+        let out: ListMultipartUploadsResult =
+            quick_xml::de::from_reader(bs.reader()).expect("must success");
+
+        assert!(!out.is_truncated);
+        assert_eq!(out.upload.len(), 2);
+        assert_eq!(out.upload[0].key, "multipart.data");
+        assert_eq!(out.upload[0].upload_id, "0004B999EF518A1FE585B0C9360DC4C8");
+        assert_eq!(out.upload[1].initiated, "2012-02-23T06:14:27.000Z");
+    }
+}
@@ -0,0 +1,47 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use async_trait::async_trait;
+
+use crate::*;
+
+/// Watch trait is used by [`raw::Accessor`] to implement `watch` operation.
+#[async_trait]
+pub trait Watch: Send + Sync + 'static {
+    /// Fetch the next batch of [`WatchEvent`]
+    ///
+    /// This function will wait until new events arrived. `Ok(None)` means
+    /// the watcher has been closed and no more events will be returned.
+    async fn next(&mut self) -> Result<Option<Vec<WatchEvent>>>;
+}
+
+/// The boxed version of [`Watch`]
+pub type Watcher = Box<dyn Watch>;
+
+#[async_trait]
+impl<W: Watch + ?Sized> Watch for Box<W> {
+    async fn next(&mut self) -> Result<Option<Vec<WatchEvent>>> {
+        (**self).next().await
+    }
+}
+
+#[async_trait]
+impl Watch for () {
+    async fn next(&mut self) -> Result<Option<Vec<WatchEvent>>> {
+        Ok(None)
+    }
+}
@@ -93,6 +93,15 @@ pub trait Write: Unpin + Send + Sync {
 
     /// Abort the pending writer.
     fn poll_abort(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>>;
+
+    /// Return the checkpoint of current upload, which could be used to
+    /// resume the upload later.
+    ///
+    /// Returns `None` if the writer doesn't support resuming or there is
+    /// nothing uploaded durably yet.
+    fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        None
+    }
 }
 
 #[async_trait]
@@ -132,6 +141,10 @@ impl<T: Write + ?Sized> Write for Box<T> {
     fn poll_abort(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
         (**self).poll_abort(cx)
     }
+
+    fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        (**self).checkpoint()
+    }
 }
 
 /// Impl WriteExt for all T: Write
@@ -78,6 +78,13 @@ impl<ONE: oio::Write, TWO: oio::Write> oio::Write for TwoWaysWriter<ONE, TWO> {
             Self::Two(two) => two.poll_abort(cx),
         }
     }
+
+    fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        match self {
+            Self::One(one) => one.checkpoint(),
+            Self::Two(two) => two.checkpoint(),
+        }
+    }
 }
 
 /// ThreeWaysWriter is used to implement [`Write`] based on three ways.
@@ -119,4 +126,12 @@ impl<ONE: oio::Write, TWO: oio::Write, THREE: oio::Write> oio::Write
             Self::Three(three) => three.poll_abort(cx),
         }
     }
+
+    fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        match self {
+            Self::One(one) => one.checkpoint(),
+            Self::Two(two) => two.checkpoint(),
+            Self::Three(three) => three.checkpoint(),
+        }
+    }
 }
@@ -73,6 +73,10 @@ impl<W: oio::Write> oio::Write for ExactBufWriter<W> {
         self.inner.poll_abort(cx)
     }
 
+    fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        self.inner.checkpoint()
+    }
+
     fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
         while !self.buffer.is_empty() {
             let n = ready!(self.inner.poll_write(cx, &self.buffer))?;
@@ -48,6 +48,12 @@ use crate::*;
 /// ```
 ///
 /// We will use `write_once` instead of starting a new multipart upload.
+///
+/// # Resume
+///
+/// `MultipartUploadWriter` can be resumed from a [`WriteCheckpoint`] via
+/// [`MultipartUploadWriter::with_checkpoint`]. The upload id and completed
+/// parts will be reused, and new parts will be appended after them.
 #[async_trait]
 pub trait MultipartUploadWrite: Send + Sync + Unpin + 'static {
     /// write_once is used to write the data to underlying storage at once.
@@ -111,6 +117,8 @@ pub struct MultipartUploadWriter<W: MultipartUploadWrite> {
     cache: Option<oio::ChunkedBytes>,
     upload_id: Option<Arc<String>>,
     parts: Vec<MultipartUploadPart>,
+    /// The size of all completed parts.
+    offset: u64,
 }
 
 enum State<W> {
@@ -135,7 +143,25 @@ impl<W: MultipartUploadWrite> MultipartUploadWriter<W> {
             cache: None,
             upload_id: None,
             parts: Vec::new(),
+            offset: 0,
+        }
+    }
+
+    /// Resume the upload from given checkpoint.
+    pub fn with_checkpoint(mut self, checkpoint: Option<WriteCheckpoint>) -> Self {
+        if let Some(cp) = checkpoint {
+            self.upload_id = Some(Arc::new(cp.upload_id().to_string()));
+            self.parts = cp
+                .parts()
+                .iter()
+                .map(|(part_number, etag)| MultipartUploadPart {
+                    part_number: *part_number,
+                    etag: etag.clone(),
+                })
+                .collect();
+            self.offset = cp.offset();
         }
+        self
     }
 }
 
@@ -150,6 +176,14 @@ where
                 State::Idle(w) => {
                     match self.upload_id.as_ref() {
                         Some(upload_id) => {
+                            // Fill cache with the first write after resumed.
+                            if self.cache.is_none() {
+                                let size = bs.remaining();
+                                let cb = oio::ChunkedBytes::from_vec(bs.vectored_bytes(size));
+                                self.cache = Some(cb);
+                                return Poll::Ready(Ok(size));
+                            }
+
                             let upload_id = upload_id.clone();
                             let part_number = self.parts.len();
 
@@ -195,6 +229,7 @@ where
                     let (w, part) = ready!(fut.as_mut().poll(cx));
                     self.state = State::Idle(Some(w));
                     self.parts.push(part?);
+                    self.offset += self.cache.as_ref().map_or(0, |bs| bs.len() as u64);
 
                     // Replace the cache when last write succeeded
                     let size = bs.remaining();
@@ -284,6 +319,7 @@ where
                     let (w, part) = ready!(fut.as_mut().poll(cx));
                     self.state = State::Idle(Some(w));
                     self.parts.push(part?);
+                    self.offset += self.cache.as_ref().map_or(0, |bs| bs.len() as u64);
                     self.cache = None;
                 }
                 State::Abort(_) => unreachable!(
@@ -328,4 +364,138 @@ where
             }
         }
     }
+
+    fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        let upload_id = self.upload_id.as_ref()?;
+        let parts = self
+            .parts
+            .iter()
+            .map(|p| (p.part_number, p.etag.clone()))
+            .collect();
+        Some(WriteCheckpoint::new(upload_id, parts, self.offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use bytes::Bytes;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::raw::oio::Write;
+    use crate::raw::oio::WriteBuf;
+    use crate::raw::oio::WriteExt;
+
+    #[derive(Default)]
+    struct MockStorage {
+        uploads: HashMap<String, Vec<(usize, Bytes)>>,
+        content: Option<Bytes>,
+    }
+
+    #[derive(Clone, Default)]
+    struct MockWriter {
+        storage: Arc<Mutex<MockStorage>>,
+    }
+
+    #[async_trait]
+    impl MultipartUploadWrite for MockWriter {
+        async fn write_once(&self, _: u64, _: AsyncBody) -> Result<()> {
+            unreachable!("write_once must not be called while resuming")
+        }
+
+        async fn initiate_part(&self) -> Result<String> {
+            let mut storage = self.storage.lock().unwrap();
+            let upload_id = format!("upload-{}", storage.uploads.len());
+            storage.uploads.insert(upload_id.clone(), vec![]);
+            Ok(upload_id)
+        }
+
+        async fn write_part(
+            &self,
+            upload_id: &str,
+            part_number: usize,
+            size: u64,
+            body: AsyncBody,
+        ) -> Result<MultipartUploadPart> {
+            let AsyncBody::ChunkedBytes(cb) = body else {
+                unreachable!("body must be chunked bytes")
+            };
+            let bs = cb.bytes(size as usize);
+
+            // Services use their own part number like S3 starts from 1.
+            let part_number = part_number + 1;
+            let mut storage = self.storage.lock().unwrap();
+            storage
+                .uploads
+                .get_mut(upload_id)
+                .expect("upload must exist")
+                .push((part_number, bs));
+
+            Ok(MultipartUploadPart {
+                part_number,
+                etag: format!("etag-{part_number}"),
+            })
+        }
+
+        async fn complete_part(
+            &self,
+            upload_id: &str,
+            parts: &[MultipartUploadPart],
+        ) -> Result<()> {
+            let mut storage = self.storage.lock().unwrap();
+            let uploaded = storage
+                .uploads
+                .remove(upload_id)
+                .expect("upload must exist");
+
+            let mut content = vec![];
+            for part in parts {
+                let (_, bs) = uploaded
+                    .iter()
+                    .find(|(n, _)| *n == part.part_number)
+                    .expect("part must be uploaded");
+                content.extend_from_slice(bs);
+            }
+            storage.content = Some(content.into());
+            Ok(())
+        }
+
+        async fn abort_part(&self, upload_id: &str) -> Result<()> {
+            self.storage.lock().unwrap().uploads.remove(upload_id);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_multipart_upload_writer_resume() -> Result<()> {
+        let mock = MockWriter::default();
+        let chunks: Vec<Bytes> = (0..4u8)
+            .map(|i| Bytes::from(vec![i; 10 + i as usize]))
+            .collect();
+
+        let mut w = MultipartUploadWriter::new(mock.clone());
+        assert_eq!(w.checkpoint(), None);
+        for bs in &chunks[..3] {
+            w.write(bs).await?;
+        }
+
+        // The last chunk is still in cache and not uploaded yet.
+        let checkpoint = w.checkpoint().expect("checkpoint must exist");
+        assert_eq!(checkpoint.parts().len(), 2);
+        assert_eq!(checkpoint.offset(), 21);
+        drop(w);
+
+        let mut w = MultipartUploadWriter::new(mock.clone()).with_checkpoint(Some(checkpoint));
+        for bs in &chunks[2..] {
+            w.write(bs).await?;
+        }
+        w.close().await?;
+
+        let content = mock.storage.lock().unwrap().content.clone();
+        assert_eq!(content, Some(chunks.concat().into()));
+        Ok(())
+    }
 }
@@ -48,6 +48,12 @@ use crate::*;
 /// - Services impl `RangeWrite`
 /// - `RangeWriter` impl `Write`
 /// - Expose `RangeWriter` as `Accessor::Writer`
+///
+/// # Resume
+///
+/// `RangeWriter` can be resumed from a [`WriteCheckpoint`] via
+/// [`RangeWriter::with_checkpoint`]. The location will be reused and new
+/// data will be written after the checkpoint's offset.
 #[async_trait]
 pub trait RangeWrite: Send + Sync + Unpin + 'static {
     /// write_once is used to write the data to underlying storage at once.
@@ -115,6 +121,15 @@ impl<W: RangeWrite> RangeWriter<W> {
             written: 0,
         }
     }
+
+    /// Resume the range write from given checkpoint.
+    pub fn with_checkpoint(mut self, checkpoint: Option<WriteCheckpoint>) -> Self {
+        if let Some(cp) = checkpoint {
+            self.location = Some(cp.upload_id().to_string());
+            self.written = cp.offset();
+        }
+        self
+    }
 }
 
 impl<W: RangeWrite> oio::Write for RangeWriter<W> {
@@ -124,6 +139,14 @@ impl<W: RangeWrite> oio::Write for RangeWriter<W> {
                 State::Idle(w) => {
                     match self.location.clone() {
                         Some(location) => {
+                            // Fill buffer with the first write after resumed.
+                            if self.buffer.is_none() {
+                                let size = bs.remaining();
+                                let cb = oio::ChunkedBytes::from_vec(bs.vectored_bytes(size));
+                                self.buffer = Some(cb);
+                                return Poll::Ready(Ok(size));
+                            }
+
                             let written = self.written;
 
                             let buffer = self.buffer.clone().expect("cache must be valid").clone();
@@ -209,7 +232,13 @@ impl<W: RangeWrite> oio::Write for RangeWriter<W> {
                                     }));
                                 }
                                 None => {
-                                    unreachable!("It's must be bug that RangeWrite is in State::Idle with no cache but has location")
+                                    // Resumed without any new data, complete with what we have.
+                                    self.state = State::Complete(Box::pin(async move {
+                                        let res = w
+                                            .complete_range(&location, written, 0, AsyncBody::Empty)
+                                            .await;
+                                        (w, res)
+                                    }));
                                 }
                             }
                         }
@@ -289,4 +318,9 @@ impl<W: RangeWrite> oio::Write for RangeWriter<W> {
             }
         }
     }
+
+    fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        let location = self.location.as_ref()?;
+        Some(WriteCheckpoint::new(location, vec![], self.written))
+    }
 }
@@ -45,6 +45,26 @@ pub enum Operation {
     Batch,
     /// Operation for [`crate::raw::Accessor::presign`]
     Presign,
+    /// Operation for [`crate::raw::Accessor::summary`]
+    Summary,
+    /// Operation for [`crate::raw::Accessor::watch`]
+    Watch,
+    /// Operation for [`crate::raw::Accessor::get_tags`]
+    GetTags,
+    /// Operation for [`crate::raw::Accessor::set_tags`]
+    SetTags,
+    /// Operation for [`crate::raw::Accessor::restore`]
+    Restore,
+    /// Operation for [`crate::raw::Accessor::initiate_multipart`]
+    InitiateMultipart,
+    /// Operation for [`crate::raw::Accessor::complete_multipart`]
+    CompleteMultipart,
+    /// Operation for [`crate::raw::Accessor::presign_post`]
+    PresignPost,
+    /// Operation for [`crate::raw::Accessor::list_uploads`]
+    ListUploads,
+    /// Operation for [`crate::raw::Accessor::abort_upload`]
+    AbortUpload,
     /// Operation for [`crate::raw::Accessor::blocking_create_dir`]
     BlockingCreateDir,
     /// Operation for [`crate::raw::Accessor::blocking_read`]
@@ -90,6 +110,16 @@ impl From<Operation> for &'static str {
             Operation::List => "list",
             Operation::Presign => "presign",
             Operation::Batch => "batch",
+            Operation::Summary => "summary",
+            Operation::Watch => "watch",
+            Operation::GetTags => "get_tags",
+            Operation::SetTags => "set_tags",
+            Operation::Restore => "restore",
+            Operation::InitiateMultipart => "initiate_multipart",
+            Operation::CompleteMultipart => "complete_multipart",
+            Operation::PresignPost => "presign_post",
+            Operation::ListUploads => "list_uploads",
+            Operation::AbortUpload => "abort_upload",
             Operation::BlockingCreateDir => "blocking_create_dir",
             Operation::BlockingRead => "blocking_read",
             Operation::BlockingWrite => "blocking_write",
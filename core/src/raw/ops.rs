@@ -19,12 +19,15 @@
 //!
 //! By using ops, users can add more context for operation.
 
+use std::collections::HashMap;
 use std::time::Duration;
 
 use flagset::FlagSet;
 
 use crate::raw::*;
 use crate::Metakey;
+use crate::PostConditions;
+use crate::WriteCheckpoint;
 
 /// Args for `create` operation.
 ///
@@ -45,6 +48,7 @@ impl OpCreateDir {
 #[derive(Debug, Clone, Default)]
 pub struct OpDelete {
     version: Option<String>,
+    recursive: bool,
 }
 
 impl OpDelete {
@@ -65,6 +69,19 @@ impl OpDelete {
     pub fn version(&self) -> Option<&str> {
         self.version.as_deref()
     }
+
+    /// Change the recursive flag of this delete operation.
+    ///
+    /// If set, deleting a dir will also delete all entries inside it.
+    pub fn with_recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Get the recursive flag of this delete operation.
+    pub fn recursive(&self) -> bool {
+        self.recursive
+    }
 }
 
 /// Args for `list` operation.
@@ -82,6 +99,10 @@ pub struct OpList {
     delimiter: String,
 
     metakey: FlagSet<Metakey>,
+
+    /// The max number of stat operations to run concurrently while
+    /// completing the metadata required by `metakey`. Default to be `1`.
+    concurrent: usize,
 }
 
 impl Default for OpList {
@@ -92,6 +113,7 @@ impl Default for OpList {
             delimiter: "/".to_string(),
             // By default, we want to know what's the mode of this entry.
             metakey: Metakey::Mode.into(),
+            concurrent: 1,
         }
     }
 }
@@ -147,6 +169,19 @@ impl OpList {
     pub fn metakey(&self) -> FlagSet<Metakey> {
         self.metakey
     }
+
+    /// Change the concurrent of stat operations while completing metadata.
+    ///
+    /// `0` will be treated as `1`.
+    pub fn with_concurrent(mut self, concurrent: usize) -> Self {
+        self.concurrent = concurrent.max(1);
+        self
+    }
+
+    /// Get the concurrent of stat operations while completing metadata.
+    pub fn concurrent(&self) -> usize {
+        self.concurrent
+    }
 }
 
 /// Args for `presign` operation.
@@ -179,6 +214,32 @@ impl OpPresign {
     }
 }
 
+/// Args for `presign_post` operation.
+///
+/// The path of `presign_post` is the prefix that uploaded keys must start with.
+#[derive(Debug, Clone)]
+pub struct OpPresignPost {
+    expire: Duration,
+    conditions: PostConditions,
+}
+
+impl OpPresignPost {
+    /// Create a new `OpPresignPost`.
+    pub fn new(conditions: PostConditions, expire: Duration) -> Self {
+        Self { expire, conditions }
+    }
+
+    /// Get conditions from op.
+    pub fn conditions(&self) -> &PostConditions {
+        &self.conditions
+    }
+
+    /// Get expire from op.
+    pub fn expire(&self) -> Duration {
+        self.expire
+    }
+}
+
 /// Presign operation used for presign.
 #[derive(Debug, Clone)]
 #[non_exhaustive]
@@ -189,6 +250,8 @@ pub enum PresignOperation {
     Read(OpRead),
     /// Presign a write operation.
     Write(OpWrite),
+    /// Presign a part upload of a multipart upload.
+    WritePart(OpWritePart),
 }
 
 impl From<OpStat> for PresignOperation {
@@ -209,6 +272,12 @@ impl From<OpWrite> for PresignOperation {
     }
 }
 
+impl From<OpWritePart> for PresignOperation {
+    fn from(v: OpWritePart) -> Self {
+        Self::WritePart(v)
+    }
+}
+
 /// Args for `batch` operation.
 #[derive(Debug, Clone)]
 pub struct OpBatch {
@@ -238,6 +307,10 @@ impl OpBatch {
 pub enum BatchOperation {
     /// Batch delete operation.
     Delete(OpDelete),
+    /// Batch stat operation.
+    Stat(OpStat),
+    /// Batch copy operation, the first field is the target path.
+    Copy(String, OpCopy),
 }
 
 impl From<OpDelete> for BatchOperation {
@@ -246,12 +319,20 @@ impl From<OpDelete> for BatchOperation {
     }
 }
 
+impl From<OpStat> for BatchOperation {
+    fn from(op: OpStat) -> Self {
+        Self::Stat(op)
+    }
+}
+
 impl BatchOperation {
     /// Return the operation of this batch.
     pub fn operation(&self) -> Operation {
         use BatchOperation::*;
         match self {
             Delete(_) => Operation::Delete,
+            Stat(_) => Operation::Stat,
+            Copy(_, _) => Operation::Copy,
         }
     }
 }
@@ -266,6 +347,7 @@ pub struct OpRead {
     override_cache_control: Option<String>,
     override_content_disposition: Option<String>,
     version: Option<String>,
+    read_ahead: Option<usize>,
 }
 
 impl OpRead {
@@ -368,6 +450,20 @@ impl OpRead {
     pub fn version(&self) -> Option<&str> {
         self.version.as_deref()
     }
+
+    /// Set the read ahead window of the option.
+    ///
+    /// If read ahead is set, reader will fetch the next `size` bytes ahead of current
+    /// position, so that small reads and nearby seeks could be served from memory.
+    pub fn with_read_ahead(mut self, size: usize) -> Self {
+        self.read_ahead = Some(size);
+        self
+    }
+
+    /// Get read ahead window from option
+    pub fn read_ahead(&self) -> Option<usize> {
+        self.read_ahead
+    }
 }
 
 /// Args for `stat` operation.
@@ -422,11 +518,16 @@ impl OpStat {
 #[derive(Debug, Clone, Default)]
 pub struct OpWrite {
     append: bool,
+    offset: Option<u64>,
     buffer: Option<usize>,
 
     content_type: Option<String>,
     content_disposition: Option<String>,
     cache_control: Option<String>,
+    storage_class: Option<String>,
+    tags: Option<HashMap<String, String>>,
+    resume: Option<WriteCheckpoint>,
+    if_not_exists: bool,
 }
 
 impl OpWrite {
@@ -456,6 +557,27 @@ impl OpWrite {
         self
     }
 
+    /// Get the offset from op.
+    ///
+    /// The offset is the position in file that this write operation starts at.
+    pub fn offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    /// Set the offset of op.
+    ///
+    /// If the offset is set, the data will be written at the given position of the
+    /// file without truncating it. The file will be created if not exist.
+    ///
+    /// # Notes
+    ///
+    /// Service could return `Unsupported` if the underlying storage does not support
+    /// positional write.
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
     /// Get the buffer from op.
     ///
     /// The buffer is used by service to decide the buffer size of the underlying writer.
@@ -508,6 +630,56 @@ impl OpWrite {
         self.cache_control = Some(cache_control.to_string());
         self
     }
+
+    /// Get the storage class from option
+    pub fn storage_class(&self) -> Option<&str> {
+        self.storage_class.as_deref()
+    }
+
+    /// Set the storage class of option
+    pub fn with_storage_class(mut self, storage_class: &str) -> Self {
+        self.storage_class = Some(storage_class.to_string());
+        self
+    }
+
+    /// Get the tags from option
+    pub fn tags(&self) -> Option<&HashMap<String, String>> {
+        self.tags.as_ref()
+    }
+
+    /// Set the tags of option
+    pub fn with_tags(mut self, tags: HashMap<String, String>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+
+    /// Get the if_not_exists flag from option.
+    pub fn if_not_exists(&self) -> bool {
+        self.if_not_exists
+    }
+
+    /// Set the if_not_exists flag of option.
+    ///
+    /// If set, the write will fail with `ConditionNotMatch` if the file
+    /// already exists.
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Get the checkpoint to resume from.
+    pub fn resume(&self) -> Option<&WriteCheckpoint> {
+        self.resume.as_ref()
+    }
+
+    /// Set the checkpoint to resume from.
+    ///
+    /// The writer will continue the upload recorded in the checkpoint instead
+    /// of starting a new one.
+    pub fn with_resume(mut self, checkpoint: WriteCheckpoint) -> Self {
+        self.resume = Some(checkpoint);
+        self
+    }
 }
 
 /// Args for `copy` operation.
@@ -531,3 +703,200 @@ impl OpRename {
         Self::default()
     }
 }
+
+/// Args for `watch` operation.
+#[derive(Debug, Clone)]
+pub struct OpWatch {
+    poll_interval: Duration,
+}
+
+impl Default for OpWatch {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(10),
+        }
+    }
+}
+
+impl OpWatch {
+    /// Create a new `OpWatch`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Change the interval to poll changes while services don't support
+    /// watch natively.
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Get the interval to poll changes.
+    pub fn poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+}
+
+/// Args for `summary` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpSummary {}
+
+impl OpSummary {
+    /// Create a new `OpSummary`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `get_tags` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpGetTags {}
+
+impl OpGetTags {
+    /// Create a new `OpGetTags`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `set_tags` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpSetTags {
+    tags: HashMap<String, String>,
+}
+
+impl OpSetTags {
+    /// Create a new `OpSetTags`.
+    pub fn new(tags: HashMap<String, String>) -> Self {
+        Self { tags }
+    }
+
+    /// Get the tags from op.
+    pub fn tags(&self) -> &HashMap<String, String> {
+        &self.tags
+    }
+
+    /// Consume op to get the tags.
+    pub fn into_tags(self) -> HashMap<String, String> {
+        self.tags
+    }
+}
+
+/// Args for `restore` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpRestore {
+    days: u32,
+}
+
+impl OpRestore {
+    /// Create a new `OpRestore`.
+    pub fn new(days: u32) -> Self {
+        Self { days }
+    }
+
+    /// Get the days that the restored copy will be kept.
+    pub fn days(&self) -> u32 {
+        self.days
+    }
+}
+
+/// Args for `initiate_multipart` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpInitiateMultipart {}
+
+impl OpInitiateMultipart {
+    /// Create a new `OpInitiateMultipart`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for presigning a part upload of a multipart upload.
+#[derive(Debug, Clone, Default)]
+pub struct OpWritePart {
+    upload_id: String,
+    part_number: usize,
+}
+
+impl OpWritePart {
+    /// Create a new `OpWritePart`.
+    ///
+    /// The part number starts from 0, services will convert it into
+    /// their own numbering.
+    pub fn new(upload_id: &str, part_number: usize) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+            part_number,
+        }
+    }
+
+    /// Get the upload id of this part.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// Get the part number of this part, starting from 0.
+    pub fn part_number(&self) -> usize {
+        self.part_number
+    }
+}
+
+/// Args for `complete_multipart` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpCompleteMultipart {
+    upload_id: String,
+    etags: Vec<String>,
+}
+
+impl OpCompleteMultipart {
+    /// Create a new `OpCompleteMultipart`.
+    ///
+    /// `etags` are the etags of all uploaded parts in the order of part number.
+    pub fn new(upload_id: &str, etags: Vec<String>) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+            etags,
+        }
+    }
+
+    /// Get the upload id of this multipart upload.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// Get the etags of all parts in order.
+    pub fn etags(&self) -> &[String] {
+        &self.etags
+    }
+}
+
+/// Args for `list_uploads` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpListUploads {}
+
+impl OpListUploads {
+    /// Create a new `OpListUploads`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `abort_upload` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpAbortUpload {
+    upload_id: String,
+}
+
+impl OpAbortUpload {
+    /// Create a new `OpAbortUpload`.
+    pub fn new(upload_id: &str) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+        }
+    }
+
+    /// Get the upload id to abort.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+}
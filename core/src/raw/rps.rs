@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
+
 use http::Request;
 
 use crate::*;
@@ -83,6 +85,85 @@ impl PresignedRequest {
     }
 }
 
+/// Reply for `presign_post` operation.
+#[derive(Debug, Clone)]
+pub struct RpPresignPost {
+    post: PresignedPost,
+}
+
+impl RpPresignPost {
+    /// Create a new reply for `presign_post`.
+    pub fn new(post: PresignedPost) -> Self {
+        RpPresignPost { post }
+    }
+
+    /// Consume reply to build a presigned post.
+    pub fn into_presigned_post(self) -> PresignedPost {
+        self.post
+    }
+}
+
+/// PresignedPost is a presigned form upload returned by `presign_post`.
+///
+/// Users should send a `multipart/form-data` POST request to the uri with
+/// all fields, and the file content as the last field named `file`.
+#[derive(Debug, Clone)]
+pub struct PresignedPost {
+    uri: http::Uri,
+    fields: Vec<(String, String)>,
+}
+
+impl PresignedPost {
+    /// Create a new PresignedPost
+    pub fn new(uri: http::Uri, fields: Vec<(String, String)>) -> Self {
+        Self { uri, fields }
+    }
+
+    /// Return the uri that form should be posted to.
+    pub fn uri(&self) -> &http::Uri {
+        &self.uri
+    }
+
+    /// Return the form fields in order.
+    ///
+    /// The `key` field defaults to `${filename}` under the prefix, and can
+    /// be changed to any key that starts with the prefix.
+    pub fn fields(&self) -> &[(String, String)] {
+        &self.fields
+    }
+}
+
+/// PresignedMultipartUpload is an initiated multipart upload along with
+/// presigned requests for each part, returned by `presign_multipart`.
+#[derive(Debug, Clone)]
+pub struct PresignedMultipartUpload {
+    upload_id: String,
+    parts: Vec<PresignedRequest>,
+}
+
+impl PresignedMultipartUpload {
+    /// Create a new PresignedMultipartUpload
+    pub fn new(upload_id: &str, parts: Vec<PresignedRequest>) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+            parts,
+        }
+    }
+
+    /// Return the upload id which is required to complete this upload.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// Return presigned requests for each part in the order of part number.
+    ///
+    /// The etag returned by each part upload must be collected to complete
+    /// this upload.
+    pub fn parts(&self) -> &[PresignedRequest] {
+        &self.parts
+    }
+}
+
 impl<T: Default> From<PresignedRequest> for Request<T> {
     fn from(v: PresignedRequest) -> Self {
         let mut builder = Request::builder().method(v.method).uri(v.uri);
@@ -156,6 +237,10 @@ impl RpBatch {
 pub enum BatchedReply {
     /// results of `delete batch` operation
     Delete(RpDelete),
+    /// results of `stat batch` operation
+    Stat(Box<RpStat>),
+    /// results of `copy batch` operation
+    Copy(RpCopy),
 }
 
 impl From<RpDelete> for BatchedReply {
@@ -164,6 +249,18 @@ impl From<RpDelete> for BatchedReply {
     }
 }
 
+impl From<RpStat> for BatchedReply {
+    fn from(rp: RpStat) -> Self {
+        Self::Stat(Box::new(rp))
+    }
+}
+
+impl From<RpCopy> for BatchedReply {
+    fn from(rp: RpCopy) -> Self {
+        Self::Copy(rp)
+    }
+}
+
 /// Reply for `stat` operation.
 #[derive(Debug, Clone)]
 pub struct RpStat {
@@ -221,6 +318,63 @@ impl RpRename {
     }
 }
 
+/// Reply for `watch` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpWatch {}
+
+/// Reply for `summary` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpSummary {
+    content_length: u64,
+    file_count: u64,
+}
+
+impl RpSummary {
+    /// Create a new reply for `summary`.
+    pub fn new(content_length: u64, file_count: u64) -> Self {
+        Self {
+            content_length,
+            file_count,
+        }
+    }
+
+    /// Get the total content length of all files under the path.
+    pub fn content_length(&self) -> u64 {
+        self.content_length
+    }
+
+    /// Get the number of files under the path.
+    pub fn file_count(&self) -> u64 {
+        self.file_count
+    }
+}
+
+/// Reply for `get_tags` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpGetTags {
+    tags: HashMap<String, String>,
+}
+
+impl RpGetTags {
+    /// Create a new reply for `get_tags`.
+    pub fn new(tags: HashMap<String, String>) -> Self {
+        Self { tags }
+    }
+
+    /// Consume reply to get the tags.
+    pub fn into_tags(self) -> HashMap<String, String> {
+        self.tags
+    }
+}
+
+/// Reply for `set_tags` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpSetTags {}
+
+/// Reply for `restore` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpRestore {}
+
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
@@ -259,3 +413,35 @@ mod tests {
         Ok(())
     }
 }
+
+/// Reply for `initiate_multipart` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpInitiateMultipart {
+    upload_id: String,
+}
+
+impl RpInitiateMultipart {
+    /// Create a new reply for `initiate_multipart`.
+    pub fn new(upload_id: &str) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+        }
+    }
+
+    /// Get the upload id of the initiated multipart upload.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+}
+
+/// Reply for `complete_multipart` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpCompleteMultipart {}
+
+/// Reply for `list_uploads` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpListUploads {}
+
+/// Reply for `abort_upload` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpAbortUpload {}
@@ -0,0 +1,449 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::core::ArchiveCore;
+use super::core::ArchiveFormat;
+use super::pager::ArchivePager;
+use crate::raw::*;
+use crate::*;
+
+/// Archive service support, exposes a zip or tar archive as a read-only service.
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct ArchiveBuilder {
+    operator: Option<Operator>,
+    path: Option<String>,
+    format: Option<String>,
+}
+
+impl Debug for ArchiveBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArchiveBuilder")
+            .field(
+                "operator",
+                &self.operator.as_ref().map(|v| v.info().scheme()),
+            )
+            .field("path", &self.path)
+            .field("format", &self.format)
+            .finish()
+    }
+}
+
+impl ArchiveBuilder {
+    /// Set the operator that stores the archive, like `s3`.
+    pub fn operator(&mut self, op: Operator) -> &mut Self {
+        self.operator = Some(op);
+        self
+    }
+
+    /// Set the path of archive in the operator.
+    pub fn path(&mut self, path: &str) -> &mut Self {
+        if !path.is_empty() {
+            self.path = Some(path.to_string());
+        }
+        self
+    }
+
+    /// Set the format of archive, available values are `zip`, `tar`, `tar.gz`
+    /// and `tgz`.
+    ///
+    /// The format will be detected from the extension of path if not set.
+    pub fn format(&mut self, format: &str) -> &mut Self {
+        if !format.is_empty() {
+            self.format = Some(format.to_string());
+        }
+        self
+    }
+}
+
+impl Builder for ArchiveBuilder {
+    const SCHEME: Scheme = Scheme::Archive;
+    type Accessor = ArchiveBackend;
+
+    fn from_map(map: HashMap<String, String>) -> Self {
+        let mut builder = Self::default();
+
+        map.get("path").map(|v| builder.path(v));
+        map.get("format").map(|v| builder.format(v));
+
+        builder
+    }
+
+    fn build(&mut self) -> Result<Self::Accessor> {
+        let inner = match self.operator.take() {
+            Some(v) => v.into_inner(),
+            None => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "operator is empty")
+                    .with_operation("Builder::build")
+                    .with_context("service", Scheme::Archive))
+            }
+        };
+        let path = match self.path.take() {
+            Some(v) => normalize_path(&v),
+            None => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "path is empty")
+                    .with_operation("Builder::build")
+                    .with_context("service", Scheme::Archive))
+            }
+        };
+        let format = match &self.format {
+            Some(v) => ArchiveFormat::parse(v).ok_or_else(|| {
+                Error::new(ErrorKind::ConfigInvalid, "format is invalid")
+                    .with_operation("Builder::build")
+                    .with_context("service", Scheme::Archive)
+                    .with_context("format", v)
+            })?,
+            None => ArchiveFormat::detect(&path).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "format can't be detected from path",
+                )
+                .with_operation("Builder::build")
+                .with_context("service", Scheme::Archive)
+                .with_context("path", &path)
+            })?,
+        };
+
+        Ok(ArchiveBackend {
+            core: Arc::new(ArchiveCore::new(inner, &path, format)),
+        })
+    }
+}
+
+/// Backend for archive services.
+#[derive(Debug, Clone)]
+pub struct ArchiveBackend {
+    core: Arc<ArchiveCore>,
+}
+
+#[async_trait]
+impl Accessor for ArchiveBackend {
+    type Reader = oio::Reader;
+    type BlockingReader = ();
+    type Writer = ();
+    type BlockingWriter = ();
+    type Pager = ArchivePager;
+    type BlockingPager = ();
+
+    fn info(&self) -> AccessorInfo {
+        let inner = self.core.inner.info();
+
+        let mut am = AccessorInfo::default();
+        am.set_scheme(Scheme::Archive)
+            .set_root("/")
+            .set_name(&format!(
+                "{}://{}{}{}",
+                inner.scheme(),
+                inner.name(),
+                inner.root(),
+                self.core.path
+            ))
+            .set_native_capability(Capability {
+                stat: true,
+
+                read: true,
+                read_can_next: true,
+                read_with_range: true,
+
+                list: true,
+                list_with_limit: true,
+                list_with_delimiter_slash: true,
+                list_without_delimiter: true,
+
+                ..Default::default()
+            });
+
+        am
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let member = match self.core.member(path).await? {
+            Some(v) => v,
+            None => {
+                return Err(Error::new(ErrorKind::NotFound, "path not found in archive")
+                    .with_context("path", path))
+            }
+        };
+        if member.is_dir {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "can't read a dir in archive")
+                    .with_context("path", path),
+            );
+        }
+
+        let (size, r) = self.core.read_member(&member, args.range()).await?;
+        Ok((RpRead::new().with_size(Some(size)), r))
+    }
+
+    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        match self.core.member(path).await? {
+            Some(v) => Ok(RpStat::new(v.metadata())),
+            None => Err(Error::new(ErrorKind::NotFound, "path not found in archive")
+                .with_context("path", path)),
+        }
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        let prefix = path.trim_start_matches('/');
+        let flat = args.delimiter().is_empty();
+
+        let entries = self
+            .core
+            .index()
+            .await?
+            .range(prefix.to_string()..)
+            .take_while(|(k, _)| k.starts_with(prefix))
+            .filter(|(k, _)| {
+                let rest = &k[prefix.len()..];
+                !rest.is_empty() && (flat || !rest.trim_end_matches('/').contains('/'))
+            })
+            .map(|(k, v)| oio::Entry::new(k, v.metadata()))
+            .collect();
+
+        Ok((RpList::default(), ArchivePager::new(entries, args.limit())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use bytes::BufMut;
+    use flate2::write::DeflateEncoder;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    use super::*;
+    use crate::services::Memory;
+
+    /// Build a zip archive, CRC is left empty since we don't check it.
+    fn build_zip(files: &[(&str, &[u8], bool)]) -> Vec<u8> {
+        // 2023-11-14 12:30:10
+        let (date, time) = ((43 << 9) | (11 << 5) | 14, (12 << 11) | (30 << 5) | 5);
+
+        let mut bs = Vec::new();
+        let mut cd = Vec::new();
+        for (name, content, deflate) in files {
+            let (method, data) = if *deflate {
+                let mut w = DeflateEncoder::new(Vec::new(), Compression::default());
+                w.write_all(content).unwrap();
+                (8, w.finish().unwrap())
+            } else {
+                (0, content.to_vec())
+            };
+            let offset = bs.len() as u32;
+
+            bs.put_u32_le(0x04034b50);
+            bs.put_u16_le(20);
+            bs.put_u16_le(0);
+            bs.put_u16_le(method);
+            bs.put_u16_le(time);
+            bs.put_u16_le(date);
+            bs.put_u32_le(0);
+            bs.put_u32_le(data.len() as u32);
+            bs.put_u32_le(content.len() as u32);
+            bs.put_u16_le(name.len() as u16);
+            // Local extra field could be different from central directory.
+            bs.put_u16_le(4);
+            bs.put_slice(name.as_bytes());
+            bs.put_slice(&[0xff, 0xff, 0, 0]);
+            bs.put_slice(&data);
+
+            cd.put_u32_le(0x02014b50);
+            cd.put_u16_le(20);
+            cd.put_u16_le(20);
+            cd.put_u16_le(0);
+            cd.put_u16_le(method);
+            cd.put_u16_le(time);
+            cd.put_u16_le(date);
+            cd.put_u32_le(0);
+            cd.put_u32_le(data.len() as u32);
+            cd.put_u32_le(content.len() as u32);
+            cd.put_u16_le(name.len() as u16);
+            cd.put_u16_le(0);
+            cd.put_u16_le(0);
+            cd.put_u16_le(0);
+            cd.put_u16_le(0);
+            cd.put_u32_le(0);
+            cd.put_u32_le(offset);
+            cd.put_slice(name.as_bytes());
+        }
+
+        let offset = bs.len() as u32;
+        bs.put_slice(&cd);
+        bs.put_u32_le(0x06054b50);
+        bs.put_u16_le(0);
+        bs.put_u16_le(0);
+        bs.put_u16_le(files.len() as u16);
+        bs.put_u16_le(files.len() as u16);
+        bs.put_u32_le(cd.len() as u32);
+        bs.put_u32_le(offset);
+        bs.put_u16_le(7);
+        bs.put_slice(b"comment");
+        bs
+    }
+
+    fn build_tar(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut bs = Vec::new();
+        for (name, data) in files {
+            let mut header = vec![0; 512];
+            header[..name.len()].copy_from_slice(name.as_bytes());
+            header[124..135].copy_from_slice(format!("{:011o}", data.len()).as_bytes());
+            header[136..147].copy_from_slice(format!("{:011o}", 1700000000).as_bytes());
+            header[156] = if name.ends_with('/') { b'5' } else { b'0' };
+            header[148..156].fill(b' ');
+            let checksum: u64 = header.iter().map(|b| *b as u64).sum();
+            header[148..155].copy_from_slice(format!("{checksum:06o}\0").as_bytes());
+
+            bs.extend(header);
+            bs.extend_from_slice(data);
+            bs.resize((bs.len() + 511) / 512 * 512, 0);
+        }
+        bs.resize(bs.len() + 1024, 0);
+        bs
+    }
+
+    async fn new_archive(path: &str, content: Vec<u8>) -> Result<Operator> {
+        let inner = Operator::new(Memory::default())?.finish();
+        inner.write(path, content).await?;
+
+        let mut builder = ArchiveBuilder::default();
+        builder.operator(inner).path(path);
+        Ok(Operator::new(builder)?.finish())
+    }
+
+    async fn check_archive(op: &Operator, content: &[u8]) -> Result<()> {
+        let expected = ["dir/", "dir/a", "dir/sub/", "dir/sub/b", "root"];
+        let mut entries: Vec<_> = op
+            .list_with("/")
+            .delimiter("")
+            .await?
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        entries.sort();
+        assert_eq!(entries, expected);
+
+        let entries: Vec<_> = op
+            .list("dir/")
+            .await?
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        assert_eq!(entries, vec!["dir/a", "dir/sub/"]);
+
+        let meta = op.stat("dir/sub/b").await?;
+        assert!(meta.is_file());
+        assert_eq!(meta.content_length(), content.len() as u64);
+        assert!(op.stat("dir/sub/").await?.is_dir());
+        assert_eq!(
+            op.stat("not_exist").await.unwrap_err().kind(),
+            ErrorKind::NotFound
+        );
+
+        assert_eq!(op.read("dir/a").await?, b"hello");
+        assert_eq!(op.read("dir/sub/b").await?, content);
+        assert_eq!(
+            op.read_with("dir/sub/b").range(10..20).await?,
+            &content[10..20]
+        );
+        assert_eq!(
+            op.read_with("dir/sub/b").range(..5).await?,
+            &content[content.len() - 5..]
+        );
+        assert_eq!(op.read_with("root").range(3..).await?, b"lo world".to_vec());
+        assert_eq!(
+            op.read("dir/").await.unwrap_err().kind(),
+            ErrorKind::IsADirectory
+        );
+
+        Ok(())
+    }
+
+    fn content() -> Vec<u8> {
+        (0..10000).map(|v| (v % 251) as u8).collect()
+    }
+
+    #[tokio::test]
+    async fn test_zip() -> Result<()> {
+        let content = content();
+        let bs = build_zip(&[
+            ("dir/a", b"hello", false),
+            ("dir/sub/b", &content, true),
+            ("root", b"hello world", true),
+        ]);
+        let op = new_archive("test.zip", bs).await?;
+        check_archive(&op, &content).await?;
+
+        let meta = op.stat("dir/a").await?;
+        assert_eq!(
+            meta.last_modified().map(|v| v.to_rfc3339()),
+            Some("2023-11-14T12:30:10+00:00".to_string())
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tar() -> Result<()> {
+        let content = content();
+        let bs = build_tar(&[
+            ("./dir/", b""),
+            ("./dir/a", b"hello"),
+            ("./dir/sub/b", &content),
+            ("./root", b"hello world"),
+        ]);
+        let op = new_archive("test.tar", bs).await?;
+        check_archive(&op, &content).await
+    }
+
+    #[tokio::test]
+    async fn test_tar_gz() -> Result<()> {
+        let content = content();
+        let bs = build_tar(&[
+            ("dir/a", b"hello"),
+            ("dir/sub/b", &content),
+            ("root", b"hello world"),
+        ]);
+        let mut w = GzEncoder::new(Vec::new(), Compression::default());
+        w.write_all(&bs).unwrap();
+        let op = new_archive("test.tgz", w.finish().unwrap()).await?;
+        check_archive(&op, &content).await
+    }
+
+    #[test]
+    fn test_build() {
+        let inner = Operator::new(Memory::default()).unwrap().finish();
+
+        let mut builder = ArchiveBuilder::default();
+        builder.operator(inner.clone()).path("test.7z");
+        assert_eq!(
+            builder.build().unwrap_err().kind(),
+            ErrorKind::ConfigInvalid
+        );
+
+        let mut builder = ArchiveBuilder::default();
+        builder.operator(inner).path("test.7z").format("zip");
+        assert!(builder.build().is_ok());
+    }
+}
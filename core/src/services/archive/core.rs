@@ -0,0 +1,520 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io;
+use std::io::Write;
+
+use bytes::Bytes;
+use chrono::DateTime;
+use chrono::Utc;
+use flate2::write::DeflateDecoder;
+use flate2::write::MultiGzDecoder;
+use tokio::sync::OnceCell;
+
+use super::tar::TarScanner;
+use super::zip;
+use crate::raw::oio::ReadExt;
+use crate::raw::*;
+use crate::*;
+
+/// The format of archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Zip,
+    Tar,
+    TarGz,
+}
+
+impl ArchiveFormat {
+    /// Parse format from user input like `zip`, `tar`, `tar.gz` or `tgz`.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim_start_matches('.').to_lowercase().as_str() {
+            "zip" => Some(Self::Zip),
+            "tar" => Some(Self::Tar),
+            "tar.gz" | "tgz" => Some(Self::TarGz),
+            _ => None,
+        }
+    }
+
+    /// Detect format from the extension of path.
+    pub fn detect(path: &str) -> Option<Self> {
+        let path = path.to_lowercase();
+        if path.ends_with(".zip") {
+            Some(Self::Zip)
+        } else if path.ends_with(".tar") {
+            Some(Self::Tar)
+        } else if path.ends_with(".tar.gz") || path.ends_with(".tgz") {
+            Some(Self::TarGz)
+        } else {
+            None
+        }
+    }
+}
+
+/// A member of archive.
+#[derive(Debug, Clone)]
+pub struct Member {
+    pub is_dir: bool,
+    /// The offset of local header for zip, or the offset of data in the
+    /// decompressed stream for tar.
+    pub offset: u64,
+    pub compressed_size: u64,
+    pub size: u64,
+    pub method: u16,
+    pub encrypted: bool,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+impl Member {
+    fn dir() -> Self {
+        Self {
+            is_dir: true,
+            offset: 0,
+            compressed_size: 0,
+            size: 0,
+            method: zip::METHOD_STORED,
+            encrypted: false,
+            last_modified: None,
+        }
+    }
+
+    pub fn metadata(&self) -> Metadata {
+        if self.is_dir {
+            return Metadata::new(EntryMode::DIR);
+        }
+
+        // All metadata we have are in the index.
+        let mut meta = Metadata::new(EntryMode::FILE).with_metakey(Metakey::Complete);
+        meta.set_content_length(self.size);
+        if let Some(v) = self.last_modified {
+            meta.set_last_modified(v);
+        }
+        meta
+    }
+}
+
+pub struct ArchiveCore {
+    pub inner: FusedAccessor,
+    pub path: String,
+    pub format: ArchiveFormat,
+
+    /// Index of all members, keyed by path relative to the root of archive.
+    /// Dirs end with `/`.
+    index: OnceCell<BTreeMap<String, Member>>,
+}
+
+impl Debug for ArchiveCore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArchiveCore")
+            .field("inner", &self.inner.info().scheme())
+            .field("path", &self.path)
+            .field("format", &self.format)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ArchiveCore {
+    pub fn new(inner: FusedAccessor, path: &str, format: ArchiveFormat) -> Self {
+        Self {
+            inner,
+            path: path.to_string(),
+            format,
+            index: OnceCell::new(),
+        }
+    }
+
+    /// Get the index of archive, it will be loaded at the first call.
+    pub async fn index(&self) -> Result<&BTreeMap<String, Member>> {
+        self.index
+            .get_or_try_init(|| async {
+                let members = match self.format {
+                    ArchiveFormat::Zip => self.load_zip().await?,
+                    ArchiveFormat::Tar | ArchiveFormat::TarGz => self.load_tar().await?,
+                };
+                Ok(build_index(members))
+            })
+            .await
+    }
+
+    /// Get the member of given path, returns `None` if not found.
+    ///
+    /// Root is always a dir even if the archive is empty.
+    pub async fn member(&self, path: &str) -> Result<Option<Member>> {
+        let path = path.trim_start_matches('/');
+        if path.is_empty() {
+            return Ok(Some(Member::dir()));
+        }
+
+        Ok(self.index().await?.get(path).cloned())
+    }
+
+    /// Read the data of member in given range.
+    pub async fn read_member(
+        &self,
+        member: &Member,
+        range: BytesRange,
+    ) -> Result<(u64, oio::Reader)> {
+        let (start, size) = resolve_range(range, member.size);
+        if size == 0 {
+            return Ok((0, Box::new(oio::Cursor::new())));
+        }
+
+        let reader: oio::Reader = match self.format {
+            ArchiveFormat::Zip => {
+                if member.encrypted {
+                    return Err(Error::new(
+                        ErrorKind::Unsupported,
+                        "encrypted zip entry is not supported",
+                    ));
+                }
+
+                let header = self
+                    .read_range(member.offset, zip::LOCAL_HEADER_SIZE)
+                    .await?;
+                let data_offset = member.offset + zip::parse_local_header(&header)?;
+
+                match member.method {
+                    zip::METHOD_STORED => self.reader(data_offset + start, size).await?,
+                    zip::METHOD_DEFLATED => {
+                        let (_, r) = self
+                            .inner
+                            .read(
+                                &self.path,
+                                OpRead::new().with_range(BytesRange::new(
+                                    Some(data_offset),
+                                    Some(member.compressed_size),
+                                )),
+                            )
+                            .await?;
+                        let mut w = DeflateDecoder::new(RangeSink::new(start, size));
+                        decode(r, &mut w, |w| w.get_ref().is_done()).await?;
+                        if !w.get_ref().is_done() {
+                            w.try_finish().map_err(new_decompress_error)?;
+                        }
+                        Box::new(oio::Cursor::from(w.get_mut().take()))
+                    }
+                    v => {
+                        return Err(Error::new(
+                            ErrorKind::Unsupported,
+                            "zip compression method is not supported",
+                        )
+                        .with_context("method", v.to_string()))
+                    }
+                }
+            }
+            ArchiveFormat::Tar => self.reader(member.offset + start, size).await?,
+            ArchiveFormat::TarGz => {
+                // Gzip streams can't be seeked, we have to decompress from the start.
+                let (_, r) = self.inner.read(&self.path, OpRead::new()).await?;
+                let mut w = MultiGzDecoder::new(RangeSink::new(member.offset + start, size));
+                decode(r, &mut w, |w| w.get_ref().is_done()).await?;
+                if !w.get_ref().is_done() {
+                    w.try_finish().map_err(new_decompress_error)?;
+                }
+                Box::new(oio::Cursor::from(w.get_mut().take()))
+            }
+        };
+
+        Ok((size, reader))
+    }
+
+    async fn load_zip(&self) -> Result<Vec<(String, Member)>> {
+        let size = self
+            .inner
+            .stat(&self.path, OpStat::new())
+            .await?
+            .into_metadata()
+            .content_length();
+
+        let tail_size = size.min(zip::MAX_TAIL_SIZE);
+        let tail = self.read_range(size - tail_size, tail_size).await?;
+        let cd = match zip::parse_tail(&tail)? {
+            zip::Tail::CentralDirectory(cd) => cd,
+            zip::Tail::Zip64(offset) => {
+                let bs = self.read_range(offset, zip::ZIP64_EOCD_SIZE).await?;
+                zip::parse_zip64_eocd(&bs)?
+            }
+        };
+
+        let bs = if cd.size == 0 {
+            vec![]
+        } else {
+            self.read_range(cd.offset, cd.size).await?
+        };
+        let entries = zip::parse_central_directory(&bs, cd.entries)?;
+
+        Ok(entries
+            .into_iter()
+            .map(|e| {
+                let is_dir = e.path.ends_with('/');
+                (
+                    e.path,
+                    Member {
+                        is_dir,
+                        offset: e.header_offset,
+                        compressed_size: e.compressed_size,
+                        size: e.size,
+                        method: e.method,
+                        encrypted: e.encrypted,
+                        last_modified: e.last_modified,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    async fn load_tar(&self) -> Result<Vec<(String, Member)>> {
+        let (_, r) = self.inner.read(&self.path, OpRead::new()).await?;
+
+        let scanner = if self.format == ArchiveFormat::TarGz {
+            let mut w = MultiGzDecoder::new(TarScanner::new());
+            decode(r, &mut w, |w| w.get_ref().is_finished()).await?;
+            if !w.get_ref().is_finished() {
+                w.try_finish().map_err(new_decompress_error)?;
+            }
+            // The rest of stream could be ignored once the end of archive reached.
+            std::mem::replace(w.get_mut(), TarScanner::new())
+        } else {
+            let mut w = TarScanner::new();
+            decode(r, &mut w, |w| w.is_finished()).await?;
+            w
+        };
+
+        Ok(scanner
+            .into_entries()
+            .into_iter()
+            .map(|e| {
+                (
+                    e.path,
+                    Member {
+                        is_dir: e.is_dir,
+                        offset: e.data_offset,
+                        compressed_size: e.size,
+                        size: e.size,
+                        method: zip::METHOD_STORED,
+                        encrypted: false,
+                        last_modified: e.last_modified,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    async fn reader(&self, offset: u64, size: u64) -> Result<oio::Reader> {
+        let (_, r) = self
+            .inner
+            .read(
+                &self.path,
+                OpRead::new().with_range(BytesRange::new(Some(offset), Some(size))),
+            )
+            .await?;
+        Ok(r)
+    }
+
+    async fn read_range(&self, offset: u64, size: u64) -> Result<Vec<u8>> {
+        let mut r = self.reader(offset, size).await?;
+        let mut bs = Vec::new();
+        r.read_to_end(&mut bs).await?;
+
+        if bs.len() as u64 != size {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "archive is truncated while reading")
+                    .with_context("offset", offset.to_string())
+                    .with_context("expect", size.to_string())
+                    .with_context("actual", bs.len().to_string()),
+            );
+        }
+        Ok(bs)
+    }
+}
+
+/// Build the index from members, parent dirs that are not recorded in
+/// archive will be added.
+fn build_index(members: Vec<(String, Member)>) -> BTreeMap<String, Member> {
+    let mut index = BTreeMap::new();
+
+    for (path, member) in members {
+        let path = normalize_path(&path, member.is_dir);
+        if path.is_empty() {
+            continue;
+        }
+
+        let mut parent = path.as_str();
+        while let Some(idx) = parent.trim_end_matches('/').rfind('/') {
+            parent = &parent[..idx + 1];
+            index.entry(parent.to_string()).or_insert_with(Member::dir);
+        }
+        // Later members override former ones, just like extracting them.
+        index.insert(path, member);
+    }
+
+    index
+}
+
+/// Normalize path in archive, leading `/` and `./` will be removed.
+fn normalize_path(path: &str, is_dir: bool) -> String {
+    let mut path = path;
+    loop {
+        let trimmed = path.trim_start_matches('/').trim_start_matches("./");
+        if trimmed == path {
+            break;
+        }
+        path = trimmed;
+    }
+
+    let path = path.trim_end_matches('/');
+    if path.is_empty() || path == "." {
+        String::new()
+    } else if is_dir {
+        format!("{path}/")
+    } else {
+        path.to_string()
+    }
+}
+
+/// Resolve the range into `(start, size)` inside a member of given size.
+fn resolve_range(range: BytesRange, total: u64) -> (u64, u64) {
+    match (range.offset(), range.size()) {
+        (Some(offset), Some(size)) => {
+            let offset = offset.min(total);
+            (offset, size.min(total - offset))
+        }
+        (Some(offset), None) => {
+            let offset = offset.min(total);
+            (offset, total - offset)
+        }
+        (None, Some(size)) => {
+            let size = size.min(total);
+            (total - size, size)
+        }
+        (None, None) => (0, total),
+    }
+}
+
+/// Feed all data from reader into writer until `done` returns true.
+async fn decode<W: Write>(mut r: oio::Reader, w: &mut W, done: impl Fn(&W) -> bool) -> Result<()> {
+    while let Some(bs) = r.next().await {
+        w.write_all(&bs?).map_err(new_decompress_error)?;
+        if done(w) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+fn new_decompress_error(err: io::Error) -> Error {
+    Error::new(ErrorKind::Unexpected, "archive is corrupted").set_source(err)
+}
+
+/// RangeSink collects data in `[start, start + size)` of the stream written
+/// into it and drops others.
+struct RangeSink {
+    pos: u64,
+    start: u64,
+    end: u64,
+    buf: Vec<u8>,
+}
+
+impl RangeSink {
+    fn new(start: u64, size: u64) -> Self {
+        Self {
+            pos: 0,
+            start,
+            end: start + size,
+            buf: Vec::with_capacity(size.min(8 * 1024 * 1024) as usize),
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.pos >= self.end
+    }
+
+    fn take(&mut self) -> Bytes {
+        Bytes::from(std::mem::take(&mut self.buf))
+    }
+}
+
+impl Write for RangeSink {
+    fn write(&mut self, bs: &[u8]) -> io::Result<usize> {
+        let (pos, end) = (self.pos, self.pos + bs.len() as u64);
+        let (lo, hi) = (pos.max(self.start), end.min(self.end));
+        if lo < hi {
+            self.buf
+                .extend_from_slice(&bs[(lo - pos) as usize..(hi - pos) as usize]);
+        }
+
+        self.pos = end;
+        Ok(bs.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_path() {
+        let cases = vec![
+            ("a/b", false, "a/b"),
+            ("/a/b", false, "a/b"),
+            ("./a/b", false, "a/b"),
+            ("././a/", true, "a/"),
+            ("a", true, "a/"),
+            ("./", true, ""),
+            (".", true, ""),
+        ];
+
+        for (input, is_dir, expected) in cases {
+            assert_eq!(normalize_path(input, is_dir), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_resolve_range() {
+        let cases = vec![
+            (BytesRange::new(None, None), (0, 10)),
+            (BytesRange::new(Some(2), None), (2, 8)),
+            (BytesRange::new(Some(2), Some(3)), (2, 3)),
+            (BytesRange::new(Some(8), Some(5)), (8, 2)),
+            (BytesRange::new(Some(20), Some(5)), (10, 0)),
+            (BytesRange::new(None, Some(3)), (7, 3)),
+            (BytesRange::new(None, Some(30)), (0, 10)),
+        ];
+
+        for (range, expected) in cases {
+            assert_eq!(resolve_range(range, 10), expected, "{range:?}");
+        }
+    }
+
+    #[test]
+    fn test_range_sink() {
+        let mut sink = RangeSink::new(3, 4);
+        sink.write_all(b"ab").unwrap();
+        sink.write_all(b"cdef").unwrap();
+        assert!(!sink.is_done());
+        sink.write_all(b"ghij").unwrap();
+        assert!(sink.is_done());
+        assert_eq!(sink.take(), Bytes::from("defg"));
+    }
+}
@@ -0,0 +1,50 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use async_trait::async_trait;
+
+use crate::raw::*;
+use crate::*;
+
+/// ArchivePager returns entries collected from the index in pages.
+pub struct ArchivePager {
+    entries: VecDeque<oio::Entry>,
+    limit: usize,
+}
+
+impl ArchivePager {
+    pub fn new(entries: Vec<oio::Entry>, limit: Option<usize>) -> Self {
+        Self {
+            entries: entries.into(),
+            limit: limit.unwrap_or(1000).max(1),
+        }
+    }
+}
+
+#[async_trait]
+impl oio::Page for ArchivePager {
+    async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        if self.entries.is_empty() {
+            return Ok(None);
+        }
+
+        let size = self.limit.min(self.entries.len());
+        Ok(Some(self.entries.drain(..size).collect()))
+    }
+}
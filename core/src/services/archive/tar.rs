@@ -0,0 +1,491 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Scan tar archives to build the index of members, and build headers for
+//! writing tar archives.
+//!
+//! Reference: <https://www.gnu.org/software/tar/manual/html_node/Standard.html>
+
+use std::io;
+
+use chrono::DateTime;
+use chrono::TimeZone;
+use chrono::Utc;
+
+/// The size of tar block.
+pub const BLOCK_SIZE: u64 = 512;
+
+/// A member in tar archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TarEntry {
+    pub path: String,
+    pub is_dir: bool,
+    pub size: u64,
+    /// The offset of member data in the (decompressed) archive.
+    pub data_offset: u64,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+/// Kind of extended headers that we need to capture.
+#[derive(Debug, Clone, Copy)]
+enum Extension {
+    /// GNU long name, the data is the path of next member.
+    LongName,
+    /// Pax extended header, the data is records like `len key=value\n`.
+    Pax,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum State {
+    Header,
+    Capture(Extension, u64),
+    Skip(u64),
+    Finished,
+}
+
+/// TarScanner accepts the archive stream via [`io::Write`] and collects all
+/// members from headers.
+///
+/// Member data will be skipped, so that tar.gz archives can be scanned by
+/// wrapping the scanner with a decoder.
+pub struct TarScanner {
+    pos: u64,
+    state: State,
+    buf: Vec<u8>,
+    next_path: Option<String>,
+    entries: Vec<TarEntry>,
+}
+
+impl TarScanner {
+    pub fn new() -> Self {
+        Self {
+            pos: 0,
+            state: State::Header,
+            buf: Vec::with_capacity(BLOCK_SIZE as usize),
+            next_path: None,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns true if the end of archive has been reached.
+    pub fn is_finished(&self) -> bool {
+        matches!(self.state, State::Finished)
+    }
+
+    pub fn into_entries(self) -> Vec<TarEntry> {
+        self.entries
+    }
+
+    fn on_header(&mut self) -> io::Result<()> {
+        let header = std::mem::take(&mut self.buf);
+        // Two zero blocks mark the end of archive, but one is enough for us.
+        if header.iter().all(|b| *b == 0) {
+            self.state = State::Finished;
+            return Ok(());
+        }
+
+        let checksum = parse_number(&header[148..156])?;
+        let actual: u64 = header
+            .iter()
+            .enumerate()
+            .map(|(i, b)| if (148..156).contains(&i) { b' ' } else { *b } as u64)
+            .sum();
+        if checksum != actual {
+            return Err(new_invalid_tar_error("header checksum mismatch"));
+        }
+
+        let size = parse_number(&header[124..136])?;
+        let padded = padded_size(size)?;
+
+        match header[156] {
+            b'L' => {
+                self.state = State::Capture(Extension::LongName, size);
+                return Ok(());
+            }
+            b'x' => {
+                self.state = State::Capture(Extension::Pax, size);
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        let path = match self.next_path.take() {
+            Some(v) => v,
+            None => {
+                let name = parse_str(&header[0..100]);
+                // Only ustar archives have a valid prefix.
+                if &header[257..262] == b"ustar" && header[345] != 0 {
+                    format!("{}/{}", parse_str(&header[345..500]), name)
+                } else {
+                    name
+                }
+            }
+        };
+
+        let is_dir = header[156] == b'5' || (header[156] == 0 && path.ends_with('/'));
+        // Only regular files and dirs will be indexed, links and devices are ignored.
+        if is_dir || matches!(header[156], 0 | b'0' | b'7') {
+            let mtime = parse_number(&header[136..148])?;
+            self.entries.push(TarEntry {
+                path,
+                is_dir,
+                size: if is_dir { 0 } else { size },
+                data_offset: self.pos,
+                last_modified: Utc.timestamp_opt(mtime as i64, 0).single(),
+            });
+        }
+
+        self.state = State::Skip(padded);
+        Ok(())
+    }
+
+    fn on_extension(&mut self, ext: Extension, size: u64) -> io::Result<()> {
+        let data = std::mem::take(&mut self.buf);
+        match ext {
+            Extension::LongName => {
+                let path = parse_str(&data);
+                self.next_path = Some(path);
+            }
+            Extension::Pax => {
+                if let Some(path) = parse_pax_path(&data) {
+                    self.next_path = Some(path);
+                }
+            }
+        }
+
+        let padded = padded_size(size)?;
+        self.state = State::Skip(padded - size);
+        Ok(())
+    }
+}
+
+impl io::Write for TarScanner {
+    fn write(&mut self, mut bs: &[u8]) -> io::Result<usize> {
+        let total = bs.len();
+
+        while !bs.is_empty() {
+            match self.state {
+                State::Finished => break,
+                State::Skip(n) => {
+                    let size = n.min(bs.len() as u64);
+                    bs = &bs[size as usize..];
+                    self.pos += size;
+                    self.state = if n == size {
+                        State::Header
+                    } else {
+                        State::Skip(n - size)
+                    };
+                }
+                State::Header | State::Capture(_, _) => {
+                    let expected = match self.state {
+                        State::Capture(_, size) => size,
+                        _ => BLOCK_SIZE,
+                    };
+                    let size = (expected - self.buf.len() as u64).min(bs.len() as u64) as usize;
+                    self.buf.extend_from_slice(&bs[..size]);
+                    bs = &bs[size..];
+                    self.pos += size as u64;
+
+                    if self.buf.len() as u64 == expected {
+                        match self.state {
+                            State::Capture(ext, size) => self.on_extension(ext, size)?,
+                            _ => self.on_header()?,
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(total)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn new_invalid_tar_error(msg: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("archive is not a valid tar: {msg}"),
+    )
+}
+
+/// Build the header of a member.
+///
+/// A pax extended header will be added before the header if path is too long
+/// for ustar format.
+pub fn build_header(path: &str, size: u64, is_dir: bool, mtime: u64) -> Vec<u8> {
+    let mut bs = Vec::with_capacity(BLOCK_SIZE as usize);
+
+    let (prefix, name) = match split_path(path) {
+        Some(v) => v,
+        None => {
+            let record = build_pax_record("path", path);
+            let mut header = new_header("././@PaxHeader", record.len() as u64, b'x', mtime);
+            bs.append(&mut header);
+            bs.extend_from_slice(record.as_bytes());
+            let padded = padded_size(bs.len() as u64).expect("pax header must not overflow");
+            bs.resize(padded as usize, 0);
+
+            // The name in ustar header will be ignored by readers that know pax.
+            let mut end = 100;
+            while !path.is_char_boundary(end) {
+                end -= 1;
+            }
+            ("", &path[..end])
+        }
+    };
+
+    let mut header = new_header(
+        name,
+        if is_dir { 0 } else { size },
+        if is_dir { b'5' } else { b'0' },
+        mtime,
+    );
+    header[345..345 + prefix.len()].copy_from_slice(prefix.as_bytes());
+    if is_dir {
+        header[100..107].copy_from_slice(b"0000755");
+    }
+    fill_checksum(&mut header);
+
+    bs.append(&mut header);
+    bs
+}
+
+fn new_header(name: &str, size: u64, typeflag: u8, mtime: u64) -> Vec<u8> {
+    let mut header = vec![0; BLOCK_SIZE as usize];
+    header[..name.len()].copy_from_slice(name.as_bytes());
+    header[100..107].copy_from_slice(b"0000644");
+    header[108..115].copy_from_slice(b"0000000");
+    header[116..123].copy_from_slice(b"0000000");
+    // Use GNU base-256 encoding if size can't be represented in 11 octal digits.
+    if size < 1 << 33 {
+        header[124..135].copy_from_slice(format!("{size:011o}").as_bytes());
+    } else {
+        header[124] = 0x80;
+        header[128..136].copy_from_slice(&size.to_be_bytes());
+    }
+    header[136..147].copy_from_slice(format!("{:011o}", mtime.min((1 << 33) - 1)).as_bytes());
+    header[156] = typeflag;
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+    fill_checksum(&mut header);
+    header
+}
+
+fn fill_checksum(header: &mut [u8]) {
+    header[148..156].fill(b' ');
+    let checksum: u64 = header.iter().map(|b| *b as u64).sum();
+    header[148..155].copy_from_slice(format!("{checksum:06o}\0").as_bytes());
+}
+
+/// Split path into ustar prefix and name, returns `None` if not possible.
+fn split_path(path: &str) -> Option<(&str, &str)> {
+    if path.len() <= 100 {
+        return Some(("", path));
+    }
+
+    // Dirs end with `/`, which should be kept in name.
+    let trimmed = path.trim_end_matches('/');
+    trimmed
+        .match_indices('/')
+        .map(|(idx, _)| (&path[..idx], &path[idx + 1..]))
+        .find(|(prefix, name)| prefix.len() <= 155 && name.len() <= 100 && !name.is_empty())
+}
+
+/// Build a pax record like `len key=value\n`, the length includes itself.
+fn build_pax_record(key: &str, value: &str) -> String {
+    let content = format!(" {key}={value}\n");
+    let mut len = content.len() + 1;
+    while len != content.len() + len.to_string().len() {
+        len = content.len() + len.to_string().len();
+    }
+    format!("{len}{content}")
+}
+
+/// Returns the size padded to blocks.
+///
+/// Returns error if the padded size overflows.
+pub fn padded_size(size: u64) -> io::Result<u64> {
+    size.checked_add(BLOCK_SIZE - 1)
+        .map(|v| v / BLOCK_SIZE * BLOCK_SIZE)
+        .ok_or_else(|| new_invalid_tar_error("size overflow"))
+}
+
+/// Parse a nul terminated string.
+fn parse_str(bs: &[u8]) -> String {
+    let end = bs.iter().position(|b| *b == 0).unwrap_or(bs.len());
+    String::from_utf8_lossy(&bs[..end]).to_string()
+}
+
+/// Parse a number in octal or GNU base-256 encoding.
+fn parse_number(bs: &[u8]) -> io::Result<u64> {
+    if bs[0] & 0x80 != 0 {
+        let mut v = (bs[0] & 0x7f) as u64;
+        for b in &bs[1..] {
+            v = v
+                .checked_mul(256)
+                .and_then(|v| v.checked_add(*b as u64))
+                .ok_or_else(|| new_invalid_tar_error("number overflow"))?;
+        }
+        return Ok(v);
+    }
+
+    let s = parse_str(bs);
+    let s = s.trim_matches(' ');
+    if s.is_empty() {
+        return Ok(0);
+    }
+    u64::from_str_radix(s, 8).map_err(|_| new_invalid_tar_error("invalid octal number"))
+}
+
+/// Parse the `path` record in pax extended header.
+fn parse_pax_path(mut bs: &[u8]) -> Option<String> {
+    let mut path = None;
+
+    while !bs.is_empty() {
+        let space = bs.iter().position(|b| *b == b' ')?;
+        let len: usize = std::str::from_utf8(&bs[..space]).ok()?.parse().ok()?;
+        if len <= space || len > bs.len() {
+            return None;
+        }
+        // Record is `len key=value\n`.
+        let record = &bs[space + 1..len - 1];
+        bs = &bs[len..];
+
+        if let Some(v) = record.strip_prefix(b"path=") {
+            path = Some(String::from_utf8_lossy(v).to_string());
+        }
+    }
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    fn header(path: &str, typeflag: u8, size: u64) -> Vec<u8> {
+        new_header(path, size, typeflag, 1700000000)
+    }
+
+    fn data(content: &[u8]) -> Vec<u8> {
+        let mut bs = content.to_vec();
+        bs.resize(padded_size(content.len() as u64).unwrap() as usize, 0);
+        bs
+    }
+
+    #[test]
+    fn test_scan() {
+        let long_name = format!("{}/file", "x".repeat(120));
+        let pax = "20 path=pax/renamed\n";
+
+        let mut bs = Vec::new();
+        bs.extend(header("dir/", b'5', 0));
+        bs.extend(header("dir/a", b'0', 5));
+        bs.extend(data(b"hello"));
+        bs.extend(header("././@LongLink", b'L', long_name.len() as u64 + 1));
+        bs.extend(data(format!("{long_name}\0").as_bytes()));
+        bs.extend(header("ignored", b'0', 0));
+        bs.extend(header("PaxHeader", b'x', pax.len() as u64));
+        bs.extend(data(pax.as_bytes()));
+        bs.extend(header("ignored", b'0', 3));
+        bs.extend(data(b"pax"));
+        bs.extend(vec![0; 1024]);
+        bs.extend(b"trailing garbage");
+
+        let mut scanner = TarScanner::new();
+        // Write in small chunks to make sure states are kept across writes.
+        for chunk in bs.chunks(100) {
+            scanner.write_all(chunk).unwrap();
+        }
+        assert!(scanner.is_finished());
+
+        let entries = scanner.into_entries();
+        let paths: Vec<_> = entries.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["dir/", "dir/a", &long_name, "pax/renamed"]);
+        assert!(entries[0].is_dir);
+        assert_eq!(entries[1].size, 5);
+        assert_eq!(entries[1].data_offset, 1024);
+        assert_eq!(&bs[1024..1029], b"hello");
+        assert_eq!(entries[3].size, 3);
+        assert_eq!(
+            &bs[entries[3].data_offset as usize..entries[3].data_offset as usize + 3],
+            b"pax"
+        );
+        assert_eq!(
+            entries[1].last_modified,
+            Utc.timestamp_opt(1700000000, 0).single()
+        );
+    }
+
+    #[test]
+    fn test_build_header() {
+        let long_dir = format!("{}/", "d".repeat(120));
+        let long_file = format!("{long_dir}{}", "f".repeat(80));
+        let too_long = "x".repeat(300);
+
+        let mut bs = Vec::new();
+        bs.extend(build_header(&long_dir, 0, true, 1700000000));
+        bs.extend(build_header(&long_file, 3, false, 1700000000));
+        bs.extend(data(b"abc"));
+        bs.extend(build_header(&too_long, 1 << 34, false, 1700000000));
+
+        let mut scanner = TarScanner::new();
+        scanner.write_all(&bs).unwrap();
+
+        let entries = scanner.into_entries();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].path, long_dir);
+        assert!(entries[0].is_dir);
+        assert_eq!(entries[1].path, long_file);
+        assert_eq!(entries[1].size, 3);
+        assert_eq!(entries[2].path, too_long);
+        assert_eq!(entries[2].size, 1 << 34);
+    }
+
+    #[test]
+    fn test_build_pax_record() {
+        for len in [1, 5, 95, 96, 97, 994, 995, 996] {
+            let value = "v".repeat(len);
+            let record = build_pax_record("path", &value);
+            let (n, _) = record.split_once(' ').unwrap();
+            assert_eq!(n.parse::<usize>().unwrap(), record.len());
+        }
+    }
+
+    #[test]
+    fn test_scan_invalid() {
+        let mut scanner = TarScanner::new();
+        let res = scanner.write_all(&[1; BLOCK_SIZE as usize]);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_scan_size_overflow() {
+        let mut scanner = TarScanner::new();
+        let res = scanner.write_all(&header("a", b'0', u64::MAX));
+        assert!(res.is_err());
+
+        assert!(padded_size(u64::MAX - BLOCK_SIZE).is_ok());
+        assert!(padded_size(u64::MAX).is_err());
+        assert!(parse_number(&[0xff; 12]).is_err());
+    }
+}
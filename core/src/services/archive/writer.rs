@@ -0,0 +1,482 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io::Write;
+
+use bytes::Bytes;
+use bytes::BytesMut;
+use chrono::Utc;
+use flate2::write::DeflateEncoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use flate2::Crc;
+use futures::TryStreamExt;
+
+use super::core::ArchiveFormat;
+use super::tar;
+use super::zip;
+use crate::raw::*;
+use crate::*;
+
+/// The default size of data buffered before writing into the underlying
+/// writer, which is larger than the minimum part size of most services.
+const DEFAULT_BUFFER_SIZE: usize = 8 * 1024 * 1024;
+
+/// Entries larger than this will be written with zip64 extensions, leave
+/// some room for the overhead of deflate on incompressible data.
+const ZIP64_THRESHOLD: u64 = 0xF000_0000;
+
+/// ArchiveWriter streams entries into a zip or tar archive via a [`Writer`].
+///
+/// Entries are encoded on the fly and written in chunks, so that archives
+/// can be built on any service without temporary files. Pair it with
+/// [`Archive`](crate::services::Archive) to read members back.
+///
+/// # Notes
+///
+/// - Zip entries are compressed with deflate by default, use
+///   [`ArchiveWriter::with_compression`] to store them as is, which allows
+///   reading members with range reads.
+/// - The size of entry must be known before writing since tar headers
+///   require it.
+/// - The archive will be broken if any error happens, please call
+///   [`ArchiveWriter::abort`] in this case.
+///
+/// # Examples
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::services::ArchiveWriter;
+/// use opendal::Operator;
+///
+/// async fn test(op: Operator) -> Result<()> {
+///     let w = op.writer("exports/2023-11-14.zip").await?;
+///     let mut archive = ArchiveWriter::new(w, "zip")?;
+///
+///     archive.write("hello.txt", "Hello, World!").await?;
+///
+///     let size = op.stat("reports/daily.csv").await?.content_length();
+///     let r = op.reader("reports/daily.csv").await?;
+///     archive.write_from("daily.csv", size, r).await?;
+///
+///     archive.close().await?;
+///     Ok(())
+/// }
+/// ```
+pub struct ArchiveWriter {
+    w: Writer,
+    format: ArchiveFormat,
+    compression: bool,
+    buffer_size: usize,
+
+    buf: BytesMut,
+    gz: Option<GzEncoder<Vec<u8>>>,
+    /// The offset in archive before gzip compression.
+    pos: u64,
+    /// Entries have been written for the central directory of zip.
+    entries: Vec<zip::ZipEntry>,
+}
+
+impl Debug for ArchiveWriter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArchiveWriter")
+            .field("format", &self.format)
+            .field("compression", &self.compression)
+            .field("buffer_size", &self.buffer_size)
+            .field("pos", &self.pos)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The entry that is being written.
+struct PendingEntry {
+    entry: zip::ZipEntry,
+    zip64: bool,
+    crc: Crc,
+    encoder: Option<DeflateEncoder<Vec<u8>>>,
+    written: u64,
+}
+
+impl ArchiveWriter {
+    /// Create a new archive writer on given writer.
+    ///
+    /// Available formats are `zip`, `tar`, `tar.gz` and `tgz`.
+    pub fn new(w: Writer, format: &str) -> Result<Self> {
+        let format = ArchiveFormat::parse(format).ok_or_else(|| {
+            Error::new(ErrorKind::InvalidInput, "archive format is invalid")
+                .with_context("format", format)
+        })?;
+
+        Ok(Self {
+            w,
+            format,
+            compression: true,
+            buffer_size: DEFAULT_BUFFER_SIZE,
+
+            buf: BytesMut::new(),
+            gz: match format {
+                ArchiveFormat::TarGz => Some(GzEncoder::new(Vec::new(), Compression::default())),
+                _ => None,
+            },
+            pos: 0,
+            entries: Vec::new(),
+        })
+    }
+
+    /// Set whether zip entries should be compressed with deflate, default
+    /// to `true`.
+    ///
+    /// It has no effect on tar archives.
+    pub fn with_compression(mut self, compression: bool) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Set the size of data buffered before writing into the underlying
+    /// writer, default to 8 MiB.
+    ///
+    /// Every write will be a part for services that use multipart uploads,
+    /// so it should be larger than the minimum part size of the service.
+    pub fn with_buffer(mut self, size: usize) -> Self {
+        self.buffer_size = size.max(1);
+        self
+    }
+
+    /// Write a file entry with given content.
+    pub async fn write(&mut self, path: &str, bs: impl Into<Bytes>) -> Result<()> {
+        let bs = bs.into();
+
+        let mut entry = self.start_entry(path, bs.len() as u64, false).await?;
+        self.write_entry(&mut entry, &bs).await?;
+        self.finish_entry(entry).await
+    }
+
+    /// Write a file entry with content from reader, `size` must be the
+    /// exact size of content.
+    pub async fn write_from(&mut self, path: &str, size: u64, mut r: Reader) -> Result<()> {
+        let mut entry = self.start_entry(path, size, false).await?;
+        while let Some(bs) = r.try_next().await.map_err(new_std_io_error)? {
+            self.write_entry(&mut entry, &bs).await?;
+        }
+        self.finish_entry(entry).await
+    }
+
+    /// Write a dir entry, the path will be appended with `/` if needed.
+    pub async fn create_dir(&mut self, path: &str) -> Result<()> {
+        let path = format!("{}/", path.trim_end_matches('/'));
+
+        let entry = self.start_entry(&path, 0, true).await?;
+        self.finish_entry(entry).await
+    }
+
+    /// Finish the archive and close the underlying writer.
+    pub async fn close(&mut self) -> Result<()> {
+        match self.format {
+            ArchiveFormat::Zip => {
+                let offset = self.pos;
+                let entries = std::mem::take(&mut self.entries);
+                for entry in &entries {
+                    self.emit(&zip::build_central_header(entry)).await?;
+                }
+
+                let tail = zip::build_tail(zip::CentralDirectory {
+                    entries: entries.len() as u64,
+                    size: self.pos - offset,
+                    offset,
+                });
+                self.emit(&tail).await?;
+            }
+            ArchiveFormat::Tar | ArchiveFormat::TarGz => {
+                self.emit(&[0; 2 * tar::BLOCK_SIZE as usize]).await?;
+            }
+        }
+
+        if let Some(gz) = &mut self.gz {
+            gz.try_finish().map_err(new_std_io_error)?;
+            self.buf.extend_from_slice(gz.get_ref());
+            gz.get_mut().clear();
+        }
+        if !self.buf.is_empty() {
+            let bs = self.buf.split().freeze();
+            self.w.write(bs).await?;
+        }
+
+        self.w.close().await
+    }
+
+    /// Abort the archive and clean up all written data.
+    pub async fn abort(&mut self) -> Result<()> {
+        self.w.abort().await
+    }
+
+    async fn start_entry(&mut self, path: &str, size: u64, is_dir: bool) -> Result<PendingEntry> {
+        let path = path.trim_start_matches('/');
+        if path.is_empty() || (!is_dir && path.ends_with('/')) {
+            return Err(
+                Error::new(ErrorKind::InvalidInput, "path of archive entry is invalid")
+                    .with_context("path", path),
+            );
+        }
+        if self.format == ArchiveFormat::Zip && path.len() > u16::MAX as usize {
+            return Err(
+                Error::new(ErrorKind::InvalidInput, "path of zip entry is too long")
+                    .with_context("path", path),
+            );
+        }
+
+        let now = Utc::now();
+        let entry = zip::ZipEntry {
+            path: path.to_string(),
+            method: if self.compression && !is_dir {
+                zip::METHOD_DEFLATED
+            } else {
+                zip::METHOD_STORED
+            },
+            encrypted: false,
+            crc32: 0,
+            compressed_size: 0,
+            size,
+            header_offset: self.pos,
+            last_modified: Some(now),
+        };
+        let zip64 = size >= ZIP64_THRESHOLD;
+
+        let (header, encoder) = match self.format {
+            ArchiveFormat::Zip => (
+                zip::build_local_header(&entry, zip64),
+                (entry.method == zip::METHOD_DEFLATED)
+                    .then(|| DeflateEncoder::new(Vec::new(), Compression::default())),
+            ),
+            ArchiveFormat::Tar | ArchiveFormat::TarGz => (
+                tar::build_header(path, size, is_dir, now.timestamp().max(0) as u64),
+                None,
+            ),
+        };
+        self.emit(&header).await?;
+
+        Ok(PendingEntry {
+            entry,
+            zip64,
+            crc: Crc::new(),
+            encoder,
+            written: 0,
+        })
+    }
+
+    async fn write_entry(&mut self, entry: &mut PendingEntry, bs: &[u8]) -> Result<()> {
+        entry.written += bs.len() as u64;
+        if entry.written > entry.entry.size {
+            return Err(new_size_mismatch_error(entry));
+        }
+
+        if self.format != ArchiveFormat::Zip {
+            return self.emit(bs).await;
+        }
+
+        entry.crc.update(bs);
+        match &mut entry.encoder {
+            Some(encoder) => {
+                encoder.write_all(bs).map_err(new_std_io_error)?;
+                let compressed = std::mem::take(encoder.get_mut());
+                entry.entry.compressed_size += compressed.len() as u64;
+                self.emit(&compressed).await
+            }
+            None => {
+                entry.entry.compressed_size += bs.len() as u64;
+                self.emit(bs).await
+            }
+        }
+    }
+
+    async fn finish_entry(&mut self, mut entry: PendingEntry) -> Result<()> {
+        if entry.written != entry.entry.size {
+            return Err(new_size_mismatch_error(&entry));
+        }
+
+        if self.format != ArchiveFormat::Zip {
+            let padding =
+                tar::padded_size(entry.written).map_err(new_std_io_error)? - entry.written;
+            return self.emit(&vec![0; padding as usize]).await;
+        }
+
+        if let Some(encoder) = entry.encoder.take() {
+            let compressed = encoder.finish().map_err(new_std_io_error)?;
+            entry.entry.compressed_size += compressed.len() as u64;
+            self.emit(&compressed).await?;
+        }
+        entry.entry.crc32 = entry.crc.sum();
+
+        self.emit(&zip::build_data_descriptor(&entry.entry, entry.zip64))
+            .await?;
+        self.entries.push(entry.entry);
+        Ok(())
+    }
+
+    /// Emit encoded data into buffer, and write the buffer into the
+    /// underlying writer once it's full.
+    async fn emit(&mut self, bs: &[u8]) -> Result<()> {
+        self.pos += bs.len() as u64;
+        match &mut self.gz {
+            Some(gz) => {
+                gz.write_all(bs).map_err(new_std_io_error)?;
+                self.buf.extend_from_slice(gz.get_ref());
+                gz.get_mut().clear();
+            }
+            None => self.buf.extend_from_slice(bs),
+        }
+
+        if self.buf.len() >= self.buffer_size {
+            let bs = self.buf.split().freeze();
+            self.w.write(bs).await?;
+        }
+        Ok(())
+    }
+}
+
+fn new_size_mismatch_error(entry: &PendingEntry) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        "size of archive entry doesn't match the content",
+    )
+    .with_context("path", &entry.entry.path)
+    .with_context("expect", entry.entry.size.to_string())
+    .with_context("actual", entry.written.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use flate2::read::GzDecoder;
+
+    use super::*;
+    use crate::services::Archive;
+    use crate::services::Memory;
+
+    async fn write_archive(op: &Operator, path: &str, compression: bool) -> Result<Vec<u8>> {
+        let content: Vec<u8> = (0..100000).map(|v| (v % 251) as u8).collect();
+        op.write("source", content.clone()).await?;
+
+        let w = op.writer(path).await?;
+        let mut archive = ArchiveWriter::new(w, path.rsplit_once('.').unwrap().1)?
+            .with_compression(compression)
+            .with_buffer(1024);
+        archive.create_dir("dir").await?;
+        archive.write("dir/a", "hello").await?;
+        archive.write("empty", "").await?;
+        let r = op.reader("source").await?;
+        archive
+            .write_from("dir/sub/b", content.len() as u64, r)
+            .await?;
+        archive.close().await?;
+
+        Ok(content)
+    }
+
+    async fn check_archive(op: &Operator, path: &str, content: &[u8]) -> Result<()> {
+        let mut builder = Archive::default();
+        builder.operator(op.clone()).path(path);
+        let archive = Operator::new(builder)?.finish();
+
+        let mut entries: Vec<_> = archive
+            .list_with("/")
+            .delimiter("")
+            .await?
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        entries.sort();
+        assert_eq!(
+            entries,
+            vec!["dir/", "dir/a", "dir/sub/", "dir/sub/b", "empty"]
+        );
+
+        assert_eq!(archive.read("dir/a").await?, b"hello");
+        assert!(archive.read("empty").await?.is_empty());
+        assert_eq!(archive.read("dir/sub/b").await?, content);
+        assert_eq!(
+            archive.read_with("dir/sub/b").range(1000..2000).await?,
+            &content[1000..2000]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_zip() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+
+        for compression in [true, false] {
+            let content = write_archive(&op, "test.zip", compression).await?;
+            check_archive(&op, "test.zip", &content).await?;
+
+            // Check crc in the central directory.
+            let bs = op.read("test.zip").await?;
+            let zip::Tail::CentralDirectory(cd) = zip::parse_tail(&bs)? else {
+                panic!("tail must not be zip64")
+            };
+            let entry = zip::parse_central_directory(&bs[cd.offset as usize..], cd.entries)?
+                .pop()
+                .unwrap();
+            let mut crc = Crc::new();
+            crc.update(&content);
+            assert_eq!(entry.crc32, crc.sum());
+            assert_eq!(entry.method == zip::METHOD_DEFLATED, compression);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_tar() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+
+        let content = write_archive(&op, "test.tar", true).await?;
+        check_archive(&op, "test.tar", &content).await?;
+        assert_eq!(op.stat("test.tar").await?.content_length() % 512, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_tar_gz() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+
+        let content = write_archive(&op, "test.tgz", true).await?;
+        check_archive(&op, "test.tgz", &content).await?;
+
+        let mut bs = Vec::new();
+        GzDecoder::new(op.read("test.tgz").await?.as_slice())
+            .read_to_end(&mut bs)
+            .unwrap();
+        assert_eq!(bs.len() % 512, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_size_mismatch() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        op.write("source", "hello").await?;
+
+        let w = op.writer("test.tar").await?;
+        let mut archive = ArchiveWriter::new(w, "tar")?;
+        let r = op.reader("source").await?;
+        let err = archive.write_from("a", 3, r).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        archive.abort().await?;
+        Ok(())
+    }
+}
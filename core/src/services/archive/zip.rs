@@ -0,0 +1,491 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Parse zip archives from the end of central directory, and build zip
+//! archives in streaming.
+//!
+//! Reference: <https://pkware.cachefly.net/webdocs/casestudies/APPNOTE.TXT>
+
+use bytes::Buf;
+use bytes::BufMut;
+use chrono::DateTime;
+use chrono::Datelike;
+use chrono::NaiveDate;
+use chrono::Timelike;
+use chrono::Utc;
+
+use crate::*;
+
+const EOCD_SIGNATURE: u32 = 0x06054b50;
+const ZIP64_LOCATOR_SIGNATURE: u32 = 0x07064b50;
+const ZIP64_EOCD_SIGNATURE: u32 = 0x06064b50;
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x02014b50;
+const LOCAL_HEADER_SIGNATURE: u32 = 0x04034b50;
+const DATA_DESCRIPTOR_SIGNATURE: u32 = 0x08074b50;
+
+const EOCD_SIZE: usize = 22;
+const ZIP64_LOCATOR_SIZE: usize = 20;
+const CENTRAL_HEADER_SIZE: usize = 46;
+const ZIP64_EXTRA_ID: u16 = 0x0001;
+
+const VERSION_DEFAULT: u16 = 20;
+const VERSION_ZIP64: u16 = 45;
+/// Made by unix, so that permissions in external attributes are respected.
+const VERSION_MADE_BY: u16 = (3 << 8) | VERSION_ZIP64;
+/// Sizes and crc are in the data descriptor, and file names are UTF-8.
+const FLAGS_STREAMING: u16 = 0x0808;
+
+/// The size of zip64 end of central directory record without extensible data.
+pub const ZIP64_EOCD_SIZE: u64 = 56;
+/// The size of local file header without file name and extra field.
+pub const LOCAL_HEADER_SIZE: u64 = 30;
+/// The max size of tail that contains the end of central directory, including
+/// the max comment and the zip64 locator before it.
+pub const MAX_TAIL_SIZE: u64 = (ZIP64_LOCATOR_SIZE + EOCD_SIZE + u16::MAX as usize) as u64;
+
+/// The compression method of stored entries.
+pub const METHOD_STORED: u16 = 0;
+/// The compression method of deflated entries.
+pub const METHOD_DEFLATED: u16 = 8;
+
+/// The location of central directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CentralDirectory {
+    pub entries: u64,
+    pub size: u64,
+    pub offset: u64,
+}
+
+/// The parsed tail of archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tail {
+    CentralDirectory(CentralDirectory),
+    /// The archive is zip64, central directory should be read from the
+    /// zip64 end of central directory record at given offset.
+    Zip64(u64),
+}
+
+/// An entry in central directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZipEntry {
+    pub path: String,
+    pub method: u16,
+    pub encrypted: bool,
+    pub crc32: u32,
+    pub compressed_size: u64,
+    pub size: u64,
+    pub header_offset: u64,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+fn new_invalid_zip_error(msg: &str) -> Error {
+    Error::new(ErrorKind::Unexpected, "archive is not a valid zip").with_context("reason", msg)
+}
+
+/// Find and parse the end of central directory from the tail of archive.
+pub fn parse_tail(tail: &[u8]) -> Result<Tail> {
+    if tail.len() < EOCD_SIZE {
+        return Err(new_invalid_zip_error("archive is too small"));
+    }
+
+    // The comment could contain anything, so search the signature backwards.
+    let pos = (0..=tail.len() - EOCD_SIZE)
+        .rev()
+        .find(|&i| (&tail[i..]).get_u32_le() == EOCD_SIGNATURE)
+        .ok_or_else(|| new_invalid_zip_error("end of central directory not found"))?;
+
+    let mut bs = &tail[pos + 10..pos + EOCD_SIZE];
+    let entries = bs.get_u16_le();
+    let size = bs.get_u32_le();
+    let offset = bs.get_u32_le();
+
+    if entries == u16::MAX || size == u32::MAX || offset == u32::MAX {
+        if pos < ZIP64_LOCATOR_SIZE {
+            return Err(new_invalid_zip_error("zip64 locator not found"));
+        }
+        let mut bs = &tail[pos - ZIP64_LOCATOR_SIZE..pos];
+        if bs.get_u32_le() != ZIP64_LOCATOR_SIGNATURE {
+            return Err(new_invalid_zip_error("zip64 locator not found"));
+        }
+        bs.advance(4);
+        return Ok(Tail::Zip64(bs.get_u64_le()));
+    }
+
+    Ok(Tail::CentralDirectory(CentralDirectory {
+        entries: entries as u64,
+        size: size as u64,
+        offset: offset as u64,
+    }))
+}
+
+/// Parse the zip64 end of central directory record.
+pub fn parse_zip64_eocd(mut bs: &[u8]) -> Result<CentralDirectory> {
+    if bs.len() < ZIP64_EOCD_SIZE as usize || bs.get_u32_le() != ZIP64_EOCD_SIGNATURE {
+        return Err(new_invalid_zip_error(
+            "zip64 end of central directory not found",
+        ));
+    }
+    // Skip size of record, versions, disk numbers and entries on this disk.
+    bs.advance(28);
+
+    Ok(CentralDirectory {
+        entries: bs.get_u64_le(),
+        size: bs.get_u64_le(),
+        offset: bs.get_u64_le(),
+    })
+}
+
+/// Parse all entries in central directory.
+pub fn parse_central_directory(mut bs: &[u8], entries: u64) -> Result<Vec<ZipEntry>> {
+    let mut result = Vec::with_capacity(entries.min(u16::MAX as u64) as usize);
+
+    for _ in 0..entries {
+        if bs.len() < CENTRAL_HEADER_SIZE || bs.get_u32_le() != CENTRAL_HEADER_SIGNATURE {
+            return Err(new_invalid_zip_error("central directory is truncated"));
+        }
+        // Skip versions.
+        bs.advance(4);
+        let flags = bs.get_u16_le();
+        let method = bs.get_u16_le();
+        let time = bs.get_u16_le();
+        let date = bs.get_u16_le();
+        let crc32 = bs.get_u32_le();
+        let mut compressed_size = bs.get_u32_le() as u64;
+        let mut size = bs.get_u32_le() as u64;
+        let name_len = bs.get_u16_le() as usize;
+        let extra_len = bs.get_u16_le() as usize;
+        let comment_len = bs.get_u16_le() as usize;
+        // Skip disk number and attributes.
+        bs.advance(8);
+        let mut header_offset = bs.get_u32_le() as u64;
+
+        if bs.len() < name_len + extra_len + comment_len {
+            return Err(new_invalid_zip_error("central directory is truncated"));
+        }
+        let path = String::from_utf8_lossy(&bs[..name_len]).to_string();
+        let mut extra = &bs[name_len..name_len + extra_len];
+        bs.advance(name_len + extra_len + comment_len);
+
+        // Values in zip64 extra field only present if the origin ones are saturated.
+        while extra.len() >= 4 {
+            let id = extra.get_u16_le();
+            let len = (extra.get_u16_le() as usize).min(extra.len());
+            let mut field = &extra[..len];
+            extra.advance(len);
+            if id != ZIP64_EXTRA_ID {
+                continue;
+            }
+
+            if size == u32::MAX as u64 && field.len() >= 8 {
+                size = field.get_u64_le();
+            }
+            if compressed_size == u32::MAX as u64 && field.len() >= 8 {
+                compressed_size = field.get_u64_le();
+            }
+            if header_offset == u32::MAX as u64 && field.len() >= 8 {
+                header_offset = field.get_u64_le();
+            }
+        }
+
+        result.push(ZipEntry {
+            path,
+            method,
+            encrypted: flags & 1 == 1,
+            crc32,
+            compressed_size,
+            size,
+            header_offset,
+            last_modified: parse_dos_datetime(date, time),
+        });
+    }
+
+    Ok(result)
+}
+
+/// Parse the local file header, returns the length from the header to file data.
+pub fn parse_local_header(mut bs: &[u8]) -> Result<u64> {
+    if bs.len() < LOCAL_HEADER_SIZE as usize || bs.get_u32_le() != LOCAL_HEADER_SIGNATURE {
+        return Err(new_invalid_zip_error("local file header not found"));
+    }
+    bs.advance(22);
+    let name_len = bs.get_u16_le() as u64;
+    let extra_len = bs.get_u16_le() as u64;
+
+    Ok(LOCAL_HEADER_SIZE + name_len + extra_len)
+}
+
+/// Build the local file header for streaming, crc and sizes are left empty
+/// and will be written in the data descriptor.
+///
+/// The zip64 extra field will be added if `zip64` is true, and the data
+/// descriptor must be built with the same `zip64`.
+pub fn build_local_header(entry: &ZipEntry, zip64: bool) -> Vec<u8> {
+    let (date, time) = to_dos_datetime(entry.last_modified.unwrap_or_else(Utc::now));
+    let extra_len = if zip64 { 20 } else { 0 };
+
+    let mut bs = Vec::with_capacity(LOCAL_HEADER_SIZE as usize + entry.path.len() + extra_len);
+    bs.put_u32_le(LOCAL_HEADER_SIGNATURE);
+    bs.put_u16_le(if zip64 {
+        VERSION_ZIP64
+    } else {
+        VERSION_DEFAULT
+    });
+    bs.put_u16_le(FLAGS_STREAMING);
+    bs.put_u16_le(entry.method);
+    bs.put_u16_le(time);
+    bs.put_u16_le(date);
+    bs.put_u32_le(0);
+    bs.put_u32_le(0);
+    bs.put_u32_le(0);
+    bs.put_u16_le(entry.path.len() as u16);
+    bs.put_u16_le(extra_len as u16);
+    bs.put_slice(entry.path.as_bytes());
+    if zip64 {
+        bs.put_u16_le(ZIP64_EXTRA_ID);
+        bs.put_u16_le(16);
+        bs.put_u64_le(0);
+        bs.put_u64_le(0);
+    }
+    bs
+}
+
+/// Build the data descriptor after file data.
+pub fn build_data_descriptor(entry: &ZipEntry, zip64: bool) -> Vec<u8> {
+    let mut bs = Vec::with_capacity(24);
+    bs.put_u32_le(DATA_DESCRIPTOR_SIGNATURE);
+    bs.put_u32_le(entry.crc32);
+    if zip64 {
+        bs.put_u64_le(entry.compressed_size);
+        bs.put_u64_le(entry.size);
+    } else {
+        bs.put_u32_le(entry.compressed_size as u32);
+        bs.put_u32_le(entry.size as u32);
+    }
+    bs
+}
+
+/// Build the central directory header of entry.
+pub fn build_central_header(entry: &ZipEntry) -> Vec<u8> {
+    let (date, time) = to_dos_datetime(entry.last_modified.unwrap_or_else(Utc::now));
+    let is_dir = entry.path.ends_with('/');
+
+    // Values in zip64 extra field only present if the origin ones are saturated.
+    let mut extra = Vec::new();
+    let mut saturate = |v: u64| -> u32 {
+        if v >= u32::MAX as u64 {
+            extra.put_u64_le(v);
+            u32::MAX
+        } else {
+            v as u32
+        }
+    };
+    let size = saturate(entry.size);
+    let compressed_size = saturate(entry.compressed_size);
+    let header_offset = saturate(entry.header_offset);
+
+    let mut bs = Vec::with_capacity(CENTRAL_HEADER_SIZE + entry.path.len() + extra.len() + 4);
+    bs.put_u32_le(CENTRAL_HEADER_SIGNATURE);
+    bs.put_u16_le(VERSION_MADE_BY);
+    bs.put_u16_le(if extra.is_empty() {
+        VERSION_DEFAULT
+    } else {
+        VERSION_ZIP64
+    });
+    bs.put_u16_le(FLAGS_STREAMING);
+    bs.put_u16_le(entry.method);
+    bs.put_u16_le(time);
+    bs.put_u16_le(date);
+    bs.put_u32_le(entry.crc32);
+    bs.put_u32_le(compressed_size);
+    bs.put_u32_le(size);
+    bs.put_u16_le(entry.path.len() as u16);
+    bs.put_u16_le(if extra.is_empty() {
+        0
+    } else {
+        extra.len() as u16 + 4
+    });
+    // Comment length, disk number and internal attributes.
+    bs.put_u16_le(0);
+    bs.put_u16_le(0);
+    bs.put_u16_le(0);
+    // Unix permissions in high bits, MS-DOS directory flag in low bits.
+    bs.put_u32_le(if is_dir {
+        (0o40755 << 16) | 0x10
+    } else {
+        0o100644 << 16
+    });
+    bs.put_u32_le(header_offset);
+    bs.put_slice(entry.path.as_bytes());
+    if !extra.is_empty() {
+        bs.put_u16_le(ZIP64_EXTRA_ID);
+        bs.put_u16_le(extra.len() as u16);
+        bs.put_slice(&extra);
+    }
+    bs
+}
+
+/// Build the end of central directory, zip64 records will be added if needed.
+pub fn build_tail(cd: CentralDirectory) -> Vec<u8> {
+    let mut bs = Vec::new();
+
+    let zip64 =
+        cd.entries >= u16::MAX as u64 || cd.size >= u32::MAX as u64 || cd.offset >= u32::MAX as u64;
+    if zip64 {
+        let eocd_offset = cd.offset + cd.size;
+
+        bs.put_u32_le(ZIP64_EOCD_SIGNATURE);
+        bs.put_u64_le(ZIP64_EOCD_SIZE - 12);
+        bs.put_u16_le(VERSION_MADE_BY);
+        bs.put_u16_le(VERSION_ZIP64);
+        bs.put_u32_le(0);
+        bs.put_u32_le(0);
+        bs.put_u64_le(cd.entries);
+        bs.put_u64_le(cd.entries);
+        bs.put_u64_le(cd.size);
+        bs.put_u64_le(cd.offset);
+
+        bs.put_u32_le(ZIP64_LOCATOR_SIGNATURE);
+        bs.put_u32_le(0);
+        bs.put_u64_le(eocd_offset);
+        bs.put_u32_le(1);
+    }
+
+    bs.put_u32_le(EOCD_SIGNATURE);
+    bs.put_u16_le(0);
+    bs.put_u16_le(0);
+    bs.put_u16_le(cd.entries.min(u16::MAX as u64) as u16);
+    bs.put_u16_le(cd.entries.min(u16::MAX as u64) as u16);
+    bs.put_u32_le(cd.size.min(u32::MAX as u64) as u32);
+    bs.put_u32_le(cd.offset.min(u32::MAX as u64) as u32);
+    bs.put_u16_le(0);
+    bs
+}
+
+/// Convert datetime into MS-DOS date and time, which can only represent
+/// years from 1980 to 2107.
+fn to_dos_datetime(dt: DateTime<Utc>) -> (u16, u16) {
+    if dt.year() < 1980 {
+        return ((1 << 5) | 1, 0);
+    }
+    let year = dt.year().min(2107) - 1980;
+
+    let date = ((year as u16) << 9) | ((dt.month() as u16) << 5) | dt.day() as u16;
+    let time = ((dt.hour() as u16) << 11) | ((dt.minute() as u16) << 5) | (dt.second() as u16 / 2);
+    (date, time)
+}
+
+/// Parse MS-DOS date and time, which are in local time without timezone.
+/// We treat them as UTC since there is no better choice.
+fn parse_dos_datetime(date: u16, time: u16) -> Option<DateTime<Utc>> {
+    let year = (date >> 9) as i32 + 1980;
+    let month = ((date >> 5) & 0x0f) as u32;
+    let day = (date & 0x1f) as u32;
+    let hour = (time >> 11) as u32;
+    let minute = ((time >> 5) & 0x3f) as u32;
+    let second = ((time & 0x1f) * 2) as u32;
+
+    NaiveDate::from_ymd_opt(year, month, day)?
+        .and_hms_opt(hour, minute, second)
+        .map(|v| v.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BufMut;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_zip64_tail() {
+        let mut bs = Vec::new();
+        bs.put_u32_le(ZIP64_EOCD_SIGNATURE);
+        bs.put_u64_le(ZIP64_EOCD_SIZE - 12);
+        bs.put_u16_le(45);
+        bs.put_u16_le(45);
+        bs.put_u32_le(0);
+        bs.put_u32_le(0);
+        bs.put_u64_le(70000);
+        bs.put_u64_le(70000);
+        bs.put_u64_le(1024);
+        bs.put_u64_le(5 << 32);
+        assert_eq!(
+            parse_zip64_eocd(&bs).unwrap(),
+            CentralDirectory {
+                entries: 70000,
+                size: 1024,
+                offset: 5 << 32,
+            }
+        );
+
+        let mut tail = vec![0xff; 16];
+        tail.put_u32_le(ZIP64_LOCATOR_SIGNATURE);
+        tail.put_u32_le(0);
+        tail.put_u64_le(6 << 32);
+        tail.put_u32_le(1);
+        tail.put_u32_le(EOCD_SIGNATURE);
+        tail.put_u16_le(0);
+        tail.put_u16_le(0);
+        tail.put_u16_le(u16::MAX);
+        tail.put_u16_le(u16::MAX);
+        tail.put_u32_le(u32::MAX);
+        tail.put_u32_le(u32::MAX);
+        tail.put_u16_le(0);
+        assert_eq!(parse_tail(&tail).unwrap(), Tail::Zip64(6 << 32));
+    }
+
+    #[test]
+    fn test_build_and_parse() {
+        let entry = ZipEntry {
+            path: "dir/a".to_string(),
+            method: METHOD_DEFLATED,
+            encrypted: false,
+            crc32: 0x12345678,
+            compressed_size: 5 << 32,
+            size: 6 << 32,
+            header_offset: 7 << 32,
+            last_modified: parse_dos_datetime(0x5770, 0x63c5),
+        };
+
+        let cd = build_central_header(&entry);
+        assert_eq!(
+            parse_central_directory(&cd, 1).unwrap(),
+            vec![entry.clone()]
+        );
+        assert_eq!(
+            parse_local_header(&build_local_header(&entry, true)).unwrap(),
+            LOCAL_HEADER_SIZE + 5 + 20
+        );
+
+        let tail = build_tail(CentralDirectory {
+            entries: 1,
+            size: cd.len() as u64,
+            offset: 8 << 32,
+        });
+        let Tail::Zip64(offset) = parse_tail(&tail).unwrap() else {
+            panic!("tail must be zip64")
+        };
+        assert_eq!(offset, (8 << 32) + cd.len() as u64);
+        assert_eq!(
+            parse_zip64_eocd(&tail).unwrap(),
+            CentralDirectory {
+                entries: 1,
+                size: cd.len() as u64,
+                offset: 8 << 32,
+            }
+        );
+    }
+}
@@ -19,10 +19,12 @@ use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
+use std::sync::Mutex;
 
 use async_trait::async_trait;
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
+use bytes::Buf;
 use http::header::CONTENT_TYPE;
 use http::StatusCode;
 use log::debug;
@@ -36,7 +38,10 @@ use super::error::parse_error;
 use super::pager::AzblobPager;
 use super::writer::AzblobWriter;
 use crate::raw::*;
+use crate::services::azblob::core::parse_access_tier;
+use crate::services::azblob::core::parse_version_id;
 use crate::services::azblob::core::AzblobCore;
+use crate::services::azblob::core::Tags;
 use crate::services::azblob::writer::AzblobWriters;
 use crate::types::Metadata;
 use crate::*;
@@ -404,11 +409,13 @@ impl Builder for AzblobBuilder {
             })?
         };
 
+        let account_name = self
+            .account_name
+            .clone()
+            .or_else(|| infer_storage_name_from_endpoint(endpoint.as_str()));
+
         let config_loader = AzureStorageConfig {
-            account_name: self
-                .account_name
-                .clone()
-                .or_else(|| infer_storage_name_from_endpoint(endpoint.as_str())),
+            account_name: account_name.clone(),
             account_key: self.account_key.clone(),
             sas_token: self.sas_token.clone(),
             ..Default::default()
@@ -454,6 +461,7 @@ impl Builder for AzblobBuilder {
         debug!("backend build finished: {:?}", &self);
         Ok(AzblobBackend {
             core: Arc::new(AzblobCore {
+                account_name,
                 root,
                 endpoint,
                 encryption_key,
@@ -465,8 +473,9 @@ impl Builder for AzblobBuilder {
                 loader: cred_loader,
                 signer,
                 batch_max_operations,
+                user_delegation_key: Mutex::default(),
             }),
-            has_sas_token: self.sas_token.is_some(),
+            has_credential: self.sas_token.is_some() || self.account_key.is_some(),
         })
     }
 }
@@ -499,7 +508,7 @@ fn infer_storage_name_from_endpoint(endpoint: &str) -> Option<String> {
 #[derive(Debug, Clone)]
 pub struct AzblobBackend {
     core: Arc<AzblobCore>,
-    has_sas_token: bool,
+    has_credential: bool,
 }
 
 #[async_trait]
@@ -527,12 +536,15 @@ impl Accessor for AzblobBackend {
                 read_with_if_match: true,
                 read_with_if_none_match: true,
                 read_with_override_content_disposition: true,
+                read_with_version: true,
 
                 write: true,
                 write_can_empty: true,
                 write_can_append: true,
                 write_with_cache_control: true,
                 write_with_content_type: true,
+                write_with_storage_class: true,
+                write_with_tags: true,
 
                 delete: true,
                 create_dir: true,
@@ -542,15 +554,20 @@ impl Accessor for AzblobBackend {
                 list_with_delimiter_slash: true,
                 list_without_delimiter: true,
 
-                presign: self.has_sas_token,
-                presign_stat: self.has_sas_token,
-                presign_read: self.has_sas_token,
-                presign_write: self.has_sas_token,
+                presign: self.has_credential,
+                presign_stat: self.has_credential,
+                presign_read: self.has_credential,
+                presign_write: self.has_credential,
 
                 batch: true,
                 batch_delete: true,
                 batch_max_operations: Some(self.core.batch_max_operations),
 
+                get_tags: true,
+                set_tags: true,
+
+                restore: true,
+
                 ..Default::default()
             });
 
@@ -596,6 +613,13 @@ impl Accessor for AzblobBackend {
     }
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        if args.append() && args.storage_class().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "azblob doesn't support access tier for append blob",
+            ));
+        }
+
         let w = AzblobWriter::new(self.core.clone(), args.clone(), path.to_string());
         let w = if args.append() {
             AzblobWriters::Two(oio::AppendObjectWriter::new(w))
@@ -631,7 +655,17 @@ impl Accessor for AzblobBackend {
         let status = resp.status();
 
         match status {
-            StatusCode::OK => parse_into_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::OK => {
+                let mut meta = parse_into_metadata(path, resp.headers())?;
+                if let Some(access_tier) = parse_access_tier(resp.headers())? {
+                    meta.set_storage_class(access_tier);
+                }
+                if let Some(v) = parse_version_id(resp.headers())? {
+                    meta.set_version(v);
+                }
+
+                Ok(RpStat::new(meta))
+            }
             StatusCode::NOT_FOUND if path.ends_with('/') => {
                 Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
             }
@@ -671,9 +705,15 @@ impl Accessor for AzblobBackend {
                 &OpWrite::default(),
                 AsyncBody::Empty,
             )?,
+            PresignOperation::WritePart(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "azblob doesn't support presign multipart upload",
+                ))
+            }
         };
 
-        self.core.sign_query(&mut req).await?;
+        self.core.sign_query(&mut req, path, args.expire()).await?;
 
         let (parts, _) = req.into_parts();
 
@@ -684,6 +724,79 @@ impl Accessor for AzblobBackend {
         )))
     }
 
+    async fn restore(&self, path: &str, _: OpRestore) -> Result<RpRestore> {
+        // Only archived blobs need to be rehydrated, changing the tier of
+        // other blobs is permanent and not what users expect.
+        let resp = self
+            .core
+            .azblob_get_blob_properties(path, &OpStat::new())
+            .await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp).await?);
+        }
+        let tier = parse_access_tier(resp.headers())?.map(|v| v.to_string());
+        resp.into_body().consume().await?;
+        if !matches!(tier.as_deref(), Some(v) if v.eq_ignore_ascii_case("Archive")) {
+            return Err(Error::new(
+                ErrorKind::ConditionNotMatch,
+                "blob is not archived, no need to restore",
+            )
+            .with_operation("Backend::restore")
+            .with_context("access_tier", tier.as_deref().unwrap_or("unknown")));
+        }
+
+        // Azblob rehydrates blobs by changing their tier permanently, so
+        // the days of restore is ignored.
+        let resp = self.core.azblob_set_blob_tier(path, "Hot").await?;
+
+        let status = resp.status();
+
+        match status {
+            // - 202 means the rehydration has been initiated.
+            // - 200 means the tier has been changed.
+            StatusCode::ACCEPTED | StatusCode::OK => {
+                resp.into_body().consume().await?;
+                Ok(RpRestore::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn get_tags(&self, path: &str, _: OpGetTags) -> Result<RpGetTags> {
+        let resp = self.core.azblob_get_blob_tags(path).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+
+                let tagging: Tags =
+                    quick_xml::de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)?;
+
+                Ok(RpGetTags::new(tagging.into()))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        let resp = self
+            .core
+            .azblob_set_blob_tags(path, args.into_tags())
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::NO_CONTENT => {
+                resp.into_body().consume().await?;
+                Ok(RpSetTags::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
         let ops = args.into_operation();
         let paths = ops.into_iter().map(|(p, _)| p).collect::<Vec<_>>();
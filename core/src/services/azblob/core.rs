@@ -15,24 +15,41 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::fmt::Write;
+use std::str::FromStr;
+use std::sync::Mutex;
 use std::time::Duration;
 
+use bytes::Buf;
+use chrono::DateTime;
+use chrono::Utc;
+
 use http::header::HeaderName;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
 use http::header::IF_MATCH;
 use http::header::IF_NONE_MATCH;
+use http::HeaderMap;
 use http::HeaderValue;
 use http::Request;
 use http::Response;
+use http::StatusCode;
+use http::Uri;
 use reqsign::AzureStorageCredential;
 use reqsign::AzureStorageLoader;
 use reqsign::AzureStorageSigner;
-
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::error::parse_error;
+use super::sas::format_sas_time;
+use super::sas::BlobSas;
+use super::sas::KeyInfo;
+use super::sas::UserDelegationKey;
 use crate::raw::*;
 use crate::*;
 
@@ -43,6 +60,9 @@ mod constants {
     pub const X_MS_COPY_SOURCE: &str = "x-ms-copy-source";
     pub const X_MS_BLOB_CACHE_CONTROL: &str = "x-ms-blob-cache-control";
     pub const X_MS_BLOB_CONDITION_APPENDPOS: &str = "x-ms-blob-condition-appendpos";
+    pub const X_MS_TAGS: &str = "x-ms-tags";
+    pub const X_MS_ACCESS_TIER: &str = "x-ms-access-tier";
+    pub const X_MS_VERSION_ID: &str = "x-ms-version-id";
 
     // Server-side encryption with customer-provided headers
     pub const X_MS_ENCRYPTION_KEY: &str = "x-ms-encryption-key";
@@ -51,6 +71,7 @@ mod constants {
 }
 
 pub struct AzblobCore {
+    pub account_name: Option<String>,
     pub container: String,
     pub root: String,
     pub endpoint: String,
@@ -61,6 +82,8 @@ pub struct AzblobCore {
     pub loader: AzureStorageLoader,
     pub signer: AzureStorageSigner,
     pub batch_max_operations: usize,
+    /// Cached user delegation key and its expiry.
+    pub user_delegation_key: Mutex<Option<(DateTime<Utc>, UserDelegationKey)>>,
 }
 
 impl Debug for AzblobCore {
@@ -91,12 +114,87 @@ impl AzblobCore {
         }
     }
 
-    pub async fn sign_query<T>(&self, req: &mut Request<T>) -> Result<()> {
+    /// Sign the request with a SAS scoped to the blob at `path`.
+    ///
+    /// - Account key will be used to sign a service SAS.
+    /// - Bearer token will be exchanged for a user delegation key to sign
+    ///   a user delegation SAS.
+    /// - Configured SAS token will be appended as is.
+    pub async fn sign_query<T>(
+        &self,
+        req: &mut Request<T>,
+        path: &str,
+        expire: Duration,
+    ) -> Result<()> {
         let cred = self.load_credential().await?;
 
-        self.signer
-            .sign_query(req, Duration::from_secs(3600), &cred)
-            .map_err(new_request_sign_error)
+        let p = build_abs_path(&self.root, path);
+        let expiry = Utc::now()
+            + chrono::Duration::from_std(expire).map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "presign expire is out of range").set_source(err)
+            })?;
+        let content_disposition = req.uri().query().and_then(|query| {
+            query
+                .split('&')
+                .find_map(|kv| kv.strip_prefix("rscd="))
+                .map(percent_decode_path)
+        });
+
+        let query = match &cred {
+            AzureStorageCredential::SharedKey(account, key) => {
+                BlobSas::new(account, &self.container, &p, req.method(), expiry)
+                    .with_content_disposition(content_disposition)
+                    .sign_with_account_key(key)?
+            }
+            AzureStorageCredential::BearerToken(_) => {
+                let account = self.account_name.as_deref().ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::ConfigInvalid,
+                        "account name is required to sign user delegation sas",
+                    )
+                })?;
+                let key = self.user_delegation_key(expiry).await?;
+
+                BlobSas::new(account, &self.container, &p, req.method(), expiry)
+                    .with_content_disposition(content_disposition)
+                    .sign_with_user_delegation_key(&key)?
+            }
+            AzureStorageCredential::SharedAccessSignature(_) => {
+                return self
+                    .signer
+                    .sign_query(req, expire, &cred)
+                    .map_err(new_request_sign_error);
+            }
+        };
+
+        let uri = match req.uri().query() {
+            Some(_) => format!("{}&{query}", req.uri()),
+            None => format!("{}?{query}", req.uri()),
+        };
+        *req.uri_mut() = Uri::from_str(&uri).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "building presigned uri").set_source(err)
+        })?;
+
+        Ok(())
+    }
+
+    /// Get a user delegation key that is valid until `expiry`.
+    ///
+    /// The key will be cached and reused until it expires. We will request
+    /// keys valid for at least one hour to avoid fetching a new key for every
+    /// presign.
+    async fn user_delegation_key(&self, expiry: DateTime<Utc>) -> Result<UserDelegationKey> {
+        if let Some((key_expiry, key)) = &*self.user_delegation_key.lock().expect("lock poisoned") {
+            if *key_expiry >= expiry {
+                return Ok(key.clone());
+            }
+        }
+
+        let expiry = expiry.max(Utc::now() + chrono::Duration::hours(1));
+        let key = self.azblob_get_user_delegation_key(expiry).await?;
+        *self.user_delegation_key.lock().expect("lock poisoned") = Some((expiry, key.clone()));
+
+        Ok(key)
     }
 
     pub async fn sign<T>(&self, req: &mut Request<T>) -> Result<()> {
@@ -174,6 +272,9 @@ impl AzblobCore {
                 percent_encode_path(override_content_disposition)
             ))
         }
+        if let Some(version) = args.version() {
+            query_args.push(format!("versionid={}", percent_encode_path(version)))
+        }
 
         if !query_args.is_empty() {
             url.push_str(&format!("?{}", query_args.join("&")));
@@ -258,6 +359,14 @@ impl AzblobCore {
             req = req.header(CONTENT_TYPE, ty)
         }
 
+        if let Some(tags) = args.tags() {
+            req = req.header(constants::X_MS_TAGS, format_tagging(tags));
+        }
+
+        if let Some(access_tier) = args.storage_class() {
+            req = req.header(constants::X_MS_ACCESS_TIER, access_tier);
+        }
+
         req = req.header(
             HeaderName::from_static(constants::X_MS_BLOB_TYPE),
             "BlockBlob",
@@ -282,6 +391,7 @@ impl AzblobCore {
     /// The following custom header could be set:
     /// - `content-type`
     /// - `x-ms-blob-cache-control`
+    /// - `x-ms-tags`
     ///
     /// # Reference
     ///
@@ -321,6 +431,10 @@ impl AzblobCore {
             req = req.header(constants::X_MS_BLOB_CACHE_CONTROL, cache_control);
         }
 
+        if let Some(tags) = args.tags() {
+            req = req.header(constants::X_MS_TAGS, format_tagging(tags));
+        }
+
         let req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
@@ -376,12 +490,15 @@ impl AzblobCore {
     ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!(
+        let mut url = format!(
             "{}/{}/{}",
             self.endpoint,
             self.container,
             percent_encode_path(&p)
         );
+        if let Some(version) = args.version() {
+            url.push_str(&format!("?versionid={}", percent_encode_path(version)));
+        }
 
         let mut req = Request::head(&url);
 
@@ -530,4 +647,175 @@ impl AzblobCore {
         self.sign(&mut req).await?;
         self.send(req).await
     }
+
+    /// Rehydrate an archived blob by setting its tier to `Hot`.
+    ///
+    /// # Reference
+    ///
+    /// https://learn.microsoft.com/en-us/rest/api/storageservices/set-blob-tier
+    pub async fn azblob_set_blob_tier(
+        &self,
+        path: &str,
+        tier: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}?comp=tier",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::put(&url)
+            .header(constants::X_MS_ACCESS_TIER, tier)
+            .header(CONTENT_LENGTH, 0)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Get the index tags of blob.
+    ///
+    /// # Reference
+    ///
+    /// https://learn.microsoft.com/en-us/rest/api/storageservices/get-blob-tags
+    pub async fn azblob_get_blob_tags(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}?comp=tags",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Replace the index tags of blob.
+    ///
+    /// # Reference
+    ///
+    /// https://learn.microsoft.com/en-us/rest/api/storageservices/set-blob-tags
+    pub async fn azblob_set_blob_tags(
+        &self,
+        path: &str,
+        tags: HashMap<String, String>,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}?comp=tags",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p)
+        );
+
+        let content =
+            quick_xml::se::to_string(&Tags::from(tags)).map_err(new_xml_deserialize_error)?;
+
+        let mut req = Request::put(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            .body(AsyncBody::Bytes(content.into()))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Get a user delegation key which is valid until `expiry`.
+    ///
+    /// # Reference
+    ///
+    /// https://learn.microsoft.com/en-us/rest/api/storageservices/get-user-delegation-key
+    pub async fn azblob_get_user_delegation_key(
+        &self,
+        expiry: DateTime<Utc>,
+    ) -> Result<UserDelegationKey> {
+        let url = format!("{}/?restype=service&comp=userdelegationkey", self.endpoint);
+
+        let content = quick_xml::se::to_string(&KeyInfo {
+            start: format_sas_time(Utc::now()),
+            expiry: format_sas_time(expiry),
+        })
+        .map_err(new_xml_deserialize_error)?;
+
+        let mut req = Request::post(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            .body(AsyncBody::Bytes(content.into()))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        let resp = self.send(req).await?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp).await?);
+        }
+
+        let bs = resp.into_body().bytes().await?;
+        quick_xml::de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)
+    }
+}
+
+/// Parse access tier from the response headers of `Get Blob Properties`.
+pub fn parse_access_tier(headers: &HeaderMap) -> Result<Option<&str>> {
+    parse_header_to_str(headers, constants::X_MS_ACCESS_TIER)
+}
+
+pub fn parse_version_id(headers: &HeaderMap) -> Result<Option<&str>> {
+    parse_header_to_str(headers, constants::X_MS_VERSION_ID)
+}
+
+/// Request and result of blob index tags.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename = "Tags", rename_all = "PascalCase")]
+pub struct Tags {
+    pub tag_set: TagSet,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct TagSet {
+    pub tag: Vec<Tag>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct Tag {
+    pub key: String,
+    pub value: String,
+}
+
+impl From<HashMap<String, String>> for Tags {
+    fn from(tags: HashMap<String, String>) -> Self {
+        let mut tag: Vec<_> = tags
+            .into_iter()
+            .map(|(key, value)| Tag { key, value })
+            .collect();
+        tag.sort_by(|a, b| a.key.cmp(&b.key));
+
+        Tags {
+            tag_set: TagSet { tag },
+        }
+    }
+}
+
+impl From<Tags> for HashMap<String, String> {
+    fn from(tags: Tags) -> Self {
+        tags.tag_set
+            .tag
+            .into_iter()
+            .map(|t| (t.key, t.value))
+            .collect()
+    }
 }
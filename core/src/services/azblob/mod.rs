@@ -21,4 +21,5 @@ pub use backend::AzblobBuilder as Azblob;
 mod core;
 mod error;
 mod pager;
+mod sas;
 mod writer;
@@ -107,7 +107,7 @@ impl oio::Page for AzblobPager {
                 continue;
             }
 
-            let meta = Metadata::new(EntryMode::FILE)
+            let mut meta = Metadata::new(EntryMode::FILE)
                 // Keep fit with ETag header.
                 .with_etag(format!("\"{}\"", object.properties.etag.as_str()))
                 .with_content_length(object.properties.content_length)
@@ -116,6 +116,9 @@ impl oio::Page for AzblobPager {
                 .with_last_modified(parse_datetime_from_rfc2822(
                     object.properties.last_modified.as_str(),
                 )?);
+            if let Some(access_tier) = &object.properties.access_tier {
+                meta.set_storage_class(access_tier);
+            }
 
             let de = oio::Entry::new(&build_rel_path(&self.core.root, &object.name), meta);
 
@@ -166,6 +169,7 @@ struct Properties {
     #[serde(rename = "Content-Type")]
     content_type: String,
     etag: String,
+    access_tier: Option<String>,
 }
 
 #[cfg(test)]
@@ -321,6 +325,11 @@ mod tests {
                 "0x8DA0A653DC82981".to_string()
             ]
         );
+        assert!(out
+            .blobs
+            .blob
+            .iter()
+            .all(|v| v.properties.access_tier.as_deref() == Some("Hot")));
         assert_eq!(
             out.blobs
                 .blob_prefix
@@ -252,6 +252,7 @@ impl Accessor for AzdlsBackend {
                 write_can_append: true,
                 create_dir: true,
                 delete: true,
+                delete_with_recursive: true,
                 rename: true,
 
                 list: true,
@@ -354,8 +355,8 @@ impl Accessor for AzdlsBackend {
         }
     }
 
-    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
-        let resp = self.core.azdls_delete(path).await?;
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let resp = self.core.azdls_delete(path, args.recursive()).await?;
 
         let status = resp.status();
 
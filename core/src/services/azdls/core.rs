@@ -253,17 +253,24 @@ impl AzdlsCore {
         self.client.send(req).await
     }
 
-    pub async fn azdls_delete(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    pub async fn azdls_delete(
+        &self,
+        path: &str,
+        recursive: bool,
+    ) -> Result<Response<IncomingAsyncBody>> {
         let p = build_abs_path(&self.root, path)
             .trim_end_matches('/')
             .to_string();
 
-        let url = format!(
+        let mut url = format!(
             "{}/{}/{}",
             self.endpoint,
             self.filesystem,
             percent_encode_path(&p)
         );
+        if recursive {
+            url.push_str("?recursive=true");
+        }
 
         let req = Request::delete(&url);
 
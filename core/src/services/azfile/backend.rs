@@ -266,6 +266,7 @@ impl Accessor for AzfileBackend {
                 read_with_range: true,
 
                 write: true,
+                write_with_offset: true,
                 create_dir: true,
                 delete: true,
                 rename: true,
@@ -328,7 +329,8 @@ impl Accessor for AzfileBackend {
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         self.core.ensure_parent_dir_exists(path).await?;
         let w = AzfileWriter::new(self.core.clone(), args.clone(), path.to_string());
-        let w = if args.append() {
+        // Write with offset shares the same logic with append, but starts at given offset.
+        let w = if args.append() || args.offset().is_some() {
             AzfileWriters::Two(oio::AppendObjectWriter::new(w))
         } else {
             AzfileWriters::One(oio::OneShotWriter::new(w))
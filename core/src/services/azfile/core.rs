@@ -209,6 +209,39 @@ impl AzfileCore {
         self.send(req).await
     }
 
+    /// Resize the file to given size, the extended range will be filled with zero.
+    ///
+    /// Reference: [Set File Properties](https://learn.microsoft.com/en-us/rest/api/storageservices/set-file-properties)
+    pub async fn azfile_resize_file(
+        &self,
+        path: &str,
+        size: u64,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path)
+            .trim_start_matches('/')
+            .to_string();
+
+        let url = format!(
+            "{}/{}/{}?comp=properties",
+            self.endpoint,
+            self.share_name,
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::put(&url);
+
+        req = req.header(X_MS_CONTENT_LENGTH, size);
+
+        // Content length must be 0 for set properties request.
+        req = req.header(CONTENT_LENGTH, 0);
+
+        let mut req = req
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub async fn azfile_get_file_properties(
         &self,
         path: &str,
@@ -80,9 +80,49 @@ impl oio::OneShotWrite for AzfileWriter {
     }
 }
 
+impl AzfileWriter {
+    /// Make sure the file exists and is large enough to hold `size` bytes.
+    async fn ensure_file_size(&self, size: u64) -> Result<()> {
+        let resp = self.core.azfile_get_file_properties(&self.path).await?;
+
+        let status = resp.status();
+        let resp = match status {
+            StatusCode::OK => {
+                let current = parse_content_length(resp.headers())?.unwrap_or_default();
+                resp.into_body().consume().await?;
+                if current >= size {
+                    return Ok(());
+                }
+
+                self.core.azfile_resize_file(&self.path, size).await?
+            }
+            StatusCode::NOT_FOUND => {
+                self.core
+                    .azfile_create_file(&self.path, size as usize, &self.op)
+                    .await?
+            }
+            _ => return Err(parse_error(resp).await?),
+        };
+
+        let status = resp.status();
+        match status {
+            StatusCode::OK | StatusCode::CREATED => {
+                resp.into_body().consume().await?;
+                Ok(())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+}
+
 #[async_trait]
 impl oio::AppendObjectWrite for AzfileWriter {
     async fn offset(&self) -> Result<u64> {
+        // Write with offset starts from the given position.
+        if let Some(offset) = self.op.offset() {
+            return Ok(offset);
+        }
+
         let resp = self.core.azfile_get_file_properties(&self.path).await?;
 
         let status = resp.status();
@@ -94,6 +134,10 @@ impl oio::AppendObjectWrite for AzfileWriter {
     }
 
     async fn append(&self, offset: u64, size: u64, body: AsyncBody) -> Result<()> {
+        if self.op.offset().is_some() {
+            self.ensure_file_size(offset + size).await?;
+        }
+
         let resp = self
             .core
             .azfile_update(&self.path, size, offset, body)
@@ -20,6 +20,7 @@ use std::fmt::Debug;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use bytes::Buf;
 use http::StatusCode;
 use http::Uri;
 use log::debug;
@@ -27,9 +28,12 @@ use reqsign::TencentCosConfig;
 use reqsign::TencentCosCredentialLoader;
 use reqsign::TencentCosSigner;
 
+use super::core::parse_storage_class;
 use super::core::CosCore;
+use super::core::Tagging;
 use super::error::parse_error;
 use super::pager::CosPager;
+use super::pager::CosUploadLister;
 use super::writer::CosWriter;
 use crate::raw::*;
 use crate::services::cos::writer::CosWriters;
@@ -268,9 +272,12 @@ impl Accessor for CosBackend {
                 write_can_empty: true,
                 write_can_append: true,
                 write_can_multi: true,
+                write_with_resume: true,
                 write_with_content_type: true,
                 write_with_cache_control: true,
                 write_with_content_disposition: true,
+                write_with_storage_class: true,
+                write_with_tags: true,
                 // The min multipart size of COS is 1 MiB.
                 //
                 // ref: <https://www.tencentcloud.com/document/product/436/14112>
@@ -296,6 +303,16 @@ impl Accessor for CosBackend {
                 presign_stat: true,
                 presign_read: true,
                 presign_write: true,
+                presign_write_multipart: true,
+                presign_post: true,
+
+                get_tags: true,
+                set_tags: true,
+
+                restore: true,
+
+                list_uploads: true,
+                abort_upload: true,
 
                 ..Default::default()
             });
@@ -347,7 +364,9 @@ impl Accessor for CosBackend {
         let w = if args.append() {
             CosWriters::Two(oio::AppendObjectWriter::new(writer))
         } else {
-            CosWriters::One(oio::MultipartUploadWriter::new(writer))
+            CosWriters::One(
+                oio::MultipartUploadWriter::new(writer).with_checkpoint(args.resume().cloned()),
+            )
         };
 
         Ok((RpWrite::default(), w))
@@ -379,7 +398,12 @@ impl Accessor for CosBackend {
 
         // The response is very similar to azblob.
         match status {
-            StatusCode::OK => parse_into_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::OK => {
+                let mut meta = parse_into_metadata(path, resp.headers())?;
+                meta.set_storage_class(parse_storage_class(resp.headers())?);
+
+                Ok(RpStat::new(meta))
+            }
             StatusCode::NOT_FOUND if path.ends_with('/') => {
                 Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
             }
@@ -408,6 +432,14 @@ impl Accessor for CosBackend {
                 self.core
                     .cos_put_object_request(path, None, v, AsyncBody::Empty)?
             }
+            // COS requires part number must between [1..=10000]
+            PresignOperation::WritePart(v) => self.core.cos_upload_part_request(
+                path,
+                v.upload_id(),
+                v.part_number() + 1,
+                None,
+                AsyncBody::Empty,
+            )?,
         };
         self.core.sign_query(&mut req, args.expire()).await?;
 
@@ -421,6 +453,124 @@ impl Accessor for CosBackend {
         )))
     }
 
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        let post = self.core.cos_presign_post(path, &args).await?;
+
+        Ok(RpPresignPost::new(post))
+    }
+
+    async fn initiate_multipart(
+        &self,
+        path: &str,
+        _: OpInitiateMultipart,
+    ) -> Result<RpInitiateMultipart> {
+        let w = CosWriter::new(self.core.clone(), path, OpWrite::default());
+        let upload_id = oio::MultipartUploadWrite::initiate_part(&w).await?;
+
+        Ok(RpInitiateMultipart::new(&upload_id))
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        // Part numbers of presigned parts start from 1.
+        let parts: Vec<_> = args
+            .etags()
+            .iter()
+            .enumerate()
+            .map(|(i, etag)| oio::MultipartUploadPart {
+                part_number: i + 1,
+                etag: etag.clone(),
+            })
+            .collect();
+
+        let w = CosWriter::new(self.core.clone(), path, OpWrite::default());
+        oio::MultipartUploadWrite::complete_part(&w, args.upload_id(), &parts).await?;
+
+        Ok(RpCompleteMultipart::default())
+    }
+
+    async fn list_uploads(
+        &self,
+        path: &str,
+        _: OpListUploads,
+    ) -> Result<(RpListUploads, oio::UploadPager)> {
+        let l = CosUploadLister::new(self.core.clone(), path);
+
+        Ok((
+            RpListUploads::default(),
+            Box::new(oio::MultipartUploadPager::new(l, &self.core.root)),
+        ))
+    }
+
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        let resp = self
+            .core
+            .cos_abort_multipart_upload(path, args.upload_id())
+            .await?;
+
+        match resp.status() {
+            StatusCode::NO_CONTENT => {
+                resp.into_body().consume().await?;
+                Ok(RpAbortUpload::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        let resp = self.core.cos_restore_object(path, args.days()).await?;
+
+        let status = resp.status();
+
+        match status {
+            // - 202 means the restore has been initiated.
+            // - 200 means the object has been restored, and the expiry is updated.
+            StatusCode::ACCEPTED | StatusCode::OK => {
+                resp.into_body().consume().await?;
+                Ok(RpRestore::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn get_tags(&self, path: &str, _: OpGetTags) -> Result<RpGetTags> {
+        let resp = self.core.cos_get_object_tagging(path).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+
+                let tagging: Tagging =
+                    quick_xml::de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)?;
+
+                Ok(RpGetTags::new(tagging.into()))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        let resp = self
+            .core
+            .cos_put_object_tagging(path, args.into_tags())
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                resp.into_body().consume().await?;
+                Ok(RpSetTags::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
         Ok((
             RpList::default(),
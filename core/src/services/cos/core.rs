@@ -15,17 +15,23 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::time::Duration;
 
 use bytes::Bytes;
+use chrono::Utc;
+use hmac::Hmac;
+use hmac::Mac;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
 use http::header::IF_MATCH;
 use http::header::IF_NONE_MATCH;
+use http::HeaderMap;
+use http::HeaderName;
 use http::Request;
 use http::Response;
 use reqsign::TencentCosCredential;
@@ -33,10 +39,23 @@ use reqsign::TencentCosCredentialLoader;
 use reqsign::TencentCosSigner;
 use serde::Deserialize;
 use serde::Serialize;
+use sha1::Digest;
+use sha1::Sha1;
 
 use crate::raw::*;
 use crate::*;
 
+fn hex_hmac_sha1(key: &[u8], content: &[u8]) -> String {
+    let mut mac = Hmac::<Sha1>::new_from_slice(key).expect("hmac accepts keys of any size");
+    mac.update(content);
+    format!("{:x}", mac.finalize().into_bytes())
+}
+
+mod constants {
+    pub const X_COS_STORAGE_CLASS: &str = "x-cos-storage-class";
+    pub const X_COS_TAGGING: &str = "x-cos-tagging";
+}
+
 pub struct CosCore {
     pub bucket: String,
     pub root: String,
@@ -94,6 +113,58 @@ impl CosCore {
             .map_err(new_request_sign_error)
     }
 
+    /// Sign a POST policy for `PostObject`.
+    ///
+    /// # Reference
+    ///
+    /// https://cloud.tencent.com/document/product/436/14690
+    pub async fn cos_presign_post(
+        &self,
+        path: &str,
+        args: &OpPresignPost,
+    ) -> Result<PresignedPost> {
+        let cred = self.load_credential().await?.ok_or_else(|| {
+            Error::new(
+                ErrorKind::ConfigInvalid,
+                "presign post requires a valid credential",
+            )
+        })?;
+
+        let p = build_abs_path(&self.root, path);
+        let now = Utc::now();
+        let expiration = now + chrono::Duration::seconds(args.expire().as_secs() as i64);
+        let key_time = format!("{};{}", now.timestamp(), expiration.timestamp());
+
+        let mut policy = PostPolicy::new(&p, args.conditions(), expiration);
+        policy.push_field("bucket", &self.bucket);
+        policy.push_field("q-sign-algorithm", "sha1");
+        policy.push_field("q-ak", &cred.secret_id);
+        policy.push_field("q-sign-time", &key_time);
+
+        let sign_key = hex_hmac_sha1(cred.secret_key.as_bytes(), key_time.as_bytes());
+        let string_to_sign = format!("{:x}", Sha1::digest(policy.to_json()));
+        let signature = hex_hmac_sha1(sign_key.as_bytes(), string_to_sign.as_bytes());
+
+        let mut fields = vec![("key".to_string(), format!("{p}${{filename}}"))];
+        if let Some(v) = args.conditions().content_type() {
+            fields.push(("Content-Type".to_string(), v.to_string()));
+        }
+        fields.push(("q-sign-algorithm".to_string(), "sha1".to_string()));
+        fields.push(("q-ak".to_string(), cred.secret_id));
+        fields.push(("q-key-time".to_string(), key_time));
+        if let Some(token) = cred.security_token {
+            fields.push(("x-cos-security-token".to_string(), token));
+        }
+        fields.push(("policy".to_string(), policy.to_base64()));
+        fields.push(("q-signature".to_string(), signature));
+
+        let uri = format!("{}/", self.endpoint).parse().map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "building presigned post uri").set_source(err)
+        })?;
+
+        Ok(PresignedPost::new(uri, fields))
+    }
+
     #[inline]
     pub async fn send(&self, req: Request<AsyncBody>) -> Result<Response<IncomingAsyncBody>> {
         self.client.send(req).await
@@ -165,6 +236,18 @@ impl CosCore {
         if let Some(mime) = args.content_type() {
             req = req.header(CONTENT_TYPE, mime)
         }
+        if let Some(storage_class) = args.storage_class() {
+            req = req.header(
+                HeaderName::from_static(constants::X_COS_STORAGE_CLASS),
+                storage_class,
+            )
+        }
+        if let Some(tags) = args.tags() {
+            req = req.header(
+                HeaderName::from_static(constants::X_COS_TAGGING),
+                format_tagging(tags),
+            )
+        }
 
         let req = req.body(body).map_err(new_request_build_error)?;
 
@@ -253,6 +336,13 @@ impl CosCore {
             req = req.header(CACHE_CONTROL, cache_control)
         }
 
+        if let Some(storage_class) = args.storage_class() {
+            req = req.header(
+                HeaderName::from_static(constants::X_COS_STORAGE_CLASS),
+                storage_class,
+            )
+        }
+
         let req = req.body(body).map_err(new_request_build_error)?;
         Ok(req)
     }
@@ -316,6 +406,40 @@ impl CosCore {
         self.send(req).await
     }
 
+    /// List on-going multipart uploads under given prefix.
+    pub async fn cos_list_multipart_uploads(
+        &self,
+        path: &str,
+        key_marker: &str,
+        upload_id_marker: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut queries = vec!["uploads".to_string()];
+        if !p.is_empty() {
+            queries.push(format!("prefix={}", percent_encode_path(&p)));
+        }
+        if !key_marker.is_empty() {
+            queries.push(format!("key-marker={}", percent_encode_path(key_marker)));
+        }
+        if !upload_id_marker.is_empty() {
+            queries.push(format!(
+                "upload-id-marker={}",
+                percent_encode_path(upload_id_marker)
+            ));
+        }
+
+        let url = format!("{}?{}", self.endpoint, queries.join("&"));
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
     pub async fn cos_initiate_multipart_upload(
         &self,
         path: &str,
@@ -339,6 +463,19 @@ impl CosCore {
             req = req.header(CACHE_CONTROL, cache_control)
         }
 
+        if let Some(storage_class) = args.storage_class() {
+            req = req.header(
+                HeaderName::from_static(constants::X_COS_STORAGE_CLASS),
+                storage_class,
+            )
+        }
+        if let Some(tags) = args.tags() {
+            req = req.header(
+                HeaderName::from_static(constants::X_COS_TAGGING),
+                format_tagging(tags),
+            )
+        }
+
         let mut req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
@@ -348,14 +485,14 @@ impl CosCore {
         self.send(req).await
     }
 
-    pub async fn cos_upload_part_request(
+    pub fn cos_upload_part_request(
         &self,
         path: &str,
         upload_id: &str,
         part_number: usize,
-        size: u64,
+        size: Option<u64>,
         body: AsyncBody,
-    ) -> Result<Response<IncomingAsyncBody>> {
+    ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -367,9 +504,23 @@ impl CosCore {
         );
 
         let mut req = Request::put(&url);
-        req = req.header(CONTENT_LENGTH, size);
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size);
+        }
         // Set body
-        let mut req = req.body(body).map_err(new_request_build_error)?;
+        req.body(body).map_err(new_request_build_error)
+    }
+
+    pub async fn cos_upload_part(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: AsyncBody,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req =
+            self.cos_upload_part_request(path, upload_id, part_number, Some(size), body)?;
 
         self.sign(&mut req).await?;
 
@@ -430,6 +581,142 @@ impl CosCore {
         self.sign(&mut req).await?;
         self.send(req).await
     }
+
+    pub async fn cos_restore_object(
+        &self,
+        path: &str,
+        days: u32,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?restore", self.endpoint, percent_encode_path(&p));
+
+        let content = quick_xml::se::to_string(&RestoreRequest {
+            days,
+            cas_job_parameters: CasJobParameters {
+                tier: "Standard".to_string(),
+            },
+        })
+        .map_err(new_xml_deserialize_error)?;
+
+        let mut req = Request::post(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            // Set content-md5 as required by API.
+            .header("CONTENT-MD5", format_content_md5(content.as_bytes()))
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    pub async fn cos_get_object_tagging(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    pub async fn cos_put_object_tagging(
+        &self,
+        path: &str,
+        tags: HashMap<String, String>,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let content =
+            quick_xml::se::to_string(&Tagging::from(tags)).map_err(new_xml_deserialize_error)?;
+
+        let mut req = Request::put(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            // Set content-md5 as required by API.
+            .header("CONTENT-MD5", format_content_md5(content.as_bytes()))
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+}
+
+/// Request of RestoreObject.
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename = "RestoreRequest", rename_all = "PascalCase")]
+pub struct RestoreRequest {
+    pub days: u32,
+    #[serde(rename = "CASJobParameters")]
+    pub cas_job_parameters: CasJobParameters,
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CasJobParameters {
+    pub tier: String,
+}
+
+/// Parse storage class from the response headers of `HeadObject`.
+///
+/// COS doesn't return storage class for `STANDARD` objects.
+pub fn parse_storage_class(headers: &HeaderMap) -> Result<&str> {
+    Ok(parse_header_to_str(headers, constants::X_COS_STORAGE_CLASS)?.unwrap_or("STANDARD"))
+}
+
+/// Request and result of object tagging.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename = "Tagging", rename_all = "PascalCase")]
+pub struct Tagging {
+    pub tag_set: TagSet,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct TagSet {
+    pub tag: Vec<Tag>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct Tag {
+    pub key: String,
+    pub value: String,
+}
+
+impl From<HashMap<String, String>> for Tagging {
+    fn from(tags: HashMap<String, String>) -> Self {
+        let mut tag: Vec<_> = tags
+            .into_iter()
+            .map(|(key, value)| Tag { key, value })
+            .collect();
+        tag.sort_by(|a, b| a.key.cmp(&b.key));
+
+        Tagging {
+            tag_set: TagSet { tag },
+        }
+    }
+}
+
+impl From<Tagging> for HashMap<String, String> {
+    fn from(tagging: Tagging) -> Self {
+        tagging
+            .tag_set
+            .tag
+            .into_iter()
+            .map(|t| (t.key, t.value))
+            .collect()
+    }
 }
 
 /// Result of CreateMultipartUpload
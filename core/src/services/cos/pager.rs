@@ -102,7 +102,10 @@ impl oio::Page for CosPager {
                 continue;
             }
 
-            let meta = Metadata::new(EntryMode::FILE).with_content_length(object.size);
+            let mut meta = Metadata::new(EntryMode::FILE).with_content_length(object.size);
+            if let Some(storage_class) = &object.storage_class {
+                meta.set_storage_class(storage_class);
+            }
 
             let de = oio::Entry::new(&build_rel_path(&self.core.root, &object.key), meta);
 
@@ -135,6 +138,7 @@ struct CommonPrefix {
 struct Content {
     key: String,
     size: u64,
+    storage_class: Option<String>,
 }
 
 #[cfg(test)]
@@ -208,3 +212,39 @@ mod tests {
         )
     }
 }
+
+/// CosUploadLister lists in-progress multipart uploads under given path.
+pub struct CosUploadLister {
+    core: Arc<CosCore>,
+    path: String,
+}
+
+impl CosUploadLister {
+    pub fn new(core: Arc<CosCore>, path: &str) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl oio::MultipartUploadList for CosUploadLister {
+    async fn list_multipart_uploads(
+        &self,
+        key_marker: &str,
+        upload_id_marker: &str,
+    ) -> Result<oio::ListMultipartUploadsResult> {
+        let resp = self
+            .core
+            .cos_list_multipart_uploads(&self.path, key_marker, upload_id_marker)
+            .await?;
+
+        if resp.status() != http::StatusCode::OK {
+            return Err(parse_error(resp).await?);
+        }
+
+        let bs = resp.into_body().bytes().await?;
+        de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)
+    }
+}
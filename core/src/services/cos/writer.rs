@@ -101,7 +101,7 @@ impl oio::MultipartUploadWrite for CosWriter {
 
         let resp = self
             .core
-            .cos_upload_part_request(&self.path, upload_id, part_number, size, body)
+            .cos_upload_part(&self.path, upload_id, part_number, size, body)
             .await?;
 
         let status = resp.status();
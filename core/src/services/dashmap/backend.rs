@@ -78,6 +78,7 @@ impl typed_kv::Adapter for Adapter {
                 set: true,
                 scan: true,
                 delete: true,
+                set_if_absent: false,
             },
         )
     }
@@ -175,6 +175,7 @@ impl Accessor for DbfsBackend {
                 write: true,
                 create_dir: true,
                 delete: true,
+                delete_with_recursive: true,
                 rename: true,
 
                 list: true,
@@ -264,8 +265,8 @@ impl Accessor for DbfsBackend {
     }
 
     /// NOTE: Server will return 200 even if the path doesn't exist.
-    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
-        let resp = self.core.dbfs_delete(path).await?;
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let resp = self.core.dbfs_delete(path, args.recursive()).await?;
 
         let status = resp.status();
 
@@ -70,7 +70,11 @@ impl DbfsCore {
         self.client.send(req).await
     }
 
-    pub async fn dbfs_delete(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    pub async fn dbfs_delete(
+        &self,
+        path: &str,
+        recursive: bool,
+    ) -> Result<Response<IncomingAsyncBody>> {
         let url = format!("{}/api/2.0/dbfs/delete", self.endpoint);
         let mut req = Request::post(&url);
 
@@ -83,8 +87,7 @@ impl DbfsCore {
 
         let request_body = &json!({
             "path": percent_encode_path(&p),
-            // TODO: support recursive toggle, should we add a new field in OpDelete?
-            "recursive": true,
+            "recursive": recursive,
         });
 
         let body = AsyncBody::Bytes(Bytes::from(request_body.to_string()));
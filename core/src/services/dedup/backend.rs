@@ -0,0 +1,353 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use super::chunker::Chunker;
+use super::core;
+use super::core::DedupCore;
+use super::pager::DedupPager;
+use super::reader::DedupReader;
+use super::writer::DedupWriter;
+use crate::raw::*;
+use crate::*;
+
+const DEFAULT_MIN_CHUNK_SIZE: usize = 256 * 1024;
+const DEFAULT_AVG_CHUNK_SIZE: usize = 1024 * 1024;
+const DEFAULT_MAX_CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+/// Dedup service support, stores files as content defined chunks.
+#[doc = include_str!("docs.md")]
+#[derive(Default, Clone)]
+pub struct DedupBuilder {
+    store: Option<Operator>,
+    min_chunk_size: Option<usize>,
+    avg_chunk_size: Option<usize>,
+    max_chunk_size: Option<usize>,
+}
+
+impl Debug for DedupBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DedupBuilder")
+            .field("store", &self.store.as_ref().map(|v| v.info().scheme()))
+            .field("min_chunk_size", &self.min_chunk_size)
+            .field("avg_chunk_size", &self.avg_chunk_size)
+            .field("max_chunk_size", &self.max_chunk_size)
+            .finish()
+    }
+}
+
+impl DedupBuilder {
+    /// Set the underlying store for chunks and manifests.
+    pub fn store(&mut self, op: Operator) -> &mut Self {
+        self.store = Some(op);
+        self
+    }
+
+    /// Set the min size of chunks, default to 256KiB.
+    pub fn min_chunk_size(&mut self, size: usize) -> &mut Self {
+        self.min_chunk_size = Some(size);
+        self
+    }
+
+    /// Set the expected average size of chunks, default to 1MiB.
+    ///
+    /// The size will be rounded up to the next power of two.
+    pub fn avg_chunk_size(&mut self, size: usize) -> &mut Self {
+        self.avg_chunk_size = Some(size);
+        self
+    }
+
+    /// Set the max size of chunks, default to 4MiB.
+    pub fn max_chunk_size(&mut self, size: usize) -> &mut Self {
+        self.max_chunk_size = Some(size);
+        self
+    }
+
+    /// Remove chunks that are not referenced by any file in the store,
+    /// returns the number of removed chunks.
+    ///
+    /// Chunks modified within `grace` will be kept so that chunks of
+    /// in-progress writes won't be removed. Existing chunks are touched at
+    /// most once per hour while being referenced again, so `grace` should be
+    /// longer than one hour plus the duration of the longest write. Chunks
+    /// are always removed if the store doesn't return last modified time,
+    /// please make sure there are no writes in progress in this case.
+    ///
+    /// No chunks will be removed if some manifests can't be read, an error
+    /// with all unreadable paths will be returned instead.
+    pub async fn gc(&self, grace: Duration) -> Result<usize> {
+        let store = self.store.as_ref().ok_or_else(|| {
+            Error::new(ErrorKind::ConfigInvalid, "store is empty")
+                .with_operation("DedupBuilder::gc")
+                .with_context("service", Scheme::Dedup)
+        })?;
+
+        core::gc(store, grace).await
+    }
+}
+
+impl Builder for DedupBuilder {
+    const SCHEME: Scheme = Scheme::Dedup;
+    type Accessor = DedupBackend;
+
+    fn from_map(map: HashMap<String, String>) -> Self {
+        let mut builder = Self::default();
+
+        map.get("min_chunk_size")
+            .and_then(|v| v.parse::<usize>().ok())
+            .map(|v| builder.min_chunk_size(v));
+        map.get("avg_chunk_size")
+            .and_then(|v| v.parse::<usize>().ok())
+            .map(|v| builder.avg_chunk_size(v));
+        map.get("max_chunk_size")
+            .and_then(|v| v.parse::<usize>().ok())
+            .map(|v| builder.max_chunk_size(v));
+
+        builder
+    }
+
+    fn build(&mut self) -> Result<Self::Accessor> {
+        let store = match self.store.take() {
+            Some(v) => v.into_inner(),
+            None => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "store is empty")
+                    .with_operation("Builder::build")
+                    .with_context("service", Scheme::Dedup))
+            }
+        };
+
+        let min = self.min_chunk_size.unwrap_or(DEFAULT_MIN_CHUNK_SIZE);
+        let avg = self.avg_chunk_size.unwrap_or(DEFAULT_AVG_CHUNK_SIZE);
+        let max = self.max_chunk_size.unwrap_or(DEFAULT_MAX_CHUNK_SIZE);
+        if min == 0 || min > avg || avg > max {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "chunk sizes must satisfy 0 < min <= avg <= max",
+            )
+            .with_operation("Builder::build")
+            .with_context("service", Scheme::Dedup)
+            .with_context("min_chunk_size", min.to_string())
+            .with_context("avg_chunk_size", avg.to_string())
+            .with_context("max_chunk_size", max.to_string()));
+        }
+
+        Ok(DedupBackend {
+            core: Arc::new(DedupCore {
+                store,
+                chunker: Chunker::new(min, avg, max),
+            }),
+        })
+    }
+}
+
+/// Backend for dedup services.
+#[derive(Debug, Clone)]
+pub struct DedupBackend {
+    core: Arc<DedupCore>,
+}
+
+#[async_trait]
+impl Accessor for DedupBackend {
+    type Reader = DedupReader;
+    type BlockingReader = ();
+    type Writer = DedupWriter;
+    type BlockingWriter = ();
+    type Pager = DedupPager;
+    type BlockingPager = ();
+
+    fn info(&self) -> AccessorInfo {
+        let info = self.core.store.info();
+        let sc = info.full_capability();
+
+        let mut cap = Capability::default();
+        cap.stat = sc.stat && sc.read;
+        cap.read = sc.read;
+        cap.read_can_next = cap.read;
+        cap.read_with_range = cap.read && sc.read_with_range;
+        // Stat is required to check whether chunks exist.
+        cap.write = sc.stat && sc.write;
+        cap.write_can_multi = cap.write;
+        cap.write_can_empty = cap.write && sc.write_can_empty;
+        cap.create_dir = sc.create_dir;
+        cap.delete = sc.delete;
+        cap.list = sc.list;
+        // Store has been completed, so we can list with any delimiter.
+        cap.list_with_delimiter_slash = cap.list;
+        cap.list_without_delimiter = cap.list;
+        // Only manifests need to be copied.
+        cap.copy = cap.read && sc.write;
+        cap.rename = cap.copy && cap.delete;
+
+        let mut am = AccessorInfo::default();
+        am.set_scheme(Scheme::Dedup)
+            .set_root("/")
+            .set_name(&format!(
+                "{}://{}{}",
+                info.scheme(),
+                info.name(),
+                info.root()
+            ))
+            .set_native_capability(cap);
+
+        am
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.core.store.create_dir(path, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let manifest = self.core.read_manifest(path).await?;
+
+        let total = manifest.size;
+        let range = args.range();
+        let (offset, size) = match (range.offset(), range.size()) {
+            (Some(offset), Some(size)) => (offset, size),
+            (Some(offset), None) => (offset, total.saturating_sub(offset)),
+            (None, Some(size)) => (total.saturating_sub(size), size.min(total)),
+            (None, None) => (0, total),
+        };
+        let size = size.min(total.saturating_sub(offset));
+
+        let chunks = manifest.locate(offset, size);
+        Ok((
+            RpRead::new().with_size(Some(size)),
+            DedupReader::new(self.core.store.clone(), chunks),
+        ))
+    }
+
+    async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        Ok((
+            RpWrite::default(),
+            DedupWriter::new(self.core.clone(), path),
+        ))
+    }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        let manifest = self.core.read_manifest(from).await?;
+        self.core.write_manifest(to, &manifest).await?;
+
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        self.copy(from, to, OpCopy::new()).await?;
+        self.core.store.delete(from, OpDelete::new()).await?;
+
+        Ok(RpRename::default())
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let stored = self.core.store.stat(path, args).await?.into_metadata();
+        if stored.is_dir() {
+            return Ok(RpStat::new(stored));
+        }
+
+        let manifest = self.core.read_manifest(path).await?;
+        let mut meta = Metadata::new(EntryMode::FILE).with_content_length(manifest.size);
+        if let Some(v) = stored.last_modified() {
+            meta = meta.with_last_modified(v);
+        }
+
+        Ok(RpStat::new(meta))
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        // Chunks will be removed by gc.
+        self.core.store.delete(path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        let (rp, p) = self.core.store.list(path, args).await?;
+
+        Ok((rp, DedupPager::new(p)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::*;
+
+    use super::*;
+    use crate::services::Memory;
+
+    fn new_operator(store: Operator) -> Result<Operator> {
+        let mut builder = DedupBuilder::default();
+        builder
+            .store(store)
+            .min_chunk_size(1024)
+            .avg_chunk_size(4096)
+            .max_chunk_size(16384);
+        Ok(Operator::new(builder)?.finish())
+    }
+
+    #[tokio::test]
+    async fn test_write_dedup_and_gc() -> Result<()> {
+        let store = Operator::new(Memory::default())?.finish();
+        let op = new_operator(store.clone())?;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut content = vec![0u8; 128 * 1024];
+        rng.fill_bytes(&mut content);
+
+        op.write("a", content.clone()).await?;
+        let chunks = store.list_with(".opendal_dedup/").delimiter("").await?;
+        let count = chunks.iter().filter(|e| e.metadata().is_file()).count();
+        assert!(count > 1);
+
+        // Writing near-identical content only adds a few chunks.
+        let mut modified = content.clone();
+        modified[64 * 1024] ^= 0xff;
+        op.write("b", modified.clone()).await?;
+        let chunks = store.list_with(".opendal_dedup/").delimiter("").await?;
+        let new_count = chunks.iter().filter(|e| e.metadata().is_file()).count();
+        assert!(new_count - count <= 2, "new chunks: {}", new_count - count);
+
+        assert_eq!(op.read("a").await?, content);
+        assert_eq!(op.read("b").await?, modified);
+        assert_eq!(
+            op.read_with("b").range(1000..70000).await?,
+            &modified[1000..70000]
+        );
+        assert_eq!(op.stat("b").await?.content_length(), modified.len() as u64);
+
+        let entries: Vec<_> = op
+            .list("/")
+            .await?
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        assert_eq!(entries, vec!["a", "b"]);
+
+        // Chunks only referenced by `b` will be removed.
+        op.delete("b").await?;
+        let mut builder = DedupBuilder::default();
+        builder.store(store.clone());
+        let removed = builder.gc(Duration::ZERO).await?;
+        assert_eq!(removed, new_count - count);
+        assert_eq!(op.read("a").await?, content);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+/// Gear table used by the rolling hash, generated by splitmix64 so that
+/// chunk boundaries are stable across builds and platforms.
+const GEAR: [u64; 256] = gear_table();
+
+const fn gear_table() -> [u64; 256] {
+    let mut table = [0u64; 256];
+    let mut state: u64 = 0x6f70_656e_6461_6c21;
+    let mut i = 0;
+    while i < 256 {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        table[i] = z ^ (z >> 31);
+        i += 1;
+    }
+    table
+}
+
+/// Chunker finds content defined chunk boundaries via gear hash.
+///
+/// Boundaries only depend on the content since the start of current chunk,
+/// so inserting or removing bytes in a file only affects nearby chunks.
+#[derive(Debug, Clone, Copy)]
+pub struct Chunker {
+    min_size: usize,
+    max_size: usize,
+    mask: u64,
+}
+
+impl Chunker {
+    /// Create a new chunker.
+    ///
+    /// Callers should make sure `0 < min_size <= avg_size <= max_size`.
+    pub fn new(min_size: usize, avg_size: usize, max_size: usize) -> Self {
+        let bits = avg_size.next_power_of_two().trailing_zeros();
+        // Use the high bits of hash which are affected by more bytes.
+        let mask = if bits == 0 {
+            0
+        } else {
+            (u64::MAX >> (64 - bits)) << (64 - bits)
+        };
+
+        Self {
+            min_size,
+            max_size,
+            mask,
+        }
+    }
+
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// Returns the size of the first chunk in `buf`.
+    ///
+    /// If no boundary found before `max_size`, the whole `buf` (at most
+    /// `max_size`) will be returned. So callers should only cut a chunk
+    /// when `buf` has at least `max_size` bytes or reaches the end of data.
+    pub fn cut(&self, buf: &[u8]) -> usize {
+        let end = buf.len().min(self.max_size);
+        if end <= self.min_size {
+            return end;
+        }
+
+        let mut hash = 0u64;
+        for (idx, b) in buf[..end].iter().enumerate().skip(self.min_size) {
+            hash = (hash << 1).wrapping_add(GEAR[*b as usize]);
+            if hash & self.mask == 0 {
+                return idx + 1;
+            }
+        }
+        end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::*;
+
+    use super::*;
+
+    fn split(chunker: &Chunker, mut buf: &[u8]) -> Vec<Vec<u8>> {
+        let mut chunks = vec![];
+        while !buf.is_empty() {
+            let n = chunker.cut(buf);
+            chunks.push(buf[..n].to_vec());
+            buf = &buf[n..];
+        }
+        chunks
+    }
+
+    #[test]
+    fn test_chunk_boundaries_are_content_defined() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut content = vec![0u8; 256 * 1024];
+        rng.fill_bytes(&mut content);
+
+        let chunker = Chunker::new(1024, 4096, 16384);
+        let chunks = split(&chunker, &content);
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|c| c.len() <= 16384));
+        assert!(chunks[..chunks.len() - 1].iter().all(|c| c.len() > 1024));
+
+        // Insert some bytes at the head, only the first few chunks should change.
+        let mut modified = b"hello, world".to_vec();
+        modified.extend_from_slice(&content);
+        let modified_chunks = split(&chunker, &modified);
+
+        let shared = modified_chunks
+            .iter()
+            .filter(|c| chunks.contains(c))
+            .count();
+        assert!(shared >= chunks.len() - 2, "shared chunks: {shared}");
+    }
+}
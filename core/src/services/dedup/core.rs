@@ -0,0 +1,301 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::time::Duration;
+
+use bytes::Buf;
+use bytes::Bytes;
+use chrono::Utc;
+use futures::TryStreamExt;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+use super::chunker::Chunker;
+use crate::raw::oio::ReadExt;
+use crate::raw::oio::WriteExt;
+use crate::raw::*;
+use crate::*;
+
+/// The dir in store to keep chunks.
+pub const CHUNK_DIR: &str = ".opendal_dedup/";
+
+/// Existing chunks older than this will be rewritten while being referenced
+/// again, so that their last modified time is refreshed for gc.
+pub const CHUNK_TOUCH_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// The number of chunks to remove in one call.
+const GC_REMOVE_BATCH: usize = 1000;
+
+pub struct DedupCore {
+    pub store: FusedAccessor,
+    pub chunker: Chunker,
+}
+
+impl Debug for DedupCore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DedupCore")
+            .field("store", &self.store.info().scheme())
+            .field("chunker", &self.chunker)
+            .finish()
+    }
+}
+
+impl DedupCore {
+    /// Store a chunk under its hash, returns the reference of this chunk.
+    ///
+    /// Chunks that already exist will not be written again, unless they
+    /// haven't been modified within [`CHUNK_TOUCH_INTERVAL`]. Rewriting them
+    /// refreshes the last modified time, so that gc won't remove chunks that
+    /// are referenced by in-progress writes.
+    pub async fn put_chunk(&self, bs: Bytes) -> Result<ChunkRef> {
+        let chunk = ChunkRef {
+            hash: format!("{:x}", Sha256::digest(&bs)),
+            size: bs.len() as u64,
+        };
+
+        let path = chunk_path(&chunk.hash);
+        match self.store.stat(&path, OpStat::new()).await {
+            Ok(rp) => match rp.into_metadata().last_modified() {
+                // Stale chunks need to be touched.
+                Some(t)
+                    if (Utc::now() - t).to_std().unwrap_or_default() >= CHUNK_TOUCH_INTERVAL => {}
+                // Touching is useless if last modified time is unknown, gc
+                // won't respect the grace period in this case.
+                _ => return Ok(chunk),
+            },
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+        write_all(&self.store, &path, bs).await?;
+
+        Ok(chunk)
+    }
+
+    pub async fn read_manifest(&self, path: &str) -> Result<Manifest> {
+        let (_, mut r) = self.store.read(path, OpRead::new()).await?;
+        let mut buf = Vec::new();
+        r.read_to_end(&mut buf).await?;
+
+        Manifest::decode(&buf).map_err(|err| err.with_context("path", path))
+    }
+
+    pub async fn write_manifest(&self, path: &str, manifest: &Manifest) -> Result<()> {
+        write_all(&self.store, path, manifest.encode()?).await
+    }
+}
+
+/// Manifest is stored at the logical path and describes how to reassemble
+/// the file from chunks.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    pub size: u64,
+    pub chunks: Vec<ChunkRef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChunkRef {
+    pub hash: String,
+    pub size: u64,
+}
+
+impl Manifest {
+    pub fn encode(&self) -> Result<Bytes> {
+        let bs = serde_json::to_vec(self).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "encode dedup manifest").set_source(err)
+        })?;
+        Ok(Bytes::from(bs))
+    }
+
+    pub fn decode(bs: &[u8]) -> Result<Self> {
+        serde_json::from_slice(bs).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "file is not a valid dedup manifest").set_source(err)
+        })
+    }
+
+    /// Returns the chunks that overlap with given range, along with the
+    /// range to read inside each chunk.
+    pub fn locate(&self, offset: u64, size: u64) -> Vec<(String, BytesRange)> {
+        let end = offset.saturating_add(size).min(self.size);
+
+        let mut chunks = vec![];
+        let mut start = 0;
+        for chunk in &self.chunks {
+            let chunk_end = start + chunk.size;
+            if chunk_end > offset && start < end {
+                let begin = offset.max(start) - start;
+                let len = end.min(chunk_end) - start - begin;
+                chunks.push((
+                    chunk_path(&chunk.hash),
+                    BytesRange::new(Some(begin), Some(len)),
+                ));
+            }
+            if chunk_end >= end {
+                break;
+            }
+            start = chunk_end;
+        }
+        chunks
+    }
+}
+
+pub fn chunk_path(hash: &str) -> String {
+    format!("{CHUNK_DIR}{}/{hash}", &hash[..2])
+}
+
+/// Remove all chunks that are not referenced by any manifest, returns the
+/// number of removed chunks.
+///
+/// Chunks modified within `grace` will be kept, so that chunks written by
+/// in-progress writes won't be removed. Existing chunks are touched at most
+/// once per [`CHUNK_TOUCH_INTERVAL`], so `grace` should be longer than it
+/// plus the duration of the longest write.
+///
+/// Files that are not valid manifests will be skipped. If some manifests
+/// can't be read, all of them will be reported and no chunks will be
+/// removed, since their chunks are unknown.
+pub async fn gc(store: &Operator, grace: Duration) -> Result<usize> {
+    let mut referenced = HashSet::new();
+    let mut unreadable = vec![];
+
+    let mut lister = store.lister_with("/").delimiter("").await?;
+    while let Some(entry) = lister.try_next().await? {
+        if entry.path().starts_with(CHUNK_DIR) || entry.path().ends_with('/') {
+            continue;
+        }
+
+        let bs = match store.read(entry.path()).await {
+            Ok(bs) => bs,
+            // File has been deleted after listed.
+            Err(err) if err.kind() == ErrorKind::NotFound => continue,
+            Err(err) => {
+                log::warn!("dedup gc can't read manifest {}: {err}", entry.path());
+                unreadable.push(entry.path().to_string());
+                continue;
+            }
+        };
+        match Manifest::decode(&bs) {
+            Ok(manifest) => referenced.extend(manifest.chunks.into_iter().map(|c| c.hash)),
+            Err(err) => log::warn!("dedup gc skips file {}: {err}", entry.path()),
+        }
+    }
+
+    if !unreadable.is_empty() {
+        return Err(Error::new(
+            ErrorKind::Unexpected,
+            "dedup gc keeps all chunks since some manifests are unreadable",
+        )
+        .with_context("count", unreadable.len().to_string())
+        .with_context("paths", unreadable.join(",")));
+    }
+
+    let grace = chrono::Duration::from_std(grace).map_err(|err| {
+        Error::new(ErrorKind::Unexpected, "grace period is out of range").set_source(err)
+    })?;
+    let deadline = Utc::now() - grace;
+
+    let mut removed = 0;
+    let mut unreferenced = Vec::with_capacity(GC_REMOVE_BATCH);
+    let mut lister = store
+        .lister_with(CHUNK_DIR)
+        .delimiter("")
+        .metakey(Metakey::Mode | Metakey::LastModified)
+        .await?;
+    while let Some(entry) = lister.try_next().await? {
+        let meta = entry.metadata();
+        if !meta.mode().is_file() {
+            continue;
+        }
+        let hash = entry.name();
+        if referenced.contains(hash) {
+            continue;
+        }
+        if matches!(meta.last_modified(), Some(t) if t > deadline) {
+            continue;
+        }
+
+        unreferenced.push(entry.path().to_string());
+        if unreferenced.len() >= GC_REMOVE_BATCH {
+            removed += unreferenced.len();
+            store.remove(std::mem::take(&mut unreferenced)).await?;
+        }
+    }
+    removed += unreferenced.len();
+    store.remove(unreferenced).await?;
+
+    Ok(removed)
+}
+
+async fn write_all(acc: &FusedAccessor, path: &str, mut bs: Bytes) -> Result<()> {
+    let (_, mut w) = acc.write(path, OpWrite::new()).await?;
+    while bs.has_remaining() {
+        let n = w.write(&bs).await?;
+        bs.advance(n);
+    }
+    w.close().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_locate() {
+        let manifest = Manifest {
+            size: 10,
+            chunks: vec![
+                ChunkRef {
+                    hash: "aa".to_string(),
+                    size: 4,
+                },
+                ChunkRef {
+                    hash: "bb".to_string(),
+                    size: 4,
+                },
+                ChunkRef {
+                    hash: "cc".to_string(),
+                    size: 2,
+                },
+            ],
+        };
+
+        assert_eq!(
+            manifest.locate(0, 10),
+            vec![
+                (chunk_path("aa"), BytesRange::new(Some(0), Some(4))),
+                (chunk_path("bb"), BytesRange::new(Some(0), Some(4))),
+                (chunk_path("cc"), BytesRange::new(Some(0), Some(2))),
+            ]
+        );
+        assert_eq!(
+            manifest.locate(3, 2),
+            vec![
+                (chunk_path("aa"), BytesRange::new(Some(3), Some(1))),
+                (chunk_path("bb"), BytesRange::new(Some(0), Some(1))),
+            ]
+        );
+        assert_eq!(
+            manifest.locate(8, 100),
+            vec![(chunk_path("cc"), BytesRange::new(Some(0), Some(2)))]
+        );
+        assert_eq!(manifest.locate(10, 1), vec![]);
+    }
+}
@@ -0,0 +1,55 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use async_trait::async_trait;
+
+use super::core::CHUNK_DIR;
+use crate::raw::*;
+use crate::*;
+
+/// DedupPager hides the chunks and drops the metadata of manifests, which
+/// doesn't describe the logical files.
+pub struct DedupPager {
+    inner: oio::Pager,
+}
+
+impl DedupPager {
+    pub fn new(inner: oio::Pager) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl oio::Page for DedupPager {
+    async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        let entries = self.inner.next().await?;
+
+        Ok(entries.map(|entries| {
+            entries
+                .into_iter()
+                .filter(|e| !e.path().starts_with(CHUNK_DIR))
+                .map(|e| {
+                    if e.mode().is_file() {
+                        oio::Entry::new(e.path(), Metadata::new(EntryMode::FILE))
+                    } else {
+                        e
+                    }
+                })
+                .collect()
+        }))
+    }
+}
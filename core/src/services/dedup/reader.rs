@@ -0,0 +1,136 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::io::SeekFrom;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
+use crate::raw::*;
+use crate::*;
+
+/// DedupReader reads the needed chunks one by one.
+pub struct DedupReader {
+    store: FusedAccessor,
+    chunks: VecDeque<(String, BytesRange)>,
+    state: State,
+}
+
+enum State {
+    Idle,
+    Send(BoxFuture<'static, Result<(RpRead, oio::Reader)>>),
+    Read(oio::Reader),
+}
+
+/// # Safety
+///
+/// We will only take `&mut Self` reference for DedupReader.
+unsafe impl Sync for DedupReader {}
+
+impl DedupReader {
+    pub fn new(store: FusedAccessor, chunks: Vec<(String, BytesRange)>) -> Self {
+        Self {
+            store,
+            chunks: chunks.into(),
+            state: State::Idle,
+        }
+    }
+
+    /// Make sure there is a reader for current chunk, returns false if all
+    /// chunks have been consumed.
+    fn poll_reader(&mut self, cx: &mut Context<'_>) -> Poll<Result<bool>> {
+        loop {
+            match &mut self.state {
+                State::Idle => {
+                    let Some((path, range)) = self.chunks.pop_front() else {
+                        return Poll::Ready(Ok(false));
+                    };
+
+                    let store = self.store.clone();
+                    self.state = State::Send(Box::pin(async move {
+                        store.read(&path, OpRead::new().with_range(range)).await
+                    }));
+                }
+                State::Send(fut) => {
+                    let res = ready!(fut.poll_unpin(cx));
+                    self.state = match res {
+                        Ok((_, r)) => State::Read(r),
+                        Err(err) => {
+                            self.state = State::Idle;
+                            return Poll::Ready(Err(err));
+                        }
+                    };
+                }
+                State::Read(_) => return Poll::Ready(Ok(true)),
+            }
+        }
+    }
+}
+
+impl oio::Read for DedupReader {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        loop {
+            if !ready!(self.poll_reader(cx))? {
+                return Poll::Ready(Ok(0));
+            }
+
+            let State::Read(r) = &mut self.state else {
+                unreachable!("reader must be ready")
+            };
+            match ready!(r.poll_read(cx, buf))? {
+                0 => self.state = State::Idle,
+                n => return Poll::Ready(Ok(n)),
+            }
+        }
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        let (_, _) = (cx, pos);
+
+        Poll::Ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "output reader doesn't support seeking",
+        )))
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        loop {
+            match ready!(self.poll_reader(cx)) {
+                Ok(true) => {}
+                Ok(false) => return Poll::Ready(None),
+                Err(err) => return Poll::Ready(Some(Err(err))),
+            }
+
+            let State::Read(r) = &mut self.state else {
+                unreachable!("reader must be ready")
+            };
+            match ready!(r.poll_next(cx)) {
+                None => self.state = State::Idle,
+                Some(res) => return Poll::Ready(Some(res)),
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Bytes;
+use bytes::BytesMut;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
+use super::core::ChunkRef;
+use super::core::DedupCore;
+use super::core::Manifest;
+use crate::raw::*;
+use crate::*;
+
+/// DedupWriter splits the content into chunks, and writes the manifest
+/// after all chunks have been stored.
+pub struct DedupWriter {
+    core: Arc<DedupCore>,
+    path: String,
+
+    buf: BytesMut,
+    /// The chunk that has been cut from buffer but not stored yet.
+    pending: Option<Bytes>,
+    manifest: Manifest,
+    uploading: Option<BoxFuture<'static, Result<ChunkRef>>>,
+    finishing: Option<BoxFuture<'static, Result<()>>>,
+}
+
+impl DedupWriter {
+    pub fn new(core: Arc<DedupCore>, path: &str) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+            buf: BytesMut::new(),
+            pending: None,
+            manifest: Manifest::default(),
+            uploading: None,
+            finishing: None,
+        }
+    }
+
+    /// Drive current upload to finish.
+    fn poll_upload(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        if let Some(fut) = self.uploading.as_mut() {
+            let res = ready!(fut.poll_unpin(cx));
+            self.uploading = None;
+
+            // Keep the pending chunk so that it can be retried.
+            let chunk = res?;
+            self.pending = None;
+            self.manifest.size += chunk.size;
+            self.manifest.chunks.push(chunk);
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    /// Cut the first chunk from buffer and start uploading it.
+    fn start_upload(&mut self) {
+        let bs = match &self.pending {
+            Some(bs) => bs.clone(),
+            None => {
+                let n = self.core.chunker.cut(&self.buf);
+                let bs = self.buf.split_to(n).freeze();
+                self.pending = Some(bs.clone());
+                bs
+            }
+        };
+
+        let core = self.core.clone();
+        self.uploading = Some(Box::pin(async move { core.put_chunk(bs).await }));
+    }
+}
+
+/// # Safety
+///
+/// We will only take `&mut Self` reference for DedupWriter.
+unsafe impl Sync for DedupWriter {}
+
+impl oio::Write for DedupWriter {
+    fn poll_write(&mut self, cx: &mut Context<'_>, bs: &dyn oio::WriteBuf) -> Poll<Result<usize>> {
+        let max_size = self.core.chunker.max_size();
+
+        loop {
+            ready!(self.poll_upload(cx))?;
+            // Only cut chunks while we have enough data, so that the
+            // boundaries won't be affected by how users call write.
+            if self.pending.is_none() && self.buf.len() < max_size {
+                break;
+            }
+            self.start_upload();
+        }
+
+        let chunk = bs.chunk();
+        let n = chunk.len().min(max_size - self.buf.len());
+        self.buf.extend_from_slice(&chunk[..n]);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        loop {
+            if let Some(fut) = self.finishing.as_mut() {
+                let res = ready!(fut.poll_unpin(cx));
+                self.finishing = None;
+                return Poll::Ready(res);
+            }
+
+            ready!(self.poll_upload(cx))?;
+            if self.pending.is_some() || !self.buf.is_empty() {
+                self.start_upload();
+                continue;
+            }
+
+            let core = self.core.clone();
+            let path = self.path.clone();
+            let manifest = self.manifest.clone();
+            self.finishing = Some(Box::pin(async move {
+                core.write_manifest(&path, &manifest).await
+            }));
+        }
+    }
+
+    fn poll_abort(&mut self, _: &mut Context<'_>) -> Poll<Result<()>> {
+        // Chunks that have been stored will be cleaned up by gc.
+        self.uploading = None;
+        self.finishing = None;
+        self.buf.clear();
+        self.pending = None;
+        self.manifest = Manifest::default();
+        Poll::Ready(Ok(()))
+    }
+}
@@ -26,9 +26,13 @@ use etcd_client::Certificate;
 use etcd_client::Client;
 use etcd_client::ConnectOptions;
 use etcd_client::Error as EtcdError;
+use etcd_client::EventType;
 use etcd_client::GetOptions;
 use etcd_client::Identity;
 use etcd_client::TlsOptions;
+use etcd_client::WatchOptions;
+use etcd_client::WatchStream;
+use etcd_client::Watcher;
 use serde::Deserialize;
 use tokio::sync::OnceCell;
 
@@ -348,6 +352,8 @@ impl kv::Adapter for Adapter {
                 create_dir: true,
                 list: true,
 
+                watch: true,
+
                 ..Default::default()
             },
         )
@@ -389,6 +395,62 @@ impl kv::Adapter for Adapter {
 
         Ok(res)
     }
+
+    async fn watch(&self, path: &str) -> Result<oio::Watcher> {
+        // Watch will hold the client until it's dropped, so we don't take
+        // it from the pool.
+        let mut client = self.conn().await?.clone();
+        let (watcher, stream) = client
+            .watch(path, Some(WatchOptions::new().with_prefix()))
+            .await?;
+
+        Ok(Box::new(EtcdWatcher {
+            _watcher: watcher,
+            stream,
+        }))
+    }
+}
+
+/// EtcdWatcher receives changes via etcd's watch API.
+pub struct EtcdWatcher {
+    /// Keep the watcher alive until EtcdWatcher is dropped.
+    _watcher: Watcher,
+    stream: WatchStream,
+}
+
+/// # Safety
+///
+/// We will only take `&mut Self` reference for EtcdWatcher.
+unsafe impl Sync for EtcdWatcher {}
+
+#[async_trait]
+impl oio::Watch for EtcdWatcher {
+    async fn next(&mut self) -> Result<Option<Vec<WatchEvent>>> {
+        let Some(resp) = self.stream.message().await? else {
+            return Ok(None);
+        };
+
+        let mut events = Vec::with_capacity(resp.events().len());
+        for event in resp.events() {
+            let Some(kv) = event.kv() else {
+                continue;
+            };
+            let key = kv.key_str().map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "store key is not valid utf-8 string")
+                    .set_source(err)
+            })?;
+
+            let kind = match event.event_type() {
+                // Version of key will be reset to 1 once it's created.
+                EventType::Put if kv.version() == 1 => WatchEventKind::Create,
+                EventType::Put => WatchEventKind::Modify,
+                EventType::Delete => WatchEventKind::Delete,
+            };
+            events.push(WatchEvent::new(kind, key));
+        }
+
+        Ok(Some(events))
+    }
 }
 
 impl From<EtcdError> for Error {
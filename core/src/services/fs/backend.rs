@@ -16,15 +16,20 @@
 // under the License.
 
 use std::collections::HashMap;
+use std::io::Seek;
+use std::io::SeekFrom;
 use std::path::Path;
 use std::path::PathBuf;
 
 use async_trait::async_trait;
 use chrono::DateTime;
 use log::debug;
+use tokio::io::AsyncSeekExt;
 use uuid::Uuid;
 
 use super::pager::FsPager;
+#[cfg(feature = "services-fs-watch")]
+use super::watcher::FsWatcher;
 use super::writer::FsWriter;
 use crate::raw::*;
 use crate::*;
@@ -265,8 +270,10 @@ impl Accessor for FsBackend {
                 write_can_empty: true,
                 write_can_append: true,
                 write_can_multi: true,
+                write_with_offset: true,
                 create_dir: true,
                 delete: true,
+                delete_with_recursive: true,
 
                 list: true,
                 list_with_delimiter_slash: true,
@@ -275,6 +282,8 @@ impl Accessor for FsBackend {
                 rename: true,
                 blocking: true,
 
+                watch: cfg!(feature = "services-fs-watch"),
+
                 ..Default::default()
             });
 
@@ -337,10 +346,13 @@ impl Accessor for FsBackend {
                 Self::ensure_write_abs_path(atomic_write_dir, &tmp_file_of(path)).await?;
 
             // If the target file exists, we should append to the end of it directly.
-            if op.append()
-                && tokio::fs::try_exists(&target_path)
-                    .await
-                    .map_err(new_std_io_error)?
+            //
+            // Write with offset always happens on the target file in place.
+            if op.offset().is_some()
+                || (op.append()
+                    && tokio::fs::try_exists(&target_path)
+                        .await
+                        .map_err(new_std_io_error)?)
             {
                 (target_path, None)
             } else {
@@ -356,15 +368,21 @@ impl Accessor for FsBackend {
         open_options.create(true).write(true);
         if op.append() {
             open_options.append(true);
-        } else {
+        } else if op.offset().is_none() {
             open_options.truncate(true);
         }
 
-        let f = open_options
+        let mut f = open_options
             .open(tmp_path.as_ref().unwrap_or(&target_path))
             .await
             .map_err(new_std_io_error)?;
 
+        if let Some(offset) = op.offset() {
+            f.seek(SeekFrom::Start(offset))
+                .await
+                .map_err(new_std_io_error)?;
+        }
+
         Ok((RpWrite::new(), FsWriter::new(target_path, tmp_path, f)))
     }
 
@@ -426,14 +444,18 @@ impl Accessor for FsBackend {
         Ok(RpStat::new(m))
     }
 
-    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         let p = self.root.join(path.trim_end_matches('/'));
 
         let meta = tokio::fs::metadata(&p).await;
 
         match meta {
             Ok(meta) => {
-                if meta.is_dir() {
+                if meta.is_dir() && args.recursive() {
+                    tokio::fs::remove_dir_all(&p)
+                        .await
+                        .map_err(new_std_io_error)?;
+                } else if meta.is_dir() {
                     tokio::fs::remove_dir(&p).await.map_err(new_std_io_error)?;
                 } else {
                     tokio::fs::remove_file(&p).await.map_err(new_std_io_error)?;
@@ -465,6 +487,13 @@ impl Accessor for FsBackend {
         Ok((RpList::default(), Some(rd)))
     }
 
+    #[cfg(feature = "services-fs-watch")]
+    async fn watch(&self, path: &str, _: OpWatch) -> Result<(RpWatch, oio::Watcher)> {
+        let w = FsWatcher::new(&self.root, self.atomic_write_dir.as_deref(), path)?;
+
+        Ok((RpWatch::default(), Box::new(w)))
+    }
+
     fn blocking_create_dir(&self, path: &str, _: OpCreateDir) -> Result<RpCreateDir> {
         let p = self.root.join(path.trim_end_matches('/'));
 
@@ -510,10 +539,13 @@ impl Accessor for FsBackend {
                 Self::blocking_ensure_write_abs_path(atomic_write_dir, &tmp_file_of(path))?;
 
             // If the target file exists, we should append to the end of it directly.
-            if op.append()
-                && Path::new(&target_path)
-                    .try_exists()
-                    .map_err(new_std_io_error)?
+            //
+            // Write with offset always happens on the target file in place.
+            if op.offset().is_some()
+                || (op.append()
+                    && Path::new(&target_path)
+                        .try_exists()
+                        .map_err(new_std_io_error)?)
             {
                 (target_path, None)
             } else {
@@ -530,14 +562,18 @@ impl Accessor for FsBackend {
 
         if op.append() {
             f.append(true);
-        } else {
+        } else if op.offset().is_none() {
             f.truncate(true);
         }
 
-        let f = f
+        let mut f = f
             .open(tmp_path.as_ref().unwrap_or(&target_path))
             .map_err(new_std_io_error)?;
 
+        if let Some(offset) = op.offset() {
+            f.seek(SeekFrom::Start(offset)).map_err(new_std_io_error)?;
+        }
+
         Ok((RpWrite::new(), FsWriter::new(target_path, tmp_path, f)))
     }
 
@@ -597,14 +633,16 @@ impl Accessor for FsBackend {
         Ok(RpStat::new(m))
     }
 
-    fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         let p = self.root.join(path.trim_end_matches('/'));
 
         let meta = std::fs::metadata(&p);
 
         match meta {
             Ok(meta) => {
-                if meta.is_dir() {
+                if meta.is_dir() && args.recursive() {
+                    std::fs::remove_dir_all(&p).map_err(new_std_io_error)?;
+                } else if meta.is_dir() {
                     std::fs::remove_dir(&p).map_err(new_std_io_error)?;
                 } else {
                     std::fs::remove_file(&p).map_err(new_std_io_error)?;
@@ -655,4 +693,32 @@ mod tests {
             assert!(tmp_file.starts_with(expected_prefix));
         }
     }
+
+    #[cfg(feature = "services-fs-watch")]
+    #[tokio::test]
+    async fn test_watch() -> Result<()> {
+        use futures::StreamExt;
+
+        let dir = std::env::temp_dir().join(Uuid::new_v4().to_string());
+        let mut builder = FsBuilder::default();
+        builder.root(&dir.to_string_lossy());
+        let op = Operator::new(builder)?.finish();
+        op.create_dir("data/").await?;
+
+        let mut watcher = op.watch("data/").await?;
+        op.write("data/a", "hello").await?;
+        op.write("other", "hello").await?;
+        op.delete("data/a").await?;
+
+        let mut events = vec![];
+        while events.last() != Some(&(WatchEventKind::Delete, "data/a".to_string())) {
+            let event = watcher.next().await.expect("watcher must not be closed")?;
+            assert_eq!(event.path(), "data/a");
+            events.push((event.kind(), event.path().to_string()));
+        }
+        assert_eq!(events[0], (WatchEventKind::Create, "data/a".to_string()));
+
+        drop(watcher);
+        std::fs::remove_dir_all(dir).map_err(new_std_io_error)
+    }
 }
@@ -19,4 +19,6 @@ mod backend;
 pub use backend::FsBuilder as Fs;
 
 mod pager;
+#[cfg(feature = "services-fs-watch")]
+mod watcher;
 mod writer;
@@ -0,0 +1,139 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use futures::channel::mpsc;
+use futures::StreamExt;
+use notify::event::CreateKind;
+use notify::event::ModifyKind;
+use notify::event::RemoveKind;
+use notify::event::RenameMode;
+use notify::EventKind;
+use notify::RecommendedWatcher;
+use notify::RecursiveMode;
+use notify::Watcher;
+
+use crate::raw::*;
+use crate::*;
+
+/// FsWatcher receives changes via native file system notifications, like
+/// inotify on linux.
+pub struct FsWatcher {
+    root: PathBuf,
+    /// Files in this dir are temporary files of atomic write.
+    atomic_write_dir: Option<PathBuf>,
+
+    /// Keep the watcher alive until FsWatcher is dropped.
+    _watcher: RecommendedWatcher,
+    rx: mpsc::UnboundedReceiver<notify::Result<notify::Event>>,
+}
+
+/// # Safety
+///
+/// We will only take `&mut Self` reference for FsWatcher.
+unsafe impl Sync for FsWatcher {}
+
+impl FsWatcher {
+    pub fn new(root: &Path, atomic_write_dir: Option<&Path>, path: &str) -> Result<Self> {
+        let (tx, rx) = mpsc::unbounded();
+        let mut watcher = notify::recommended_watcher(move |res| {
+            // Receiver has been dropped means watcher is closing.
+            let _ = tx.unbounded_send(res);
+        })
+        .map_err(new_notify_error)?;
+
+        let p = root.join(path.trim_end_matches('/'));
+        watcher
+            .watch(&p, RecursiveMode::Recursive)
+            .map_err(new_notify_error)?;
+
+        Ok(Self {
+            root: root.to_owned(),
+            atomic_write_dir: atomic_write_dir.map(|v| v.to_owned()),
+            _watcher: watcher,
+            rx,
+        })
+    }
+
+    fn rel_path(&self, p: &Path) -> Option<String> {
+        if let Some(dir) = &self.atomic_write_dir {
+            if p.starts_with(dir) {
+                return None;
+            }
+        }
+
+        let rel = p.strip_prefix(&self.root).ok()?;
+        Some(normalize_path(&rel.to_string_lossy().replace('\\', "/")))
+    }
+
+    fn convert(&self, event: notify::Event, events: &mut Vec<WatchEvent>) {
+        let kinds: &[WatchEventKind] = match event.kind {
+            EventKind::Create(CreateKind::Folder) => &[],
+            EventKind::Create(_) => &[WatchEventKind::Create],
+            EventKind::Modify(ModifyKind::Name(RenameMode::From)) => &[WatchEventKind::Delete],
+            EventKind::Modify(ModifyKind::Name(RenameMode::To)) => &[WatchEventKind::Create],
+            // Paths of `Both` are `[from, to]`.
+            EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+                &[WatchEventKind::Delete, WatchEventKind::Create]
+            }
+            EventKind::Modify(ModifyKind::Metadata(_)) => &[],
+            EventKind::Modify(_) => &[WatchEventKind::Modify],
+            EventKind::Remove(RemoveKind::Folder) => &[],
+            EventKind::Remove(_) => &[WatchEventKind::Delete],
+            _ => &[],
+        };
+
+        for (kind, p) in kinds.iter().zip(event.paths.iter()) {
+            if let Some(path) = self.rel_path(p) {
+                events.push(WatchEvent::new(*kind, &path));
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl oio::Watch for FsWatcher {
+    async fn next(&mut self) -> Result<Option<Vec<WatchEvent>>> {
+        let mut events = vec![];
+
+        while events.is_empty() {
+            let Some(res) = self.rx.next().await else {
+                return Ok(None);
+            };
+            self.convert(res.map_err(new_notify_error)?, &mut events);
+
+            // Collect all events that are ready.
+            while let Ok(Some(res)) = self.rx.try_next() {
+                self.convert(res.map_err(new_notify_error)?, &mut events);
+            }
+        }
+
+        Ok(Some(events))
+    }
+}
+
+fn new_notify_error(err: notify::Error) -> Error {
+    let kind = match err.kind {
+        notify::ErrorKind::PathNotFound => ErrorKind::NotFound,
+        _ => ErrorKind::Unexpected,
+    };
+
+    Error::new(kind, "file system notification failed").set_source(err)
+}
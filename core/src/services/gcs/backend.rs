@@ -16,11 +16,15 @@
 // under the License.
 
 use std::collections::HashMap;
+use std::env;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use http::Response;
 use http::StatusCode;
 use log::debug;
 use reqsign::GoogleCredentialLoader;
@@ -31,6 +35,7 @@ use serde::Deserialize;
 use serde_json;
 
 use super::core::GcsCore;
+use super::core::ServiceAccountKey;
 use super::error::parse_error;
 use super::pager::GcsPager;
 use super::writer::GcsWriter;
@@ -66,6 +71,7 @@ pub struct GcsBuilder {
     customed_token_loader: Option<Box<dyn GoogleTokenLoad>>,
     predefined_acl: Option<String>,
     default_storage_class: Option<String>,
+    enable_append_emulation: bool,
 }
 
 impl GcsBuilder {
@@ -183,6 +189,20 @@ impl GcsBuilder {
         };
         self
     }
+
+    /// Enable append emulation for GCS.
+    ///
+    /// GCS doesn't support append natively. With emulation enabled, the
+    /// appended data will be uploaded as a temporary object first, and then
+    /// composed with the existing object into the target.
+    ///
+    /// The emulation is NOT atomic: concurrent writers to the same path could
+    /// overwrite each other, and the temporary object is visible in list
+    /// before being cleaned up.
+    pub fn enable_append_emulation(&mut self) -> &mut Self {
+        self.enable_append_emulation = true;
+        self
+    }
 }
 
 impl Debug for GcsBuilder {
@@ -199,6 +219,7 @@ impl Debug for GcsBuilder {
             ds.field("predefined_acl", &self.predefined_acl);
         }
         ds.field("default_storage_class", &self.default_storage_class);
+        ds.field("enable_append_emulation", &self.enable_append_emulation);
         ds.finish()
     }
 }
@@ -218,6 +239,9 @@ impl Builder for GcsBuilder {
         map.get("predefined_acl").map(|v| builder.predefined_acl(v));
         map.get("default_storage_class")
             .map(|v| builder.default_storage_class(v));
+        map.get("enable_append_emulation")
+            .filter(|v| *v == "on" || *v == "true")
+            .map(|_| builder.enable_append_emulation());
 
         builder
     }
@@ -263,6 +287,13 @@ impl Builder for GcsBuilder {
             cred_loader = cred_loader.with_path(cred);
         }
 
+        let service_account_key =
+            load_service_account_key(self.credential.as_deref(), self.credential_path.as_deref())
+                .map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Gcs)
+            })?;
+
         let scope = if let Some(scope) = &self.scope {
             scope
         } else {
@@ -291,8 +322,10 @@ impl Builder for GcsBuilder {
                 signer,
                 token_loader,
                 credential_loader: cred_loader,
+                service_account_key,
                 predefined_acl: self.predefined_acl.clone(),
                 default_storage_class: self.default_storage_class.clone(),
+                enable_append_emulation: self.enable_append_emulation,
             }),
         };
 
@@ -332,10 +365,13 @@ impl Accessor for GcsBackend {
                 read_with_range: true,
                 read_with_if_match: true,
                 read_with_if_none_match: true,
+                read_with_version: true,
 
                 write: true,
                 write_can_empty: true,
                 write_can_multi: true,
+                write_with_resume: true,
+                write_can_append: self.core.enable_append_emulation,
                 write_with_content_type: true,
                 // The buffer size should be a multiple of 256 KiB (256 x 1024 bytes), unless it's the last chunk that completes the upload.
                 // Larger chunk sizes typically make uploads faster, but note that there's a tradeoff between speed and memory usage.
@@ -354,11 +390,15 @@ impl Accessor for GcsBackend {
                 list_without_delimiter: true,
 
                 batch: true,
+                batch_delete: true,
+                batch_stat: true,
+                batch_copy: true,
                 batch_max_operations: Some(100),
                 presign: true,
                 presign_stat: true,
                 presign_read: true,
                 presign_write: true,
+                presign_post: self.core.service_account_key.is_some(),
 
                 ..Default::default()
             });
@@ -399,8 +439,12 @@ impl Accessor for GcsBackend {
     }
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
-        let w = GcsWriter::new(self.core.clone(), path, args);
-        let w = oio::RangeWriter::new(w);
+        let w = GcsWriter::new(self.core.clone(), path, args.clone());
+        let w = if args.append() {
+            GcsWriters::Two(oio::AppendObjectWriter::new(w))
+        } else {
+            GcsWriters::One(oio::RangeWriter::new(w).with_checkpoint(args.resume().cloned()))
+        };
 
         Ok((RpWrite::default(), w))
     }
@@ -424,40 +468,7 @@ impl Accessor for GcsBackend {
 
         let resp = self.core.gcs_get_object_metadata(path, &args).await?;
 
-        if resp.status().is_success() {
-            // read http response body
-            let slc = resp.into_body().bytes().await?;
-
-            let meta: GetObjectJsonResponse =
-                serde_json::from_slice(&slc).map_err(new_json_deserialize_error)?;
-
-            let mode = if path.ends_with('/') {
-                EntryMode::DIR
-            } else {
-                EntryMode::FILE
-            };
-            let mut m = Metadata::new(mode);
-
-            m.set_etag(&meta.etag);
-            m.set_content_md5(&meta.md5_hash);
-
-            let size = meta
-                .size
-                .parse::<u64>()
-                .map_err(|e| Error::new(ErrorKind::Unexpected, "parse u64").set_source(e))?;
-            m.set_content_length(size);
-            if !meta.content_type.is_empty() {
-                m.set_content_type(&meta.content_type);
-            }
-
-            m.set_last_modified(parse_datetime_from_rfc3339(&meta.updated)?);
-
-            Ok(RpStat::new(m))
-        } else if resp.status() == StatusCode::NOT_FOUND && path.ends_with('/') {
-            Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
-        } else {
-            Err(parse_error(resp).await?)
-        }
+        parse_stat_response(path, resp).await
     }
 
     async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
@@ -489,13 +500,12 @@ impl Accessor for GcsBackend {
         if ops.len() > 100 {
             return Err(Error::new(
                 ErrorKind::Unsupported,
-                "gcs services only allow delete less than 100 keys at once",
+                "gcs services only allow less than 100 operations in one batch",
             )
             .with_context("length", ops.len().to_string()));
         }
 
-        let paths: Vec<String> = ops.into_iter().map(|(p, _)| p).collect();
-        let resp = self.core.gcs_delete_objects(paths.clone()).await?;
+        let resp = self.core.gcs_batch(&ops).await?;
 
         let status = resp.status();
 
@@ -503,7 +513,7 @@ impl Accessor for GcsBackend {
             let content_type = parse_content_type(resp.headers())?.ok_or_else(|| {
                 Error::new(
                     ErrorKind::Unexpected,
-                    "gcs batch delete response content type is empty",
+                    "gcs batch response content type is empty",
                 )
             })?;
             let boundary = content_type
@@ -511,7 +521,7 @@ impl Accessor for GcsBackend {
                 .ok_or_else(|| {
                     Error::new(
                         ErrorKind::Unexpected,
-                        "gcs batch delete response content type is not multipart/mixed",
+                        "gcs batch response content type is not multipart/mixed",
                     )
                 })?
                 .trim_matches('"');
@@ -520,19 +530,41 @@ impl Accessor for GcsBackend {
                 .parse(resp.into_body().bytes().await?)?;
             let parts = multipart.into_parts();
 
+            if ops.len() != parts.len() {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "invalid batch response, operations and response parts don't match",
+                ));
+            }
+
             let mut batched_result = Vec::with_capacity(parts.len());
 
-            for (i, part) in parts.into_iter().enumerate() {
+            for ((path, op), part) in ops.into_iter().zip(parts) {
                 let resp = part.into_response();
-                // TODO: maybe we can take it directly?
-                let path = paths[i].clone();
-
-                // deleting not existing objects is ok
-                if resp.status().is_success() || resp.status() == StatusCode::NOT_FOUND {
-                    batched_result.push((path, Ok(RpDelete::default().into())));
-                } else {
-                    batched_result.push((path, Err(parse_error(resp).await?)));
-                }
+
+                let result = match op {
+                    BatchOperation::Delete(_) => {
+                        // deleting not existing objects is ok
+                        if resp.status().is_success() || resp.status() == StatusCode::NOT_FOUND {
+                            Ok(RpDelete::default().into())
+                        } else {
+                            Err(parse_error(resp).await?)
+                        }
+                    }
+                    BatchOperation::Stat(_) => {
+                        parse_stat_response(&path, resp).await.map(|rp| rp.into())
+                    }
+                    BatchOperation::Copy(_, _) => {
+                        if resp.status().is_success() {
+                            resp.into_body().consume().await?;
+                            Ok(RpCopy::default().into())
+                        } else {
+                            Err(parse_error(resp).await?)
+                        }
+                    }
+                };
+
+                batched_result.push((path, result));
             }
 
             Ok(RpBatch::new(batched_result))
@@ -552,6 +584,12 @@ impl Accessor for GcsBackend {
                 self.core
                     .gcs_insert_object_xml_request(path, v, AsyncBody::Empty)?
             }
+            PresignOperation::WritePart(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "gcs doesn't support presign multipart upload",
+                ))
+            }
         };
 
         self.core.sign_query(&mut req, args.expire()).await?;
@@ -565,16 +603,99 @@ impl Accessor for GcsBackend {
             parts.headers,
         )))
     }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        let post = self.core.gcs_presign_post(path, &args)?;
+
+        Ok(RpPresignPost::new(post))
+    }
+}
+
+/// Load service account key from the credential configured or the file
+/// pointed by `GOOGLE_APPLICATION_CREDENTIALS`.
+///
+/// Returns `None` if there is no credential or the credential is not a
+/// service account, since others like external accounts can't sign.
+fn load_service_account_key(
+    credential: Option<&str>,
+    credential_path: Option<&str>,
+) -> Result<Option<ServiceAccountKey>> {
+    let content = if let Some(v) = credential {
+        BASE64_STANDARD.decode(v).map_err(|err| {
+            Error::new(ErrorKind::ConfigInvalid, "credential is not valid base64").set_source(err)
+        })?
+    } else {
+        let path = match credential_path
+            .map(|v| v.to_string())
+            .or_else(|| env::var("GOOGLE_APPLICATION_CREDENTIALS").ok())
+        {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+        std::fs::read(&path).map_err(|err| {
+            Error::new(ErrorKind::ConfigInvalid, "read credential file failed")
+                .with_context("path", &path)
+                .set_source(err)
+        })?
+    };
+
+    let value: serde_json::Value =
+        serde_json::from_slice(&content).map_err(new_json_deserialize_error)?;
+    if value.get("type").and_then(|v| v.as_str()) != Some("service_account") {
+        return Ok(None);
+    }
+
+    ServiceAccountKey::from_credential(value).map(Some)
+}
+
+async fn parse_stat_response(path: &str, resp: Response<IncomingAsyncBody>) -> Result<RpStat> {
+    if resp.status().is_success() {
+        // read http response body
+        let slc = resp.into_body().bytes().await?;
+
+        let meta: GetObjectJsonResponse =
+            serde_json::from_slice(&slc).map_err(new_json_deserialize_error)?;
+
+        let mode = if path.ends_with('/') {
+            EntryMode::DIR
+        } else {
+            EntryMode::FILE
+        };
+        let mut m = Metadata::new(mode);
+
+        m.set_etag(&meta.etag);
+        m.set_content_md5(&meta.md5_hash);
+
+        let size = meta
+            .size
+            .parse::<u64>()
+            .map_err(|e| Error::new(ErrorKind::Unexpected, "parse u64").set_source(e))?;
+        m.set_content_length(size);
+        if !meta.content_type.is_empty() {
+            m.set_content_type(&meta.content_type);
+        }
+
+        m.set_last_modified(parse_datetime_from_rfc3339(&meta.updated)?);
+        if !meta.generation.is_empty() {
+            m.set_version(&meta.generation);
+        }
+
+        Ok(RpStat::new(m))
+    } else if resp.status() == StatusCode::NOT_FOUND && path.ends_with('/') {
+        Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
+    } else {
+        Err(parse_error(resp).await?)
+    }
 }
 
 /// The raw json response returned by [`get`](https://cloud.google.com/storage/docs/json_api/v1/objects/get)
 #[derive(Debug, Default, Deserialize)]
 #[serde(default, rename_all = "camelCase")]
-struct GetObjectJsonResponse {
+pub(super) struct GetObjectJsonResponse {
     /// GCS will return size in string.
     ///
     /// For example: `"size": "56535"`
-    size: String,
+    pub(super) size: String,
     /// etag is not quoted.
     ///
     /// For example: `"etag": "CKWasoTgyPkCEAE="`
@@ -591,6 +712,10 @@ struct GetObjectJsonResponse {
     ///
     /// For example: `"contentType": "image/png",`
     content_type: String,
+    /// Generation of this object, used as its version.
+    ///
+    /// For example: `"generation": "1660563214863653"`
+    generation: String,
 }
 
 #[cfg(test)]
@@ -627,5 +752,28 @@ mod tests {
         assert_eq!(meta.md5_hash, "fHcEH1vPwA6eTPqxuasXcg==");
         assert_eq!(meta.etag, "CKWasoTgyPkCEAE=");
         assert_eq!(meta.content_type, "image/png");
+        assert_eq!(meta.generation, "1660563214863653");
+    }
+
+    #[test]
+    fn test_load_service_account_key() {
+        let encode = |v: serde_json::Value| BASE64_STANDARD.encode(v.to_string());
+
+        let cred = encode(serde_json::json!({
+            "type": "authorized_user",
+            "client_id": "test",
+        }));
+        let key = load_service_account_key(Some(&cred), None).expect("load must succeed");
+        assert!(key.is_none());
+
+        let cred = encode(serde_json::json!({
+            "type": "service_account",
+            "client_email": "test@example.iam.gserviceaccount.com",
+            "private_key": "invalid",
+        }));
+        let err = load_service_account_key(Some(&cred), None)
+            .err()
+            .expect("invalid key must fail");
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
     }
 }
@@ -23,6 +23,8 @@ use std::time::Duration;
 
 use backon::ExponentialBuilder;
 use backon::Retryable;
+use bytes::Bytes;
+use chrono::Utc;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_RANGE;
 use http::header::CONTENT_TYPE;
@@ -37,7 +39,14 @@ use reqsign::GoogleCredentialLoader;
 use reqsign::GoogleSigner;
 use reqsign::GoogleToken;
 use reqsign::GoogleTokenLoader;
+use rsa::pkcs1v15::SigningKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::signature::SignatureEncoding;
+use rsa::signature::Signer;
+use rsa::RsaPrivateKey;
+use serde::Deserialize;
 use serde_json::json;
+use sha2::Sha256;
 
 use super::uri::percent_encode_path;
 use crate::raw::*;
@@ -52,9 +61,11 @@ pub struct GcsCore {
     pub signer: GoogleSigner,
     pub token_loader: GoogleTokenLoader,
     pub credential_loader: GoogleCredentialLoader,
+    pub service_account_key: Option<ServiceAccountKey>,
 
     pub predefined_acl: Option<String>,
     pub default_storage_class: Option<String>,
+    pub enable_append_emulation: bool,
 }
 
 impl Debug for GcsCore {
@@ -67,6 +78,40 @@ impl Debug for GcsCore {
     }
 }
 
+/// The key of service account which is used to sign POST policies.
+///
+/// Only service account credentials carry a private key, others like
+/// external accounts can't sign POST policies.
+#[derive(Clone)]
+pub struct ServiceAccountKey {
+    pub client_email: String,
+    pub signing_key: SigningKey<Sha256>,
+}
+
+impl ServiceAccountKey {
+    /// Parse the key from a service account credential.
+    ///
+    /// The private key is parsed here so that invalid keys are reported
+    /// while building instead of presigning.
+    pub fn from_credential(value: serde_json::Value) -> Result<Self> {
+        #[derive(Deserialize)]
+        struct Credential {
+            client_email: String,
+            private_key: String,
+        }
+
+        let cred: Credential = serde_json::from_value(value).map_err(new_json_deserialize_error)?;
+        let private_key = RsaPrivateKey::from_pkcs8_pem(&cred.private_key).map_err(|err| {
+            Error::new(ErrorKind::ConfigInvalid, "service account key is invalid").set_source(err)
+        })?;
+
+        Ok(Self {
+            client_email: cred.client_email,
+            signing_key: SigningKey::new(private_key),
+        })
+    }
+}
+
 static BACKOFF: Lazy<ExponentialBuilder> =
     Lazy::new(|| ExponentialBuilder::default().with_jitter());
 
@@ -139,6 +184,68 @@ impl GcsCore {
         Ok(())
     }
 
+    /// Sign a POST policy with the service account key.
+    ///
+    /// # Reference
+    ///
+    /// https://cloud.google.com/storage/docs/xml-api/post-object-forms
+    pub fn gcs_presign_post(&self, path: &str, args: &OpPresignPost) -> Result<PresignedPost> {
+        let key = self.service_account_key.as_ref().ok_or_else(|| {
+            Error::new(
+                ErrorKind::ConfigInvalid,
+                "presign post requires a service account credential",
+            )
+        })?;
+
+        let p = build_abs_path(&self.root, path);
+        let now = Utc::now();
+        let expiration = now + chrono::Duration::seconds(args.expire().as_secs() as i64);
+
+        let mut policy = PostPolicy::new(&p, args.conditions(), expiration);
+        policy.push_field("bucket", &self.bucket);
+
+        let mut fields = vec![("key".to_string(), format!("{p}${{filename}}"))];
+        if let Some(v) = args.conditions().content_type() {
+            fields.push(("Content-Type".to_string(), v.to_string()));
+        }
+        let auth_fields = [
+            ("x-goog-algorithm", "GOOG4-RSA-SHA256".to_string()),
+            (
+                "x-goog-credential",
+                format!(
+                    "{}/{}/auto/storage/goog4_request",
+                    key.client_email,
+                    now.format("%Y%m%d")
+                ),
+            ),
+            ("x-goog-date", now.format("%Y%m%dT%H%M%SZ").to_string()),
+        ];
+        for (k, v) in auth_fields {
+            policy.push_field(k, &v);
+            fields.push((k.to_string(), v));
+        }
+        let policy = policy.to_base64();
+
+        let signature = key
+            .signing_key
+            .sign(policy.as_bytes())
+            .to_bytes()
+            .iter()
+            .map(|v| format!("{v:02x}"))
+            .collect();
+
+        fields.push(("policy".to_string(), policy));
+        fields.push(("x-goog-signature".to_string(), signature));
+
+        let uri = format!("{}/{}", self.endpoint, self.bucket)
+            .parse()
+            .map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "building presigned post uri").set_source(err)
+            })?;
+
+        Ok(PresignedPost::new(uri, fields))
+    }
+
     #[inline]
     pub async fn send(&self, req: Request<AsyncBody>) -> Result<Response<IncomingAsyncBody>> {
         self.client.send(req).await
@@ -149,12 +256,16 @@ impl GcsCore {
     pub fn gcs_get_object_request(&self, path: &str, args: &OpRead) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!(
+        let mut url = format!(
             "{}/storage/v1/b/{}/o/{}?alt=media",
             self.endpoint,
             self.bucket,
             percent_encode_path(&p)
         );
+        if let Some(version) = args.version() {
+            write!(url, "&generation={}", percent_encode_path(version))
+                .expect("write into string must succeed");
+        }
 
         let mut req = Request::get(&url);
 
@@ -183,7 +294,11 @@ impl GcsCore {
     ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!("{}/{}/{}", self.endpoint, self.bucket, p);
+        let mut url = format!("{}/{}/{}", self.endpoint, self.bucket, p);
+        if let Some(version) = args.version() {
+            write!(url, "?generation={}", percent_encode_path(version))
+                .expect("write into string must succeed");
+        }
 
         let mut req = Request::get(&url);
 
@@ -335,12 +450,16 @@ impl GcsCore {
     pub fn gcs_head_object_request(&self, path: &str, args: &OpStat) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!(
+        let mut url = format!(
             "{}/storage/v1/b/{}/o/{}",
             self.endpoint,
             self.bucket,
             percent_encode_path(&p)
         );
+        if let Some(version) = args.version() {
+            write!(url, "?generation={}", percent_encode_path(version))
+                .expect("write into string must succeed");
+        }
 
         let mut req = Request::get(&url);
 
@@ -367,7 +486,11 @@ impl GcsCore {
     ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!("{}/{}/{}", self.endpoint, self.bucket, p);
+        let mut url = format!("{}/{}/{}", self.endpoint, self.bucket, p);
+        if let Some(version) = args.version() {
+            write!(url, "?generation={}", percent_encode_path(version))
+                .expect("write into string must succeed");
+        }
 
         let mut req = Request::head(&url);
 
@@ -420,16 +543,23 @@ impl GcsCore {
             .map_err(new_request_build_error)
     }
 
-    pub async fn gcs_delete_objects(
+    /// Send delete, stat and copy operations in one batch request.
+    ///
+    /// Reference: [Sending batch requests](https://cloud.google.com/storage/docs/batch)
+    pub async fn gcs_batch(
         &self,
-        paths: Vec<String>,
+        ops: &[(String, BatchOperation)],
     ) -> Result<Response<IncomingAsyncBody>> {
         let uri = format!("{}/batch/storage/v1", self.endpoint);
 
         let mut multipart = Multipart::new();
 
-        for (idx, path) in paths.iter().enumerate() {
-            let req = self.gcs_delete_object_request(path)?;
+        for (idx, (path, op)) in ops.iter().enumerate() {
+            let req = match op {
+                BatchOperation::Delete(_) => self.gcs_delete_object_request(path)?,
+                BatchOperation::Stat(args) => self.gcs_head_object_request(path, args)?,
+                BatchOperation::Copy(to, _) => self.gcs_copy_object_request(path, to)?,
+            };
 
             multipart = multipart.part(
                 MixedPart::from_request(req).part_header("content-id".parse().unwrap(), idx.into()),
@@ -443,11 +573,7 @@ impl GcsCore {
         self.send(req).await
     }
 
-    pub async fn gcs_copy_object(
-        &self,
-        from: &str,
-        to: &str,
-    ) -> Result<Response<IncomingAsyncBody>> {
+    pub fn gcs_copy_object_request(&self, from: &str, to: &str) -> Result<Request<AsyncBody>> {
         let source = build_abs_path(&self.root, from);
         let dest = build_abs_path(&self.root, to);
 
@@ -460,9 +586,64 @@ impl GcsCore {
             percent_encode_path(&dest)
         );
 
-        let mut req = Request::post(req_uri)
+        Request::post(req_uri)
             .header(CONTENT_LENGTH, 0)
             .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)
+    }
+
+    pub async fn gcs_copy_object(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.gcs_copy_object_request(from, to)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Compose `sources` into `dest` in order.
+    ///
+    /// Reference: [Objects: compose](https://cloud.google.com/storage/docs/json_api/v1/objects/compose)
+    pub async fn gcs_compose_object(
+        &self,
+        sources: &[&str],
+        dest: &str,
+        op: &OpWrite,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let dest = build_abs_path(&self.root, dest);
+
+        let req_uri = format!(
+            "{}/storage/v1/b/{}/o/{}/compose",
+            self.endpoint,
+            self.bucket,
+            percent_encode_path(&dest)
+        );
+
+        let sources: Vec<_> = sources
+            .iter()
+            .map(|p| json!({ "name": build_abs_path(&self.root, p) }))
+            .collect();
+
+        let mut destination = HashMap::new();
+        if let Some(content_type) = op.content_type() {
+            destination.insert("contentType", content_type);
+        }
+        if let Some(cache_control) = op.cache_control() {
+            destination.insert("cacheControl", cache_control);
+        }
+
+        let body = json!({
+            "sourceObjects": sources,
+            "destination": destination,
+        })
+        .to_string();
+
+        let mut req = Request::post(req_uri)
+            .header(CONTENT_TYPE, "application/json")
+            .header(CONTENT_LENGTH, body.len())
+            .body(AsyncBody::Bytes(Bytes::from(body)))
             .map_err(new_request_build_error)?;
 
         self.sign(&mut req).await?;
@@ -564,17 +745,21 @@ impl GcsCore {
         size: u64,
         body: AsyncBody,
     ) -> Result<Response<IncomingAsyncBody>> {
+        // An empty range is expressed as `bytes */total`.
+        let range = if size == 0 {
+            format!("bytes */{written}")
+        } else {
+            format!(
+                "bytes {}-{}/{}",
+                written,
+                written + size - 1,
+                written + size
+            )
+        };
+
         let mut req = Request::post(location)
             .header(CONTENT_LENGTH, size)
-            .header(
-                CONTENT_RANGE,
-                format!(
-                    "bytes {}-{}/{}",
-                    written,
-                    written + size - 1,
-                    written + size
-                ),
-            )
+            .header(CONTENT_RANGE, range)
             .body(body)
             .map_err(new_request_build_error)?;
 
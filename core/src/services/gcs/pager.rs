@@ -135,6 +135,9 @@ impl oio::Page for GcsPager {
             }
 
             meta.set_last_modified(parse_datetime_from_rfc3339(object.updated.as_str())?);
+            if !object.generation.is_empty() {
+                meta.set_version(&object.generation);
+            }
 
             let de = oio::Entry::new(path, meta);
 
@@ -172,6 +175,7 @@ struct ListResponseItem {
     md5_hash: String,
     updated: String,
     content_type: String,
+    generation: String,
 }
 
 #[cfg(test)]
@@ -20,12 +20,14 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use http::StatusCode;
 
+use super::backend::GetObjectJsonResponse;
 use super::core::GcsCore;
 use super::error::parse_error;
 use crate::raw::*;
 use crate::*;
 
-pub type GcsWriters = oio::RangeWriter<GcsWriter>;
+pub type GcsWriters =
+    oio::TwoWaysWriter<oio::RangeWriter<GcsWriter>, oio::AppendObjectWriter<GcsWriter>>;
 
 pub struct GcsWriter {
     core: Arc<GcsCore>,
@@ -146,3 +148,67 @@ impl oio::RangeWrite for GcsWriter {
         }
     }
 }
+
+/// Append is emulated by compose since GCS doesn't support it natively.
+#[async_trait]
+impl oio::AppendObjectWrite for GcsWriter {
+    async fn offset(&self) -> Result<u64> {
+        let resp = self
+            .core
+            .gcs_get_object_metadata(&self.path, &OpStat::default())
+            .await?;
+
+        let status = resp.status();
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+
+                let meta: GetObjectJsonResponse =
+                    serde_json::from_slice(&bs).map_err(new_json_deserialize_error)?;
+
+                meta.size.parse::<u64>().map_err(|e| {
+                    Error::new(ErrorKind::Unexpected, "parse content length").set_source(e)
+                })
+            }
+            StatusCode::NOT_FOUND => Ok(0),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn append(&self, offset: u64, size: u64, body: AsyncBody) -> Result<()> {
+        if offset == 0 {
+            return oio::RangeWrite::write_once(self, size, body).await;
+        }
+
+        let temp = format!("{}.opendal-append-{}", self.path, uuid::Uuid::new_v4());
+
+        let temp_writer = GcsWriter::new(self.core.clone(), &temp, OpWrite::default());
+        oio::RangeWrite::write_once(&temp_writer, size, body).await?;
+
+        let resp = self
+            .core
+            .gcs_compose_object(&[&self.path, &temp], &self.path, &self.op)
+            .await;
+
+        // Remove the temporary object no matter compose succeeds or not.
+        match self.core.gcs_delete_object(&temp).await {
+            Ok(resp) if resp.status().is_success() || resp.status() == StatusCode::NOT_FOUND => {
+                resp.into_body().consume().await?
+            }
+            Ok(resp) => log::warn!(
+                "gcs: remove temporary object {temp} failed: {}",
+                parse_error(resp).await?
+            ),
+            Err(err) => log::warn!("gcs: remove temporary object {temp} failed: {err}"),
+        }
+
+        let resp = resp?;
+        match resp.status() {
+            StatusCode::OK => {
+                resp.into_body().consume().await?;
+                Ok(())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+}
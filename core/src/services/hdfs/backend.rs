@@ -180,6 +180,7 @@ impl Accessor for HdfsBackend {
 
                 create_dir: true,
                 delete: true,
+                delete_with_recursive: true,
 
                 list: true,
                 list_with_delimiter_slash: true,
@@ -269,7 +270,7 @@ impl Accessor for HdfsBackend {
         Ok(RpStat::new(m))
     }
 
-    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         let p = build_rooted_abs_path(&self.root, path);
 
         let meta = self.client.metadata(&p);
@@ -285,7 +286,9 @@ impl Accessor for HdfsBackend {
         // Safety: Err branch has been checked, it's OK to unwrap.
         let meta = meta.ok().unwrap();
 
-        let result = if meta.is_dir() {
+        let result = if meta.is_dir() && args.recursive() {
+            self.client.remove_dir_all(&p)
+        } else if meta.is_dir() {
             self.client.remove_dir(&p)
         } else {
             self.client.remove_file(&p)
@@ -386,7 +389,7 @@ impl Accessor for HdfsBackend {
         Ok(RpStat::new(m))
     }
 
-    fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         let p = build_rooted_abs_path(&self.root, path);
 
         let meta = self.client.metadata(&p);
@@ -402,7 +405,9 @@ impl Accessor for HdfsBackend {
         // Safety: Err branch has been checked, it's OK to unwrap.
         let meta = meta.ok().unwrap();
 
-        let result = if meta.is_dir() {
+        let result = if meta.is_dir() && args.recursive() {
+            self.client.remove_dir_all(&p)
+        } else if meta.is_dir() {
             self.client.remove_dir(&p)
         } else {
             self.client.remove_file(&p)
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::btree_map::Entry;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fmt::Debug;
@@ -85,6 +86,7 @@ impl typed_kv::Adapter for Adapter {
             typed_kv::Capability {
                 get: true,
                 set: true,
+                set_if_absent: true,
                 delete: true,
                 scan: true,
             },
@@ -112,6 +114,20 @@ impl typed_kv::Adapter for Adapter {
         Ok(())
     }
 
+    async fn set_if_absent(&self, path: &str, value: typed_kv::Value) -> Result<bool> {
+        self.blocking_set_if_absent(path, value)
+    }
+
+    fn blocking_set_if_absent(&self, path: &str, value: typed_kv::Value) -> Result<bool> {
+        match self.inner.lock().entry(path.to_string()) {
+            Entry::Occupied(_) => Ok(false),
+            Entry::Vacant(e) => {
+                e.insert(value);
+                Ok(true)
+            }
+        }
+    }
+
     async fn delete(&self, path: &str) -> Result<()> {
         self.blocking_delete(path)
     }
@@ -148,6 +148,7 @@ impl typed_kv::Adapter for Adapter {
                 set: true,
                 delete: true,
                 scan: true,
+                set_if_absent: false,
             },
         )
     }
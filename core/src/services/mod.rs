@@ -19,6 +19,13 @@
 //!
 //! More ongoing services support is tracked at [opendal#5](https://github.com/apache/incubator-opendal/issues/5). Please feel free to submit issues if there are services not covered.
 
+#[cfg(feature = "services-archive")]
+mod archive;
+#[cfg(feature = "services-archive")]
+pub use archive::Archive;
+#[cfg(feature = "services-archive")]
+pub use archive::ArchiveWriter;
+
 #[cfg(feature = "services-azblob")]
 mod azblob;
 #[cfg(feature = "services-azblob")]
@@ -173,6 +180,11 @@ pub use sftp::Sftp;
 #[cfg(feature = "services-sftp")]
 pub use sftp::SftpConfig;
 
+#[cfg(feature = "services-sharding")]
+mod sharding;
+#[cfg(feature = "services-sharding")]
+pub use sharding::Sharding;
+
 #[cfg(feature = "services-sled")]
 mod sled;
 #[cfg(feature = "services-sled")]
@@ -218,6 +230,11 @@ mod redb;
 #[cfg(feature = "services-redb")]
 pub use self::redb::Redb;
 
+#[cfg(feature = "services-tiered")]
+mod tiered;
+#[cfg(feature = "services-tiered")]
+pub use tiered::Tiered;
+
 #[cfg(feature = "services-tikv")]
 mod tikv;
 #[cfg(feature = "services-tikv")]
@@ -276,6 +293,11 @@ mod dbfs;
 #[cfg(feature = "services-dbfs")]
 pub use self::dbfs::Dbfs;
 
+#[cfg(feature = "services-dedup")]
+mod dedup;
+#[cfg(feature = "services-dedup")]
+pub use self::dedup::Dedup;
+
 #[cfg(feature = "services-swift")]
 mod swift;
 #[cfg(feature = "services-swift")]
@@ -20,6 +20,7 @@ use std::fmt::Debug;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use bytes::Buf;
 use http::StatusCode;
 use http::Uri;
 use log::debug;
@@ -28,8 +29,10 @@ use reqsign::HuaweicloudObsCredentialLoader;
 use reqsign::HuaweicloudObsSigner;
 
 use super::core::ObsCore;
+use super::core::Tagging;
 use super::error::parse_error;
 use super::pager::ObsPager;
+use super::pager::ObsUploadLister;
 use super::writer::ObsWriter;
 use crate::raw::*;
 use crate::services::obs::writer::ObsWriters;
@@ -275,6 +278,7 @@ impl Accessor for ObsBackend {
                 write_can_empty: true,
                 write_can_append: true,
                 write_can_multi: true,
+                write_with_resume: true,
                 write_with_content_type: true,
                 write_with_cache_control: true,
                 // The min multipart size of OBS is 5 MiB.
@@ -302,6 +306,13 @@ impl Accessor for ObsBackend {
                 presign_stat: true,
                 presign_read: true,
                 presign_write: true,
+                presign_write_multipart: true,
+
+                get_tags: true,
+                set_tags: true,
+
+                list_uploads: true,
+                abort_upload: true,
 
                 ..Default::default()
             });
@@ -317,6 +328,14 @@ impl Accessor for ObsBackend {
                 self.core
                     .obs_put_object_request(path, None, v, AsyncBody::Empty)?
             }
+            // OBS requires part number must between [1..=10000]
+            PresignOperation::WritePart(v) => self.core.obs_upload_part_request(
+                path,
+                v.upload_id(),
+                v.part_number() + 1,
+                None,
+                AsyncBody::Empty,
+            )?,
         };
         self.core.sign_query(&mut req, args.expire()).await?;
 
@@ -330,6 +349,67 @@ impl Accessor for ObsBackend {
         )))
     }
 
+    async fn initiate_multipart(
+        &self,
+        path: &str,
+        _: OpInitiateMultipart,
+    ) -> Result<RpInitiateMultipart> {
+        let w = ObsWriter::new(self.core.clone(), path, OpWrite::default());
+        let upload_id = oio::MultipartUploadWrite::initiate_part(&w).await?;
+
+        Ok(RpInitiateMultipart::new(&upload_id))
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        // Part numbers of presigned parts start from 1.
+        let parts: Vec<_> = args
+            .etags()
+            .iter()
+            .enumerate()
+            .map(|(i, etag)| oio::MultipartUploadPart {
+                part_number: i + 1,
+                etag: etag.clone(),
+            })
+            .collect();
+
+        let w = ObsWriter::new(self.core.clone(), path, OpWrite::default());
+        oio::MultipartUploadWrite::complete_part(&w, args.upload_id(), &parts).await?;
+
+        Ok(RpCompleteMultipart::default())
+    }
+
+    async fn list_uploads(
+        &self,
+        path: &str,
+        _: OpListUploads,
+    ) -> Result<(RpListUploads, oio::UploadPager)> {
+        let l = ObsUploadLister::new(self.core.clone(), path);
+
+        Ok((
+            RpListUploads::default(),
+            Box::new(oio::MultipartUploadPager::new(l, &self.core.root)),
+        ))
+    }
+
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        let resp = self
+            .core
+            .obs_abort_multipart_upload(path, args.upload_id())
+            .await?;
+
+        match resp.status() {
+            StatusCode::NO_CONTENT => {
+                resp.into_body().consume().await?;
+                Ok(RpAbortUpload::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn create_dir(&self, path: &str, _: OpCreateDir) -> Result<RpCreateDir> {
         let mut req = self.core.obs_put_object_request(
             path,
@@ -374,7 +454,9 @@ impl Accessor for ObsBackend {
         let w = if args.append() {
             ObsWriters::Two(oio::AppendObjectWriter::new(writer))
         } else {
-            ObsWriters::One(oio::MultipartUploadWriter::new(writer))
+            ObsWriters::One(
+                oio::MultipartUploadWriter::new(writer).with_checkpoint(args.resume().cloned()),
+            )
         };
 
         Ok((RpWrite::default(), w))
@@ -427,6 +509,41 @@ impl Accessor for ObsBackend {
         }
     }
 
+    async fn get_tags(&self, path: &str, _: OpGetTags) -> Result<RpGetTags> {
+        let resp = self.core.obs_get_object_tagging(path).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+
+                let tagging: Tagging =
+                    quick_xml::de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)?;
+
+                Ok(RpGetTags::new(tagging.into()))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        let resp = self
+            .core
+            .obs_put_object_tagging(path, args.into_tags())
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                resp.into_body().consume().await?;
+                Ok(RpSetTags::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
         Ok((
             RpList::default(),
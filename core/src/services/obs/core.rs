@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::time::Duration;
@@ -316,6 +317,40 @@ impl ObsCore {
 
         self.send(req).await
     }
+    /// List on-going multipart uploads under given prefix.
+    pub async fn obs_list_multipart_uploads(
+        &self,
+        path: &str,
+        key_marker: &str,
+        upload_id_marker: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut queries = vec!["uploads".to_string()];
+        if !p.is_empty() {
+            queries.push(format!("prefix={}", percent_encode_path(&p)));
+        }
+        if !key_marker.is_empty() {
+            queries.push(format!("key-marker={}", percent_encode_path(key_marker)));
+        }
+        if !upload_id_marker.is_empty() {
+            queries.push(format!(
+                "upload-id-marker={}",
+                percent_encode_path(upload_id_marker)
+            ));
+        }
+
+        let url = format!("{}?{}", self.endpoint, queries.join("&"));
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
     pub async fn obs_initiate_multipart_upload(
         &self,
         path: &str,
@@ -337,14 +372,14 @@ impl ObsCore {
 
         self.send(req).await
     }
-    pub async fn obs_upload_part_request(
+    pub fn obs_upload_part_request(
         &self,
         path: &str,
         upload_id: &str,
         part_number: usize,
         size: Option<u64>,
         body: AsyncBody,
-    ) -> Result<Response<IncomingAsyncBody>> {
+    ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -362,7 +397,18 @@ impl ObsCore {
         }
 
         // Set body
-        let mut req = req.body(body).map_err(new_request_build_error)?;
+        req.body(body).map_err(new_request_build_error)
+    }
+
+    pub async fn obs_upload_part(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        size: Option<u64>,
+        body: AsyncBody,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.obs_upload_part_request(path, upload_id, part_number, size, body)?;
 
         self.sign(&mut req).await?;
 
@@ -424,6 +470,90 @@ impl ObsCore {
         self.sign(&mut req).await?;
         self.send(req).await
     }
+
+    pub async fn obs_get_object_tagging(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    pub async fn obs_put_object_tagging(
+        &self,
+        path: &str,
+        tags: HashMap<String, String>,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let content =
+            quick_xml::se::to_string(&Tagging::from(tags)).map_err(new_xml_deserialize_error)?;
+
+        let mut req = Request::put(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            // Set content-md5 as required by API.
+            .header("CONTENT-MD5", format_content_md5(content.as_bytes()))
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+}
+
+/// Request and result of object tagging.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename = "Tagging", rename_all = "PascalCase")]
+pub struct Tagging {
+    pub tag_set: TagSet,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct TagSet {
+    pub tag: Vec<Tag>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct Tag {
+    pub key: String,
+    pub value: String,
+}
+
+impl From<HashMap<String, String>> for Tagging {
+    fn from(tags: HashMap<String, String>) -> Self {
+        let mut tag: Vec<_> = tags
+            .into_iter()
+            .map(|(key, value)| Tag { key, value })
+            .collect();
+        tag.sort_by(|a, b| a.key.cmp(&b.key));
+
+        Tagging {
+            tag_set: TagSet { tag },
+        }
+    }
+}
+
+impl From<Tagging> for HashMap<String, String> {
+    fn from(tagging: Tagging) -> Self {
+        tagging
+            .tag_set
+            .tag
+            .into_iter()
+            .map(|t| (t.key, t.value))
+            .collect()
+    }
 }
 
 /// Result of CreateMultipartUpload
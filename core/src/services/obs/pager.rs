@@ -208,3 +208,39 @@ mod tests {
         )
     }
 }
+
+/// ObsUploadLister lists in-progress multipart uploads under given path.
+pub struct ObsUploadLister {
+    core: Arc<ObsCore>,
+    path: String,
+}
+
+impl ObsUploadLister {
+    pub fn new(core: Arc<ObsCore>, path: &str) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl oio::MultipartUploadList for ObsUploadLister {
+    async fn list_multipart_uploads(
+        &self,
+        key_marker: &str,
+        upload_id_marker: &str,
+    ) -> Result<oio::ListMultipartUploadsResult> {
+        let resp = self
+            .core
+            .obs_list_multipart_uploads(&self.path, key_marker, upload_id_marker)
+            .await?;
+
+        if resp.status() != http::StatusCode::OK {
+            return Err(parse_error(resp).await?);
+        }
+
+        let bs = resp.into_body().bytes().await?;
+        de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)
+    }
+}
@@ -33,6 +33,7 @@ use reqsign::AliyunOssSigner;
 use super::core::*;
 use super::error::parse_error;
 use super::pager::OssPager;
+use super::pager::OssUploadLister;
 use super::writer::OssWriter;
 use crate::raw::*;
 use crate::services::oss::writer::OssWriters;
@@ -403,9 +404,12 @@ impl Accessor for OssBackend {
                 write_can_empty: true,
                 write_can_append: true,
                 write_can_multi: true,
+                write_with_resume: true,
                 write_with_cache_control: true,
                 write_with_content_type: true,
                 write_with_content_disposition: true,
+                write_with_storage_class: true,
+                write_with_tags: true,
                 // The min multipart size of OSS is 100 KiB.
                 //
                 // ref: <https://www.alibabacloud.com/help/en/oss/user-guide/multipart-upload-12>
@@ -433,10 +437,21 @@ impl Accessor for OssBackend {
                 presign_stat: true,
                 presign_read: true,
                 presign_write: true,
+                presign_write_multipart: true,
+                presign_post: true,
 
                 batch: true,
+                batch_delete: true,
                 batch_max_operations: Some(self.core.batch_max_operations),
 
+                get_tags: true,
+                set_tags: true,
+
+                restore: true,
+
+                list_uploads: true,
+                abort_upload: true,
+
                 ..Default::default()
             });
 
@@ -489,7 +504,9 @@ impl Accessor for OssBackend {
         let w = if args.append() {
             OssWriters::Two(oio::AppendObjectWriter::new(writer))
         } else {
-            OssWriters::One(oio::MultipartUploadWriter::new(writer))
+            OssWriters::One(
+                oio::MultipartUploadWriter::new(writer).with_checkpoint(args.resume().cloned()),
+            )
         };
 
         Ok((RpWrite::default(), w))
@@ -522,7 +539,14 @@ impl Accessor for OssBackend {
         let status = resp.status();
 
         match status {
-            StatusCode::OK => parse_into_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::OK => {
+                let mut meta = parse_into_metadata(path, resp.headers())?;
+                if let Some(storage_class) = parse_storage_class(resp.headers())? {
+                    meta.set_storage_class(storage_class);
+                }
+
+                Ok(RpStat::new(meta))
+            }
             StatusCode::NOT_FOUND if path.ends_with('/') => {
                 let m = Metadata::new(EntryMode::DIR);
                 Ok(RpStat::new(m))
@@ -576,6 +600,15 @@ impl Accessor for OssBackend {
                 self.core
                     .oss_put_object_request(path, None, v, AsyncBody::Empty, true)?
             }
+            // OSS requires part number must between [1..=10000]
+            PresignOperation::WritePart(v) => self.core.oss_upload_part_request(
+                path,
+                v.upload_id(),
+                v.part_number() + 1,
+                true,
+                None,
+                AsyncBody::Empty,
+            )?,
         };
 
         self.core.sign_query(&mut req, args.expire()).await?;
@@ -590,6 +623,124 @@ impl Accessor for OssBackend {
         )))
     }
 
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        let post = self.core.oss_presign_post(path, &args).await?;
+
+        Ok(RpPresignPost::new(post))
+    }
+
+    async fn initiate_multipart(
+        &self,
+        path: &str,
+        _: OpInitiateMultipart,
+    ) -> Result<RpInitiateMultipart> {
+        let w = OssWriter::new(self.core.clone(), path, OpWrite::default());
+        let upload_id = oio::MultipartUploadWrite::initiate_part(&w).await?;
+
+        Ok(RpInitiateMultipart::new(&upload_id))
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        // Part numbers of presigned parts start from 1.
+        let parts: Vec<_> = args
+            .etags()
+            .iter()
+            .enumerate()
+            .map(|(i, etag)| oio::MultipartUploadPart {
+                part_number: i + 1,
+                etag: etag.clone(),
+            })
+            .collect();
+
+        let w = OssWriter::new(self.core.clone(), path, OpWrite::default());
+        oio::MultipartUploadWrite::complete_part(&w, args.upload_id(), &parts).await?;
+
+        Ok(RpCompleteMultipart::default())
+    }
+
+    async fn list_uploads(
+        &self,
+        path: &str,
+        _: OpListUploads,
+    ) -> Result<(RpListUploads, oio::UploadPager)> {
+        let l = OssUploadLister::new(self.core.clone(), path);
+
+        Ok((
+            RpListUploads::default(),
+            Box::new(oio::MultipartUploadPager::new(l, &self.core.root)),
+        ))
+    }
+
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        let resp = self
+            .core
+            .oss_abort_multipart_upload(path, args.upload_id())
+            .await?;
+
+        match resp.status() {
+            StatusCode::NO_CONTENT => {
+                resp.into_body().consume().await?;
+                Ok(RpAbortUpload::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn get_tags(&self, path: &str, _: OpGetTags) -> Result<RpGetTags> {
+        let resp = self.core.oss_get_object_tagging(path).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+
+                let tagging: Tagging =
+                    quick_xml::de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)?;
+
+                Ok(RpGetTags::new(tagging.into()))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        let resp = self
+            .core
+            .oss_put_object_tagging(path, args.into_tags())
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                resp.into_body().consume().await?;
+                Ok(RpSetTags::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        let resp = self.core.oss_restore_object(path, args.days()).await?;
+
+        let status = resp.status();
+
+        match status {
+            // - 202 means the restore has been initiated.
+            // - 200 means the object has been restored, and the expiry is updated.
+            StatusCode::ACCEPTED | StatusCode::OK => {
+                resp.into_body().consume().await?;
+                Ok(RpRestore::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
         let ops = args.into_operation();
         // Sadly, OSS will not return failed keys, so we will build
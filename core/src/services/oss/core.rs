@@ -15,7 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use bytes::Bytes;
+use chrono::Utc;
+use hmac::Hmac;
+use hmac::Mac;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
 use http::header::CONTENT_LENGTH;
@@ -23,6 +28,7 @@ use http::header::CONTENT_TYPE;
 use http::header::IF_MATCH;
 use http::header::IF_NONE_MATCH;
 use http::header::RANGE;
+use http::HeaderMap;
 use http::HeaderName;
 use http::HeaderValue;
 use http::Request;
@@ -32,6 +38,8 @@ use reqsign::AliyunLoader;
 use reqsign::AliyunOssSigner;
 use serde::Deserialize;
 use serde::Serialize;
+use sha1::Sha1;
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::fmt::Write;
@@ -45,6 +53,9 @@ mod constants {
 
     pub const X_OSS_SERVER_SIDE_ENCRYPTION_KEY_ID: &str = "x-oss-server-side-encryption-key-id";
 
+    pub const X_OSS_STORAGE_CLASS: &str = "x-oss-storage-class";
+    pub const X_OSS_TAGGING: &str = "x-oss-tagging";
+
     pub const RESPONSE_CONTENT_DISPOSITION: &str = "response-content-disposition";
 }
 
@@ -124,6 +135,55 @@ impl OssCore {
             .map_err(new_request_sign_error)
     }
 
+    /// Sign a POST policy for `PostObject`.
+    ///
+    /// # Reference
+    ///
+    /// https://help.aliyun.com/zh/oss/developer-reference/postobject
+    pub async fn oss_presign_post(
+        &self,
+        path: &str,
+        args: &OpPresignPost,
+    ) -> Result<PresignedPost> {
+        let cred = self.load_credential().await?.ok_or_else(|| {
+            Error::new(
+                ErrorKind::ConfigInvalid,
+                "presign post requires a valid credential",
+            )
+        })?;
+
+        let p = build_abs_path(&self.root, path);
+
+        let expiration = Utc::now() + chrono::Duration::seconds(args.expire().as_secs() as i64);
+        let mut policy = PostPolicy::new(&p, args.conditions(), expiration);
+        policy.push_field("bucket", &self.bucket);
+        let policy = policy.to_base64();
+
+        let mut mac = Hmac::<Sha1>::new_from_slice(cred.access_key_secret.as_bytes())
+            .expect("hmac accepts keys of any size");
+        mac.update(policy.as_bytes());
+        let signature = BASE64_STANDARD.encode(mac.finalize().into_bytes());
+
+        let mut fields = vec![("key".to_string(), format!("{p}${{filename}}"))];
+        if let Some(v) = args.conditions().content_type() {
+            fields.push(("Content-Type".to_string(), v.to_string()));
+        }
+        fields.push(("OSSAccessKeyId".to_string(), cred.access_key_id));
+        if let Some(token) = cred.security_token {
+            fields.push(("x-oss-security-token".to_string(), token));
+        }
+        fields.push(("policy".to_string(), policy));
+        fields.push(("Signature".to_string(), signature));
+
+        let uri = format!("{}/", self.presign_endpoint)
+            .parse()
+            .map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "building presigned post uri").set_source(err)
+            })?;
+
+        Ok(PresignedPost::new(uri, fields))
+    }
+
     #[inline]
     pub async fn send(&self, req: Request<AsyncBody>) -> Result<Response<IncomingAsyncBody>> {
         self.client.send(req).await
@@ -185,6 +245,20 @@ impl OssCore {
             req = req.header(CACHE_CONTROL, cache_control)
         }
 
+        if let Some(storage_class) = args.storage_class() {
+            req = req.header(
+                HeaderName::from_static(constants::X_OSS_STORAGE_CLASS),
+                storage_class,
+            );
+        }
+
+        if let Some(tags) = args.tags() {
+            req = req.header(
+                HeaderName::from_static(constants::X_OSS_TAGGING),
+                format_tagging(tags),
+            );
+        }
+
         // set sse headers
         req = self.insert_sse_headers(req);
 
@@ -225,6 +299,13 @@ impl OssCore {
             req = req.header(CACHE_CONTROL, cache_control)
         }
 
+        if let Some(storage_class) = args.storage_class() {
+            req = req.header(
+                HeaderName::from_static(constants::X_OSS_STORAGE_CLASS),
+                storage_class,
+            );
+        }
+
         // set sse headers
         req = self.insert_sse_headers(req);
 
@@ -497,6 +578,60 @@ impl OssCore {
         self.send(req).await
     }
 
+    pub async fn oss_get_object_tagging(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    pub async fn oss_put_object_tagging(
+        &self,
+        path: &str,
+        tags: HashMap<String, String>,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let content =
+            quick_xml::se::to_string(&Tagging::from(tags)).map_err(new_xml_deserialize_error)?;
+
+        let mut req = Request::put(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    pub async fn oss_restore_object(
+        &self,
+        path: &str,
+        days: u32,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!("{}/{}?restore", self.endpoint, percent_encode_path(&p));
+
+        let content = quick_xml::se::to_string(&RestoreRequest { days })
+            .map_err(new_xml_deserialize_error)?;
+
+        let mut req = Request::post(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     fn get_endpoint(&self, is_presign: bool) -> &str {
         if is_presign {
             &self.presign_endpoint
@@ -508,24 +643,34 @@ impl OssCore {
     pub async fn oss_initiate_upload(
         &self,
         path: &str,
-        content_type: Option<&str>,
-        content_disposition: Option<&str>,
-        cache_control: Option<&str>,
+        args: &OpWrite,
         is_presign: bool,
     ) -> Result<Response<IncomingAsyncBody>> {
         let path = build_abs_path(&self.root, path);
         let endpoint = self.get_endpoint(is_presign);
         let url = format!("{}/{}?uploads", endpoint, percent_encode_path(&path));
         let mut req = Request::post(&url);
-        if let Some(mime) = content_type {
+        if let Some(mime) = args.content_type() {
             req = req.header(CONTENT_TYPE, mime);
         }
-        if let Some(disposition) = content_disposition {
+        if let Some(disposition) = args.content_disposition() {
             req = req.header(CONTENT_DISPOSITION, disposition);
         }
-        if let Some(cache_control) = cache_control {
+        if let Some(cache_control) = args.cache_control() {
             req = req.header(CACHE_CONTROL, cache_control);
         }
+        if let Some(storage_class) = args.storage_class() {
+            req = req.header(
+                HeaderName::from_static(constants::X_OSS_STORAGE_CLASS),
+                storage_class,
+            );
+        }
+        if let Some(tags) = args.tags() {
+            req = req.header(
+                HeaderName::from_static(constants::X_OSS_TAGGING),
+                format_tagging(tags),
+            );
+        }
         req = self.insert_sse_headers(req);
         let mut req = req
             .body(AsyncBody::Empty)
@@ -535,15 +680,15 @@ impl OssCore {
     }
 
     /// Creates a request to upload a part
-    pub async fn oss_upload_part_request(
+    pub fn oss_upload_part_request(
         &self,
         path: &str,
         upload_id: &str,
         part_number: usize,
         is_presign: bool,
-        size: u64,
+        size: Option<u64>,
         body: AsyncBody,
-    ) -> Result<Response<IncomingAsyncBody>> {
+    ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
         let endpoint = self.get_endpoint(is_presign);
 
@@ -556,8 +701,22 @@ impl OssCore {
         );
 
         let mut req = Request::put(&url);
-        req = req.header(CONTENT_LENGTH, size);
-        let mut req = req.body(body).map_err(new_request_build_error)?;
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size);
+        }
+        req.body(body).map_err(new_request_build_error)
+    }
+
+    pub async fn oss_upload_part(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: AsyncBody,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req =
+            self.oss_upload_part_request(path, upload_id, part_number, false, Some(size), body)?;
         self.sign(&mut req).await?;
         self.send(req).await
     }
@@ -619,6 +778,42 @@ impl OssCore {
         self.sign(&mut req).await?;
         self.send(req).await
     }
+
+    /// List on-going multipart uploads under given prefix.
+    ///
+    /// reference docs https://www.alibabacloud.com/help/en/oss/developer-reference/listmultipartuploads
+    pub async fn oss_list_multipart_uploads(
+        &self,
+        path: &str,
+        key_marker: &str,
+        upload_id_marker: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = format!("{}/?uploads", self.endpoint);
+        if !p.is_empty() {
+            write!(url, "&prefix={}", percent_encode_path(&p))
+                .expect("write into string must succeed");
+        }
+        if !key_marker.is_empty() {
+            write!(url, "&key-marker={}", percent_encode_path(key_marker))
+                .expect("write into string must succeed");
+        }
+        if !upload_id_marker.is_empty() {
+            write!(
+                url,
+                "&upload-id-marker={}",
+                percent_encode_path(upload_id_marker)
+            )
+            .expect("write into string must succeed");
+        }
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
 }
 
 /// Request of DeleteObjects.
@@ -690,6 +885,63 @@ pub struct CompleteMultipartUploadResult {
     pub etag: String,
 }
 
+/// Request of RestoreObject.
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename = "RestoreRequest", rename_all = "PascalCase")]
+pub struct RestoreRequest {
+    pub days: u32,
+}
+
+/// Parse storage class from the response headers of `HeadObject`.
+pub fn parse_storage_class(headers: &HeaderMap) -> Result<Option<&str>> {
+    parse_header_to_str(headers, constants::X_OSS_STORAGE_CLASS)
+}
+
+/// Request and result of object tagging.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename = "Tagging", rename_all = "PascalCase")]
+pub struct Tagging {
+    pub tag_set: TagSet,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct TagSet {
+    pub tag: Vec<Tag>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct Tag {
+    pub key: String,
+    pub value: String,
+}
+
+impl From<HashMap<String, String>> for Tagging {
+    fn from(tags: HashMap<String, String>) -> Self {
+        let mut tag: Vec<_> = tags
+            .into_iter()
+            .map(|(key, value)| Tag { key, value })
+            .collect();
+        tag.sort_by(|a, b| a.key.cmp(&b.key));
+
+        Tagging {
+            tag_set: TagSet { tag },
+        }
+    }
+}
+
+impl From<Tagging> for HashMap<String, String> {
+    fn from(tagging: Tagging) -> Self {
+        tagging
+            .tag_set
+            .tag
+            .into_iter()
+            .map(|t| (t.key, t.value))
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use bytes::Buf;
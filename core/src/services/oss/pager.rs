@@ -123,6 +123,9 @@ impl oio::Page for OssPager {
             meta.set_content_md5(object.etag.trim_matches('"'));
             meta.set_content_length(object.size);
             meta.set_last_modified(parse_datetime_from_rfc3339(object.last_modified.as_str())?);
+            if let Some(storage_class) = &object.storage_class {
+                meta.set_storage_class(storage_class);
+            }
 
             let rel = build_rel_path(&self.core.root, &object.key);
             let path = unescape(&rel)
@@ -157,6 +160,7 @@ struct Content {
     #[serde(rename = "ETag")]
     etag: String,
     size: u64,
+    storage_class: Option<String>,
 }
 
 #[derive(Default, Debug, Deserialize)]
@@ -232,20 +236,59 @@ mod tests {
                     last_modified: "2020-05-18T05:45:54.000Z".to_string(),
                     etag: "\"35A27C2B9EAEEB6F48FD7FB5861D****\"".to_string(),
                     size: 25,
+                    storage_class: Some("STANDARD".to_string()),
                 },
                 Content {
                     key: "ba".to_string(),
                     last_modified: "2020-05-18T11:17:58.000Z".to_string(),
                     etag: "\"35A27C2B9EAEEB6F48FD7FB5861D****\"".to_string(),
                     size: 25,
+                    storage_class: Some("STANDARD".to_string()),
                 },
                 Content {
                     key: "bc".to_string(),
                     last_modified: "2020-05-18T05:45:59.000Z".to_string(),
                     etag: "\"35A27C2B9EAEEB6F48FD7FB5861D****\"".to_string(),
                     size: 25,
+                    storage_class: Some("STANDARD".to_string()),
                 }
             ]
         )
     }
 }
+
+/// OssUploadLister lists in-progress multipart uploads under given path.
+pub struct OssUploadLister {
+    core: Arc<OssCore>,
+    path: String,
+}
+
+impl OssUploadLister {
+    pub fn new(core: Arc<OssCore>, path: &str) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl oio::MultipartUploadList for OssUploadLister {
+    async fn list_multipart_uploads(
+        &self,
+        key_marker: &str,
+        upload_id_marker: &str,
+    ) -> Result<oio::ListMultipartUploadsResult> {
+        let resp = self
+            .core
+            .oss_list_multipart_uploads(&self.path, key_marker, upload_id_marker)
+            .await?;
+
+        if resp.status() != http::StatusCode::OK {
+            return Err(parse_error(resp).await?);
+        }
+
+        let bs = resp.into_body().bytes().await?;
+        de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)
+    }
+}
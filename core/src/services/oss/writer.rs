@@ -70,13 +70,7 @@ impl oio::MultipartUploadWrite for OssWriter {
     async fn initiate_part(&self) -> Result<String> {
         let resp = self
             .core
-            .oss_initiate_upload(
-                &self.path,
-                self.op.content_type(),
-                self.op.content_disposition(),
-                self.op.cache_control(),
-                false,
-            )
+            .oss_initiate_upload(&self.path, &self.op, false)
             .await?;
 
         let status = resp.status();
@@ -107,7 +101,7 @@ impl oio::MultipartUploadWrite for OssWriter {
 
         let resp = self
             .core
-            .oss_upload_part_request(&self.path, upload_id, part_number, false, size, body)
+            .oss_upload_part(&self.path, upload_id, part_number, size, body)
             .await?;
 
         let status = resp.status();
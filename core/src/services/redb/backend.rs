@@ -40,6 +40,7 @@ pub struct RedbBuilder {
     datadir: Option<String>,
     root: Option<String>,
     table: Option<String>,
+    enable_append_emulation: bool,
 }
 
 impl RedbBuilder {
@@ -60,6 +61,16 @@ impl RedbBuilder {
         self.root = Some(path.into());
         self
     }
+
+    /// Enable append emulation for this backend.
+    ///
+    /// Append will be emulated by reading the old value and writing back the
+    /// joined one, which is NOT atomic. Concurrent writers to the same path
+    /// could lose data.
+    pub fn enable_append_emulation(&mut self) -> &mut Self {
+        self.enable_append_emulation = true;
+        self
+    }
 }
 
 impl Builder for RedbBuilder {
@@ -72,6 +83,9 @@ impl Builder for RedbBuilder {
         map.get("datadir").map(|v| builder.datadir(v));
         map.get("table").map(|v| builder.table(v));
         map.get("root").map(|v| builder.root(v));
+        map.get("enable_append_emulation")
+            .filter(|v| *v == "on" || *v == "true")
+            .map(|_| builder.enable_append_emulation());
 
         builder
     }
@@ -96,7 +110,8 @@ impl Builder for RedbBuilder {
             table: table_name,
             db,
         })
-        .with_root(self.root.as_deref().unwrap_or_default()))
+        .with_root(self.root.as_deref().unwrap_or_default())
+        .with_append_emulation(self.enable_append_emulation))
     }
 }
 
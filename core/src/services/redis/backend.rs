@@ -22,6 +22,8 @@ use std::path::PathBuf;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use futures::stream::BoxStream;
+use futures::StreamExt;
 use http::Uri;
 use redis::aio::ConnectionManager;
 use redis::cluster::ClusterClient;
@@ -31,6 +33,7 @@ use redis::AsyncCommands;
 use redis::Client;
 use redis::ConnectionAddr;
 use redis::ConnectionInfo;
+use redis::Msg;
 use redis::RedisConnectionInfo;
 use redis::RedisError;
 use serde::Deserialize;
@@ -380,8 +383,12 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                write_can_append: true,
                 create_dir: true,
 
+                // Keyspace notifications are not supported in cluster mode.
+                watch: self.client.is_some(),
+
                 ..Default::default()
             },
         )
@@ -445,6 +452,79 @@ impl kv::Adapter for Adapter {
         }
         Ok(())
     }
+
+    async fn watch(&self, path: &str) -> Result<oio::Watcher> {
+        let Some(client) = self.client.as_ref() else {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "redis cluster doesn't support watch",
+            ));
+        };
+
+        let prefix = format!("__keyspace@{}__:", client.get_connection_info().redis.db);
+        let mut pubsub = client.get_async_connection().await?.into_pubsub();
+        pubsub
+            .psubscribe(format!("{prefix}{}*", escape_pattern(path)))
+            .await?;
+
+        Ok(Box::new(RedisWatcher {
+            prefix,
+            stream: pubsub.into_on_message().boxed(),
+        }))
+    }
+}
+
+/// Escape the special chars of redis glob-style pattern.
+fn escape_pattern(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// RedisWatcher receives changes via redis keyspace notifications.
+///
+/// Keyspace notifications must be enabled on server side via
+/// `notify-keyspace-events`, with at least `K`, `g`, `$` and `x` set. `n`
+/// is required to receive create events, otherwise new keys will be
+/// reported as modified.
+pub struct RedisWatcher {
+    /// The prefix of keyspace channel like `__keyspace@0__:`.
+    prefix: String,
+    stream: BoxStream<'static, Msg>,
+}
+
+/// # Safety
+///
+/// We will only take `&mut Self` reference for RedisWatcher.
+unsafe impl Sync for RedisWatcher {}
+
+#[async_trait]
+impl oio::Watch for RedisWatcher {
+    async fn next(&mut self) -> Result<Option<Vec<WatchEvent>>> {
+        loop {
+            let Some(msg) = self.stream.next().await else {
+                return Ok(None);
+            };
+
+            let Some(key) = msg.get_channel_name().strip_prefix(&self.prefix) else {
+                continue;
+            };
+            let op: String = msg.get_payload()?;
+            let kind = match op.as_str() {
+                "new" | "rename_to" | "copy_to" => WatchEventKind::Create,
+                "set" | "append" | "setrange" => WatchEventKind::Modify,
+                "del" | "expired" | "evicted" | "rename_from" => WatchEventKind::Delete,
+                _ => continue,
+            };
+
+            return Ok(Some(vec![WatchEvent::new(kind, key)]));
+        }
+    }
 }
 
 impl From<RedisError> for Error {
@@ -42,6 +42,7 @@ use super::core::*;
 use super::error::parse_error;
 use super::error::parse_s3_error_code;
 use super::pager::S3Pager;
+use super::pager::S3UploadLister;
 use super::writer::S3Writer;
 use super::writer::S3Writers;
 use crate::raw::*;
@@ -196,6 +197,13 @@ pub struct S3Config {
     ///
     /// Please tune this value based on services' document.
     pub batch_max_operations: Option<usize>,
+    /// Emulate append for this backend.
+    ///
+    /// S3 doesn't support append natively. Once enabled, opendal will
+    /// build the new object from the existing one and the appended data,
+    /// which is NOT atomic: concurrent writers to the same path could
+    /// overwrite each other.
+    pub enable_append_emulation: bool,
 }
 
 impl Debug for S3Config {
@@ -641,6 +649,24 @@ impl S3Builder {
         self
     }
 
+    /// Enable append emulation for this backend.
+    ///
+    /// S3 doesn't support append natively. With emulation enabled, appending
+    /// to an object is done by:
+    ///
+    /// - Objects smaller than 5 MiB: read the existing data and put it back
+    ///   with the new data.
+    /// - Others: build a new object by multipart upload, copying the existing
+    ///   object as the first part and uploading the new data as the second.
+    ///
+    /// The emulation is NOT atomic: concurrent writers to the same path could
+    /// overwrite each other. Please make sure there is only one writer.
+    pub fn enable_append_emulation(&mut self) -> &mut Self {
+        self.config.enable_append_emulation = true;
+
+        self
+    }
+
     /// Detect region of S3 bucket.
     ///
     /// # Args
@@ -939,6 +965,7 @@ impl Builder for S3Builder {
         Ok(S3Backend {
             core: Arc::new(S3Core {
                 bucket: bucket.to_string(),
+                region: region.clone(),
                 endpoint,
                 root,
                 server_side_encryption,
@@ -948,6 +975,7 @@ impl Builder for S3Builder {
                 server_side_encryption_customer_key_md5,
                 default_storage_class,
                 allow_anonymous: self.config.allow_anonymous,
+                enable_append_emulation: self.config.enable_append_emulation,
                 signer,
                 loader,
                 client,
@@ -986,6 +1014,7 @@ impl Accessor for S3Backend {
                 read_can_next: true,
                 read_with_range: true,
                 read_with_if_match: true,
+                read_with_version: true,
                 read_with_if_none_match: true,
                 read_with_override_cache_control: true,
                 read_with_override_content_disposition: true,
@@ -994,8 +1023,13 @@ impl Accessor for S3Backend {
                 write: true,
                 write_can_empty: true,
                 write_can_multi: true,
+                write_with_resume: true,
+                write_can_append: self.core.enable_append_emulation,
                 write_with_cache_control: true,
                 write_with_content_type: true,
+                write_with_storage_class: true,
+                write_with_tags: true,
+                write_with_if_not_exists: true,
                 // The min multipart size of S3 is 5 MiB.
                 //
                 // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
@@ -1023,10 +1057,21 @@ impl Accessor for S3Backend {
                 presign_stat: true,
                 presign_read: true,
                 presign_write: true,
+                presign_write_multipart: true,
+                presign_post: true,
 
                 batch: true,
+                batch_delete: true,
                 batch_max_operations: Some(self.core.batch_max_operations),
 
+                get_tags: true,
+                set_tags: true,
+
+                restore: true,
+
+                list_uploads: true,
+                abort_upload: true,
+
                 ..Default::default()
             });
 
@@ -1072,9 +1117,15 @@ impl Accessor for S3Backend {
     }
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
-        let writer = S3Writer::new(self.core.clone(), path, args);
+        let writer = S3Writer::new(self.core.clone(), path, args.clone());
 
-        let w = oio::MultipartUploadWriter::new(writer);
+        let w = if args.append() {
+            S3Writers::Two(oio::AppendObjectWriter::new(writer))
+        } else {
+            S3Writers::One(
+                oio::MultipartUploadWriter::new(writer).with_checkpoint(args.resume().cloned()),
+            )
+        };
 
         Ok((RpWrite::default(), w))
     }
@@ -1102,15 +1153,20 @@ impl Accessor for S3Backend {
             return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
         }
 
-        let resp = self
-            .core
-            .s3_head_object(path, args.if_none_match(), args.if_match())
-            .await?;
+        let resp = self.core.s3_head_object(path, &args).await?;
 
         let status = resp.status();
 
         match status {
-            StatusCode::OK => parse_into_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::OK => {
+                let mut meta = parse_into_metadata(path, resp.headers())?;
+                meta.set_storage_class(parse_storage_class(resp.headers())?);
+                if let Some(v) = parse_version_id(resp.headers())? {
+                    meta.set_version(v);
+                }
+
+                Ok(RpStat::new(meta))
+            }
             StatusCode::NOT_FOUND if path.ends_with('/') => {
                 Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
             }
@@ -1149,10 +1205,7 @@ impl Accessor for S3Backend {
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         // We will not send this request out, just for signing.
         let mut req = match args.operation() {
-            PresignOperation::Stat(v) => {
-                self.core
-                    .s3_head_object_request(path, v.if_none_match(), v.if_match())?
-            }
+            PresignOperation::Stat(v) => self.core.s3_head_object_request(path, v)?,
             PresignOperation::Read(v) => self.core.s3_get_object_request(path, v.clone())?,
             PresignOperation::Write(_) => self.core.s3_put_object_request(
                 path,
@@ -1160,6 +1213,14 @@ impl Accessor for S3Backend {
                 &OpWrite::default(),
                 AsyncBody::Empty,
             )?,
+            // AWS S3 requires part number must between [1..=10000]
+            PresignOperation::WritePart(v) => self.core.s3_upload_part_request(
+                path,
+                v.upload_id(),
+                v.part_number() + 1,
+                None,
+                AsyncBody::Empty,
+            )?,
         };
 
         self.core.sign_query(&mut req, args.expire()).await?;
@@ -1174,6 +1235,124 @@ impl Accessor for S3Backend {
         )))
     }
 
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        let post = self.core.s3_presign_post(path, &args).await?;
+
+        Ok(RpPresignPost::new(post))
+    }
+
+    async fn initiate_multipart(
+        &self,
+        path: &str,
+        _: OpInitiateMultipart,
+    ) -> Result<RpInitiateMultipart> {
+        let w = S3Writer::new(self.core.clone(), path, OpWrite::default());
+        let upload_id = oio::MultipartUploadWrite::initiate_part(&w).await?;
+
+        Ok(RpInitiateMultipart::new(&upload_id))
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        // Part numbers of presigned parts start from 1.
+        let parts: Vec<_> = args
+            .etags()
+            .iter()
+            .enumerate()
+            .map(|(i, etag)| oio::MultipartUploadPart {
+                part_number: i + 1,
+                etag: etag.clone(),
+            })
+            .collect();
+
+        let w = S3Writer::new(self.core.clone(), path, OpWrite::default());
+        oio::MultipartUploadWrite::complete_part(&w, args.upload_id(), &parts).await?;
+
+        Ok(RpCompleteMultipart::default())
+    }
+
+    async fn get_tags(&self, path: &str, _: OpGetTags) -> Result<RpGetTags> {
+        let resp = self.core.s3_get_object_tagging(path).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+
+                let tagging: Tagging =
+                    quick_xml::de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)?;
+
+                Ok(RpGetTags::new(tagging.into()))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        let resp = self
+            .core
+            .s3_put_object_tagging(path, args.into_tags())
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                resp.into_body().consume().await?;
+                Ok(RpSetTags::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        let resp = self.core.s3_restore_object(path, args.days()).await?;
+
+        let status = resp.status();
+
+        match status {
+            // - 202 means the restore has been initiated.
+            // - 200 means the object has been restored, and the expiry is updated.
+            StatusCode::ACCEPTED | StatusCode::OK => {
+                resp.into_body().consume().await?;
+                Ok(RpRestore::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn list_uploads(
+        &self,
+        path: &str,
+        _: OpListUploads,
+    ) -> Result<(RpListUploads, oio::UploadPager)> {
+        let l = S3UploadLister::new(self.core.clone(), path);
+
+        Ok((
+            RpListUploads::default(),
+            Box::new(oio::MultipartUploadPager::new(l, &self.core.root)),
+        ))
+    }
+
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        let resp = self
+            .core
+            .s3_abort_multipart_upload(path, args.upload_id())
+            .await?;
+
+        match resp.status() {
+            StatusCode::NO_CONTENT => {
+                resp.into_body().consume().await?;
+                Ok(RpAbortUpload::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
         let ops = args.into_operation();
         if ops.len() > 1000 {
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
 use std::fmt::Formatter;
@@ -22,6 +23,9 @@ use std::fmt::Write;
 use std::time::Duration;
 
 use bytes::Bytes;
+use chrono::Utc;
+use hmac::Hmac;
+use hmac::Mac;
 use http::header::HeaderName;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
@@ -30,6 +34,7 @@ use http::header::CONTENT_TYPE;
 use http::header::HOST;
 use http::header::IF_MATCH;
 use http::header::IF_NONE_MATCH;
+use http::HeaderMap;
 use http::HeaderValue;
 use http::Request;
 use http::Response;
@@ -38,12 +43,23 @@ use reqsign::AwsCredentialLoad;
 use reqsign::AwsV4Signer;
 use serde::Deserialize;
 use serde::Serialize;
+use sha2::Sha256;
 
 use crate::raw::*;
 use crate::*;
 
+fn hmac_sha256(key: &[u8], content: &[u8]) -> Vec<u8> {
+    Hmac::<Sha256>::new_from_slice(key)
+        .expect("hmac accepts keys of any size")
+        .chain_update(content)
+        .finalize()
+        .into_bytes()
+        .to_vec()
+}
+
 mod constants {
     pub const X_AMZ_COPY_SOURCE: &str = "x-amz-copy-source";
+    pub const X_AMZ_COPY_SOURCE_RANGE: &str = "x-amz-copy-source-range";
 
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION: &str = "x-amz-server-side-encryption";
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM: &str =
@@ -55,6 +71,8 @@ mod constants {
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID: &str =
         "x-amz-server-side-encryption-aws-kms-key-id";
     pub const X_AMZ_STORAGE_CLASS: &str = "x-amz-storage-class";
+    pub const X_AMZ_TAGGING: &str = "x-amz-tagging";
+    pub const X_AMZ_VERSION_ID: &str = "x-amz-version-id";
 
     pub const X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM: &str =
         "x-amz-copy-source-server-side-encryption-customer-algorithm";
@@ -66,10 +84,13 @@ mod constants {
     pub const RESPONSE_CONTENT_DISPOSITION: &str = "response-content-disposition";
     pub const RESPONSE_CONTENT_TYPE: &str = "response-content-type";
     pub const RESPONSE_CACHE_CONTROL: &str = "response-cache-control";
+
+    pub const QUERY_VERSION_ID: &str = "versionId";
 }
 
 pub struct S3Core {
     pub bucket: String,
+    pub region: String,
     pub endpoint: String,
     pub root: String,
     pub server_side_encryption: Option<HeaderValue>,
@@ -79,6 +100,7 @@ pub struct S3Core {
     pub server_side_encryption_customer_key_md5: Option<HeaderValue>,
     pub default_storage_class: Option<HeaderValue>,
     pub allow_anonymous: bool,
+    pub enable_append_emulation: bool,
 
     pub signer: AwsV4Signer,
     pub loader: Box<dyn AwsCredentialLoad>,
@@ -164,6 +186,73 @@ impl S3Core {
         Ok(())
     }
 
+    /// Sign a POST policy with AWS Signature Version 4.
+    ///
+    /// # Reference
+    ///
+    /// https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-HTTPPOSTConstructPolicy.html
+    pub async fn s3_presign_post(&self, path: &str, args: &OpPresignPost) -> Result<PresignedPost> {
+        let cred = self.load_credential().await?.ok_or_else(|| {
+            Error::new(
+                ErrorKind::ConfigInvalid,
+                "presign post requires a valid credential",
+            )
+        })?;
+
+        let p = build_abs_path(&self.root, path);
+        let now = Utc::now();
+        let date = now.format("%Y%m%d").to_string();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let credential = format!(
+            "{}/{date}/{}/s3/aws4_request",
+            cred.access_key_id, self.region
+        );
+
+        let expiration = now + chrono::Duration::seconds(args.expire().as_secs() as i64);
+        let mut policy = PostPolicy::new(&p, args.conditions(), expiration);
+        policy.push_field("bucket", &self.bucket);
+
+        let mut fields = vec![("key".to_string(), format!("{p}${{filename}}"))];
+        if let Some(v) = args.conditions().content_type() {
+            fields.push(("Content-Type".to_string(), v.to_string()));
+        }
+
+        let mut auth_fields = vec![
+            ("x-amz-algorithm", "AWS4-HMAC-SHA256".to_string()),
+            ("x-amz-credential", credential),
+            ("x-amz-date", amz_date),
+        ];
+        if let Some(token) = &cred.session_token {
+            auth_fields.push(("x-amz-security-token", token.clone()));
+        }
+        for (k, v) in auth_fields {
+            policy.push_field(k, &v);
+            fields.push((k.to_string(), v));
+        }
+
+        let policy = policy.to_base64();
+        let signing_key = {
+            let key = format!("AWS4{}", cred.secret_access_key);
+            let key = hmac_sha256(key.as_bytes(), date.as_bytes());
+            let key = hmac_sha256(&key, self.region.as_bytes());
+            let key = hmac_sha256(&key, b"s3");
+            hmac_sha256(&key, b"aws4_request")
+        };
+        let signature = hmac_sha256(&signing_key, policy.as_bytes())
+            .iter()
+            .map(|v| format!("{v:02x}"))
+            .collect();
+
+        fields.push(("policy".to_string(), policy));
+        fields.push(("x-amz-signature".to_string(), signature));
+
+        let uri = format!("{}/", self.endpoint).parse().map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "building presigned post uri").set_source(err)
+        })?;
+
+        Ok(PresignedPost::new(uri, fields))
+    }
+
     #[inline]
     pub async fn send(&self, req: Request<AsyncBody>) -> Result<Response<IncomingAsyncBody>> {
         self.client.send(req).await
@@ -229,28 +318,77 @@ impl S3Core {
 
         req
     }
+
+    /// Insert the SSE-C headers of copy source.
+    ///
+    /// The source object is always encrypted with the same customer key as
+    /// the destination, since they are in the same backend.
+    pub fn insert_copy_source_sse_headers(
+        &self,
+        mut req: http::request::Builder,
+    ) -> http::request::Builder {
+        if let Some(v) = &self.server_side_encryption_customer_algorithm {
+            let mut v = v.clone();
+            v.set_sensitive(true);
+
+            req = req.header(
+                HeaderName::from_static(
+                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM,
+                ),
+                v,
+            )
+        }
+
+        if let Some(v) = &self.server_side_encryption_customer_key {
+            let mut v = v.clone();
+            v.set_sensitive(true);
+
+            req = req.header(
+                HeaderName::from_static(
+                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY,
+                ),
+                v,
+            )
+        }
+
+        if let Some(v) = &self.server_side_encryption_customer_key_md5 {
+            let mut v = v.clone();
+            v.set_sensitive(true);
+
+            req = req.header(
+                HeaderName::from_static(
+                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5,
+                ),
+                v,
+            )
+        }
+
+        req
+    }
 }
 
 impl S3Core {
-    pub fn s3_head_object_request(
-        &self,
-        path: &str,
-        if_none_match: Option<&str>,
-        if_match: Option<&str>,
-    ) -> Result<Request<AsyncBody>> {
+    pub fn s3_head_object_request(&self, path: &str, args: &OpStat) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+        let mut url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+        if let Some(version) = args.version() {
+            url.push_str(&format!(
+                "?{}={}",
+                constants::QUERY_VERSION_ID,
+                percent_encode_path(version)
+            ));
+        }
 
         let mut req = Request::head(&url);
 
         req = self.insert_sse_headers(req, false);
 
-        if let Some(if_none_match) = if_none_match {
+        if let Some(if_none_match) = args.if_none_match() {
             req = req.header(IF_NONE_MATCH, if_none_match);
         }
 
-        if let Some(if_match) = if_match {
+        if let Some(if_match) = args.if_match() {
             req = req.header(IF_MATCH, if_match);
         }
 
@@ -290,6 +428,13 @@ impl S3Core {
                 percent_encode_path(override_cache_control)
             ))
         }
+        if let Some(version) = args.version() {
+            query_args.push(format!(
+                "{}={}",
+                constants::QUERY_VERSION_ID,
+                percent_encode_path(version)
+            ))
+        }
         if !query_args.is_empty() {
             url.push_str(&format!("?{}", query_args.join("&")));
         }
@@ -361,10 +506,23 @@ impl S3Core {
         }
 
         // Set storage class header
-        if let Some(v) = &self.default_storage_class {
+        if let Some(v) = args.storage_class() {
+            req = req.header(HeaderName::from_static(constants::X_AMZ_STORAGE_CLASS), v);
+        } else if let Some(v) = &self.default_storage_class {
             req = req.header(HeaderName::from_static(constants::X_AMZ_STORAGE_CLASS), v);
         }
 
+        if let Some(tags) = args.tags() {
+            req = req.header(
+                HeaderName::from_static(constants::X_AMZ_TAGGING),
+                format_tagging(tags),
+            );
+        }
+
+        if args.if_not_exists() {
+            req = req.header(IF_NONE_MATCH, "*");
+        }
+
         // Set SSE headers.
         req = self.insert_sse_headers(req, true);
 
@@ -377,10 +535,9 @@ impl S3Core {
     pub async fn s3_head_object(
         &self,
         path: &str,
-        if_none_match: Option<&str>,
-        if_match: Option<&str>,
+        args: &OpStat,
     ) -> Result<Response<IncomingAsyncBody>> {
-        let mut req = self.s3_head_object_request(path, if_none_match, if_match)?;
+        let mut req = self.s3_head_object_request(path, args)?;
 
         self.sign(&mut req).await?;
 
@@ -417,41 +574,8 @@ impl S3Core {
         // Set SSE headers.
         req = self.insert_sse_headers(req, true);
 
-        if let Some(v) = &self.server_side_encryption_customer_algorithm {
-            let mut v = v.clone();
-            v.set_sensitive(true);
-
-            req = req.header(
-                HeaderName::from_static(
-                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM,
-                ),
-                v,
-            )
-        }
-
-        if let Some(v) = &self.server_side_encryption_customer_key {
-            let mut v = v.clone();
-            v.set_sensitive(true);
-
-            req = req.header(
-                HeaderName::from_static(
-                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY,
-                ),
-                v,
-            )
-        }
-
-        if let Some(v) = &self.server_side_encryption_customer_key_md5 {
-            let mut v = v.clone();
-            v.set_sensitive(true);
-
-            req = req.header(
-                HeaderName::from_static(
-                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5,
-                ),
-                v,
-            )
-        }
+        // Set SSE headers of copy source.
+        req = self.insert_copy_source_sse_headers(req);
 
         let mut req = req
             .header(constants::X_AMZ_COPY_SOURCE, &source)
@@ -535,10 +659,19 @@ impl S3Core {
         }
 
         // Set storage class header
-        if let Some(v) = &self.default_storage_class {
+        if let Some(v) = args.storage_class() {
+            req = req.header(HeaderName::from_static(constants::X_AMZ_STORAGE_CLASS), v);
+        } else if let Some(v) = &self.default_storage_class {
             req = req.header(HeaderName::from_static(constants::X_AMZ_STORAGE_CLASS), v);
         }
 
+        if let Some(tags) = args.tags() {
+            req = req.header(
+                HeaderName::from_static(constants::X_AMZ_TAGGING),
+                format_tagging(tags),
+            );
+        }
+
         // Set SSE headers.
         let req = self.insert_sse_headers(req, true);
 
@@ -556,7 +689,7 @@ impl S3Core {
         path: &str,
         upload_id: &str,
         part_number: usize,
-        size: u64,
+        size: Option<u64>,
         body: AsyncBody,
     ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
@@ -571,7 +704,9 @@ impl S3Core {
 
         let mut req = Request::put(&url);
 
-        req = req.header(CONTENT_LENGTH, size);
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size);
+        }
 
         // Set SSE headers.
         req = self.insert_sse_headers(req, true);
@@ -582,11 +717,48 @@ impl S3Core {
         Ok(req)
     }
 
+    /// Upload a part by copying `range` of the object at `path` itself.
+    pub async fn s3_upload_part_copy(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        range: BytesRange,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let source = format!("{}/{}", self.bucket, percent_encode_path(&p));
+        let url = format!(
+            "{}/{}?partNumber={}&uploadId={}",
+            self.endpoint,
+            percent_encode_path(&p),
+            part_number,
+            percent_encode_path(upload_id)
+        );
+
+        let mut req = Request::put(&url);
+
+        // Set SSE headers.
+        req = self.insert_sse_headers(req, true);
+        req = self.insert_copy_source_sse_headers(req);
+
+        let mut req = req
+            .header(constants::X_AMZ_COPY_SOURCE, &source)
+            .header(constants::X_AMZ_COPY_SOURCE_RANGE, range.to_header())
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
     pub async fn s3_complete_multipart_upload(
         &self,
         path: &str,
         upload_id: &str,
         parts: Vec<CompleteMultipartUploadRequestPart>,
+        args: &OpWrite,
     ) -> Result<Response<IncomingAsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
@@ -597,7 +769,11 @@ impl S3Core {
             percent_encode_path(upload_id)
         );
 
-        let req = Request::post(&url);
+        let mut req = Request::post(&url);
+
+        if args.if_not_exists() {
+            req = req.header(IF_NONE_MATCH, "*");
+        }
 
         // Set SSE headers.
         let req = self.insert_sse_headers(req, true);
@@ -640,6 +816,109 @@ impl S3Core {
         self.send(req).await
     }
 
+    /// List on-going multipart uploads under given prefix.
+    pub async fn s3_list_multipart_uploads(
+        &self,
+        path: &str,
+        key_marker: &str,
+        upload_id_marker: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = format!("{}?uploads", self.endpoint);
+        if !p.is_empty() {
+            write!(url, "&prefix={}", percent_encode_path(&p))
+                .expect("write into string must succeed");
+        }
+        if !key_marker.is_empty() {
+            write!(url, "&key-marker={}", percent_encode_path(key_marker))
+                .expect("write into string must succeed");
+        }
+        if !upload_id_marker.is_empty() {
+            write!(
+                url,
+                "&upload-id-marker={}",
+                percent_encode_path(upload_id_marker)
+            )
+            .expect("write into string must succeed");
+        }
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    pub async fn s3_get_object_tagging(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    pub async fn s3_put_object_tagging(
+        &self,
+        path: &str,
+        tags: HashMap<String, String>,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let req = Request::put(&url);
+
+        let content =
+            quick_xml::se::to_string(&Tagging::from(tags)).map_err(new_xml_deserialize_error)?;
+
+        let req = req.header(CONTENT_LENGTH, content.len());
+        let req = req.header(CONTENT_TYPE, "application/xml");
+        // Set content-md5 as required by API.
+        let req = req.header("CONTENT-MD5", format_content_md5(content.as_bytes()));
+
+        let mut req = req
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    pub async fn s3_restore_object(
+        &self,
+        path: &str,
+        days: u32,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?restore", self.endpoint, percent_encode_path(&p));
+
+        let content = quick_xml::se::to_string(&RestoreRequest { days })
+            .map_err(new_xml_deserialize_error)?;
+
+        let mut req = Request::post(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            // Set content-md5 as required by API.
+            .header("CONTENT-MD5", format_content_md5(content.as_bytes()))
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
     pub async fn s3_delete_objects(
         &self,
         paths: Vec<String>,
@@ -675,6 +954,17 @@ impl S3Core {
     }
 }
 
+/// Parse storage class from the response headers of `HeadObject`.
+///
+/// S3 doesn't return storage class for `STANDARD` objects.
+pub fn parse_storage_class(headers: &HeaderMap) -> Result<&str> {
+    Ok(parse_header_to_str(headers, constants::X_AMZ_STORAGE_CLASS)?.unwrap_or("STANDARD"))
+}
+
+pub fn parse_version_id(headers: &HeaderMap) -> Result<Option<&str>> {
+    parse_header_to_str(headers, constants::X_AMZ_VERSION_ID)
+}
+
 /// Result of CreateMultipartUpload
 #[derive(Default, Debug, Deserialize)]
 #[serde(default, rename_all = "PascalCase")]
@@ -682,6 +972,14 @@ pub struct InitiateMultipartUploadResult {
     pub upload_id: String,
 }
 
+/// Result of UploadPartCopy
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CopyPartResult {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+}
+
 /// Request of CompleteMultipartUploadRequest
 #[derive(Default, Debug, Serialize)]
 #[serde(default, rename = "CompleteMultipartUpload", rename_all = "PascalCase")]
@@ -761,6 +1059,58 @@ pub struct DeleteObjectsResultError {
     pub message: String,
 }
 
+/// Request of RestoreObject.
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename = "RestoreRequest", rename_all = "PascalCase")]
+pub struct RestoreRequest {
+    pub days: u32,
+}
+
+/// Request and result of object tagging.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename = "Tagging", rename_all = "PascalCase")]
+pub struct Tagging {
+    pub tag_set: TagSet,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct TagSet {
+    pub tag: Vec<Tag>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct Tag {
+    pub key: String,
+    pub value: String,
+}
+
+impl From<HashMap<String, String>> for Tagging {
+    fn from(tags: HashMap<String, String>) -> Self {
+        let mut tag: Vec<_> = tags
+            .into_iter()
+            .map(|(key, value)| Tag { key, value })
+            .collect();
+        tag.sort_by(|a, b| a.key.cmp(&b.key));
+
+        Tagging {
+            tag_set: TagSet { tag },
+        }
+    }
+}
+
+impl From<Tagging> for HashMap<String, String> {
+    fn from(tagging: Tagging) -> Self {
+        tagging
+            .tag_set
+            .tag
+            .into_iter()
+            .map(|t| (t.key, t.value))
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use bytes::Buf;
@@ -789,6 +1139,22 @@ mod tests {
         )
     }
 
+    /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_UploadPartCopy.html#API_UploadPartCopy_Examples
+    #[test]
+    fn test_deserialize_copy_part_result() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <CopyPartResult>
+              <LastModified>2009-10-28T22:32:00.000Z</LastModified>
+              <ETag>"9b2cf535f27731c974343645a3985328"</ETag>
+            </CopyPartResult>"#,
+        );
+
+        let out: CopyPartResult = quick_xml::de::from_reader(bs.reader()).expect("must success");
+
+        assert_eq!(out.etag, "\"9b2cf535f27731c974343645a3985328\"")
+    }
+
     /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_CompleteMultipartUpload.html#API_CompleteMultipartUpload_Examples
     #[test]
     fn test_serialize_complete_multipart_upload_request() {
@@ -892,4 +1258,46 @@ mod tests {
         assert_eq!(out.error[0].code, "AccessDenied");
         assert_eq!(out.error[0].message, "Access Denied");
     }
+
+    /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetObjectTagging.html#API_GetObjectTagging_Examples
+    #[test]
+    fn test_deserialize_tagging() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <Tagging xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+              <TagSet>
+                 <Tag>
+                   <Key>tag1</Key>
+                   <Value>val1</Value>
+                 </Tag>
+                 <Tag>
+                   <Key>tag2</Key>
+                   <Value>val2</Value>
+                 </Tag>
+              </TagSet>
+            </Tagging>"#,
+        );
+
+        let out: Tagging = quick_xml::de::from_reader(bs.reader()).expect("must success");
+        let tags: HashMap<String, String> = out.into();
+
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags["tag1"], "val1");
+        assert_eq!(tags["tag2"], "val2");
+    }
+
+    #[test]
+    fn test_serialize_tagging() {
+        let tags = HashMap::from([
+            ("tag2".to_string(), "val2".to_string()),
+            ("tag1".to_string(), "val1".to_string()),
+        ]);
+
+        let out = quick_xml::se::to_string(&Tagging::from(tags)).expect("must success");
+
+        assert_eq!(
+            out,
+            "<Tagging><TagSet><Tag><Key>tag1</Key><Value>val1</Value></Tag><Tag><Key>tag2</Key><Value>val2</Value></Tag></TagSet></Tagging>"
+        );
+    }
 }
@@ -136,6 +136,10 @@ impl oio::Page for S3Pager {
             // nanosecond, let's trim them.
             meta.set_last_modified(parse_datetime_from_rfc3339(object.last_modified.as_str())?);
 
+            if let Some(storage_class) = &object.storage_class {
+                meta.set_storage_class(storage_class);
+            }
+
             let de = oio::Entry::new(&build_rel_path(&self.core.root, &object.key), meta);
 
             entries.push(de);
@@ -171,6 +175,7 @@ struct OutputContent {
     last_modified: String,
     #[serde(rename = "ETag")]
     etag: Option<String>,
+    storage_class: Option<String>,
 }
 
 #[derive(Default, Debug, Eq, PartialEq, Deserialize)]
@@ -241,20 +246,59 @@ mod tests {
                     size: 56,
                     etag: Some("\"d41d8cd98f00b204e9800998ecf8427e\"".to_string()),
                     last_modified: "2016-04-30T23:51:29.000Z".to_string(),
+                    storage_class: Some("STANDARD".to_string()),
                 },
                 OutputContent {
                     key: "photos/2007".to_string(),
                     size: 100,
                     last_modified: "2016-04-30T23:51:29.000Z".to_string(),
                     etag: Some("\"d41d8cd98f00b204e9800998ecf8427e\"".to_string()),
+                    storage_class: Some("STANDARD".to_string()),
                 },
                 OutputContent {
                     key: "photos/2008".to_string(),
                     size: 42,
                     last_modified: "2016-05-30T23:51:29.000Z".to_string(),
                     etag: None,
+                    storage_class: None,
                 },
             ]
         )
     }
 }
+
+/// S3UploadLister lists in-progress multipart uploads under given path.
+pub struct S3UploadLister {
+    core: Arc<S3Core>,
+    path: String,
+}
+
+impl S3UploadLister {
+    pub fn new(core: Arc<S3Core>, path: &str) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl oio::MultipartUploadList for S3UploadLister {
+    async fn list_multipart_uploads(
+        &self,
+        key_marker: &str,
+        upload_id_marker: &str,
+    ) -> Result<oio::ListMultipartUploadsResult> {
+        let resp = self
+            .core
+            .s3_list_multipart_uploads(&self.path, key_marker, upload_id_marker)
+            .await?;
+
+        if resp.status() != http::StatusCode::OK {
+            return Err(parse_error(resp).await?);
+        }
+
+        let bs = resp.into_body().bytes().await?;
+        de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)
+    }
+}
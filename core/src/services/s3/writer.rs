@@ -18,6 +18,8 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use bytes::Bytes;
+use bytes::BytesMut;
 use http::StatusCode;
 
 use super::core::*;
@@ -25,7 +27,14 @@ use super::error::parse_error;
 use crate::raw::*;
 use crate::*;
 
-pub type S3Writers = oio::MultipartUploadWriter<S3Writer>;
+pub type S3Writers =
+    oio::TwoWaysWriter<oio::MultipartUploadWriter<S3Writer>, oio::AppendObjectWriter<S3Writer>>;
+
+/// The min size of a part that is not the last part.
+///
+/// Objects smaller than it can't be copied as the first part of a multipart
+/// upload.
+const MIN_PART_SIZE: u64 = 5 * 1024 * 1024;
 
 pub struct S3Writer {
     core: Arc<S3Core>,
@@ -98,9 +107,13 @@ impl oio::MultipartUploadWrite for S3Writer {
         // AWS S3 requires part number must between [1..=10000]
         let part_number = part_number + 1;
 
-        let mut req =
-            self.core
-                .s3_upload_part_request(&self.path, upload_id, part_number, size, body)?;
+        let mut req = self.core.s3_upload_part_request(
+            &self.path,
+            upload_id,
+            part_number,
+            Some(size),
+            body,
+        )?;
 
         self.core.sign(&mut req).await?;
 
@@ -142,7 +155,7 @@ impl oio::MultipartUploadWrite for S3Writer {
 
         let resp = self
             .core
-            .s3_complete_multipart_upload(&self.path, upload_id, parts)
+            .s3_complete_multipart_upload(&self.path, upload_id, parts, &self.op)
             .await?;
 
         let status = resp.status();
@@ -172,3 +185,123 @@ impl oio::MultipartUploadWrite for S3Writer {
         }
     }
 }
+
+/// Append is emulated since S3 doesn't support it natively.
+#[async_trait]
+impl oio::AppendObjectWrite for S3Writer {
+    async fn offset(&self) -> Result<u64> {
+        let resp = self
+            .core
+            .s3_head_object(&self.path, &OpStat::default())
+            .await?;
+
+        let status = resp.status();
+        match status {
+            StatusCode::OK => {
+                let content_length = parse_content_length(resp.headers())?.ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::Unexpected,
+                        "Content-Length not present in returning response",
+                    )
+                })?;
+                Ok(content_length)
+            }
+            StatusCode::NOT_FOUND => Ok(0),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn append(&self, offset: u64, size: u64, body: AsyncBody) -> Result<()> {
+        if offset == 0 {
+            return oio::MultipartUploadWrite::write_once(self, size, body).await;
+        }
+
+        if offset < MIN_PART_SIZE {
+            return self.append_by_rewrite(offset, size, body).await;
+        }
+
+        let upload_id = oio::MultipartUploadWrite::initiate_part(self).await?;
+
+        let res = self.append_by_copy(&upload_id, offset, size, body).await;
+        if res.is_err() {
+            // Try our best to abort the upload, the error of append is
+            // more important here.
+            let _ = oio::MultipartUploadWrite::abort_part(self, &upload_id).await;
+        }
+        res
+    }
+}
+
+impl S3Writer {
+    /// Read the existing data and put it back with the new data.
+    async fn append_by_rewrite(&self, offset: u64, size: u64, body: AsyncBody) -> Result<()> {
+        let args = OpRead::new().with_range(BytesRange::new(Some(0), Some(offset)));
+        let resp = self.core.s3_get_object(&self.path, args).await?;
+
+        let status = resp.status();
+        let existing = match status {
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => resp.into_body().bytes().await?,
+            _ => return Err(parse_error(resp).await?),
+        };
+
+        let mut bs = BytesMut::with_capacity((offset + size) as usize);
+        bs.extend_from_slice(&existing);
+        bs.extend_from_slice(&body_into_bytes(body)?);
+
+        oio::MultipartUploadWrite::write_once(self, bs.len() as u64, AsyncBody::Bytes(bs.freeze()))
+            .await
+    }
+
+    /// Copy the existing object as the first part and upload the new data as
+    /// the second part.
+    async fn append_by_copy(
+        &self,
+        upload_id: &str,
+        offset: u64,
+        size: u64,
+        body: AsyncBody,
+    ) -> Result<()> {
+        let resp = self
+            .core
+            .s3_upload_part_copy(
+                &self.path,
+                upload_id,
+                1,
+                BytesRange::new(Some(0), Some(offset)),
+            )
+            .await?;
+
+        let status = resp.status();
+        let copied = match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+
+                let result: CopyPartResult = quick_xml::de::from_reader(bytes::Buf::reader(bs))
+                    .map_err(new_xml_deserialize_error)?;
+
+                oio::MultipartUploadPart {
+                    part_number: 1,
+                    etag: result.etag,
+                }
+            }
+            _ => return Err(parse_error(resp).await?),
+        };
+
+        // `write_part` takes the index of part which starts from 0.
+        let appended =
+            oio::MultipartUploadWrite::write_part(self, upload_id, 1, size, body).await?;
+
+        oio::MultipartUploadWrite::complete_part(self, upload_id, &[copied, appended]).await
+    }
+}
+
+fn body_into_bytes(body: AsyncBody) -> Result<Bytes> {
+    match body {
+        AsyncBody::Empty => Ok(Bytes::new()),
+        AsyncBody::Bytes(bs) => Ok(bs),
+        _ => Err(Error::new(
+            ErrorKind::Unexpected,
+            "append emulation of s3 requires the body to be bytes",
+        )),
+    }
+}
@@ -18,6 +18,7 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::io::SeekFrom;
 use std::path::Path;
 use std::path::PathBuf;
 use std::pin::Pin;
@@ -30,6 +31,7 @@ use openssh::SessionBuilder;
 use openssh_sftp_client::file::TokioCompatFile;
 use openssh_sftp_client::Sftp;
 use openssh_sftp_client::SftpOptions;
+use tokio::io::AsyncSeekExt;
 
 use super::error::is_not_found;
 use super::error::is_sftp_protocol_error;
@@ -262,6 +264,7 @@ impl Accessor for SftpBackend {
 
                 write: true,
                 write_can_multi: true,
+                write_with_offset: true,
 
                 create_dir: true,
                 delete: true,
@@ -341,7 +344,13 @@ impl Accessor for SftpBackend {
             option.write(true);
         }
 
-        let file = option.open(path).await?;
+        let mut file = option.open(path).await?;
+
+        if let Some(offset) = op.offset() {
+            file.seek(SeekFrom::Start(offset)).await.map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "seek sftp file").set_source(err)
+            })?;
+        }
 
         Ok((RpWrite::new(), SftpWriter::new(file)))
     }
@@ -0,0 +1,518 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use async_trait::async_trait;
+use bytes::Buf;
+use bytes::Bytes;
+use futures::future::try_join_all;
+use md5::Digest;
+
+use crate::raw::oio::ReadExt;
+use crate::raw::oio::WriteExt;
+use crate::raw::*;
+use crate::*;
+
+/// The default number of virtual nodes of every shard on the hash ring.
+const DEFAULT_VIRTUAL_NODES: usize = 160;
+
+/// Sharding service support, distributes paths across several operators.
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct ShardingBuilder {
+    shards: Vec<Operator>,
+    virtual_nodes: Option<usize>,
+    rebalancing: bool,
+}
+
+impl Debug for ShardingBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShardingBuilder")
+            .field("shards", &self.shards.len())
+            .field("virtual_nodes", &self.virtual_nodes)
+            .field("rebalancing", &self.rebalancing)
+            .finish()
+    }
+}
+
+impl ShardingBuilder {
+    /// Add a shard.
+    ///
+    /// Shards are identified by their scheme, name and root, so the same
+    /// service must not be added twice.
+    pub fn shard(&mut self, op: Operator) -> &mut Self {
+        self.shards.push(op);
+        self
+    }
+
+    /// Set the number of virtual nodes of every shard on the hash ring.
+    ///
+    /// More virtual nodes make the paths more evenly distributed.
+    ///
+    /// Default to `160`.
+    pub fn virtual_nodes(&mut self, n: usize) -> &mut Self {
+        if n > 0 {
+            self.virtual_nodes = Some(n);
+        }
+        self
+    }
+
+    /// Enable rebalancing.
+    ///
+    /// If enabled, `stat` and `read` will fall back to other shards if the
+    /// path is not found on its owner, and `delete` will delete the path
+    /// from all shards.
+    pub fn rebalancing(&mut self, enabled: bool) -> &mut Self {
+        self.rebalancing = enabled;
+        self
+    }
+}
+
+impl Builder for ShardingBuilder {
+    const SCHEME: Scheme = Scheme::Sharding;
+    type Accessor = ShardingBackend;
+
+    fn from_map(map: HashMap<String, String>) -> Self {
+        let mut builder = Self::default();
+
+        map.get("virtual_nodes")
+            .and_then(|v| v.parse::<usize>().ok())
+            .map(|v| builder.virtual_nodes(v));
+        map.get("rebalancing")
+            .map(|v| builder.rebalancing(v == "true" || v == "on"));
+
+        builder
+    }
+
+    fn build(&mut self) -> Result<Self::Accessor> {
+        if self.shards.is_empty() {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "shards is empty")
+                .with_operation("Builder::build")
+                .with_context("service", Scheme::Sharding));
+        }
+
+        let shards: Vec<FusedAccessor> = self.shards.drain(..).map(|op| op.into_inner()).collect();
+
+        let mut ids = Vec::with_capacity(shards.len());
+        for shard in shards.iter() {
+            let id = shard_id(&shard.info());
+            if ids.contains(&id) {
+                return Err(
+                    Error::new(ErrorKind::ConfigInvalid, "shard has been added twice")
+                        .with_operation("Builder::build")
+                        .with_context("service", Scheme::Sharding)
+                        .with_context("shard", id),
+                );
+            }
+            ids.push(id);
+        }
+
+        let ring = HashRing::new(&shards, self.virtual_nodes.unwrap_or(DEFAULT_VIRTUAL_NODES));
+
+        Ok(ShardingBackend {
+            name: ids.join(","),
+            shards,
+            ring,
+            rebalancing: self.rebalancing,
+        })
+    }
+}
+
+/// Backend for sharding services.
+#[derive(Debug, Clone)]
+pub struct ShardingBackend {
+    name: String,
+    shards: Vec<FusedAccessor>,
+    ring: HashRing,
+    rebalancing: bool,
+}
+
+#[async_trait]
+impl Accessor for ShardingBackend {
+    type Reader = oio::Reader;
+    type BlockingReader = ();
+    type Writer = oio::Writer;
+    type BlockingWriter = ();
+    type Pager = oio::MergePager<oio::Pager>;
+    type BlockingPager = ();
+
+    fn info(&self) -> AccessorInfo {
+        let caps: Vec<Capability> = self
+            .shards
+            .iter()
+            .map(|v| v.info().full_capability())
+            .collect();
+        let all = |f: fn(&Capability) -> bool| caps.iter().all(f);
+
+        let mut cap = Capability::default();
+        cap.stat = all(|c| c.stat);
+        cap.read = all(|c| c.read);
+        cap.read_can_seek = all(|c| c.read_can_seek);
+        cap.read_can_next = all(|c| c.read_can_next);
+        cap.read_with_range = all(|c| c.read_with_range);
+        cap.write = all(|c| c.write);
+        cap.write_can_multi = all(|c| c.write_can_multi);
+        cap.write_can_empty = all(|c| c.write_can_empty);
+        cap.create_dir = all(|c| c.create_dir);
+        cap.delete = all(|c| c.delete);
+        cap.list = all(|c| c.list);
+        // All shards have been completed, so we can list with any delimiter.
+        cap.list_with_delimiter_slash = cap.list;
+        cap.list_without_delimiter = cap.list;
+        cap.list_with_limit = cap.list;
+        cap.copy = cap.read && cap.write;
+        cap.rename = cap.read && cap.write && cap.delete;
+
+        let mut am = AccessorInfo::default();
+        am.set_scheme(Scheme::Sharding)
+            .set_root("/")
+            .set_name(&self.name)
+            .set_native_capability(cap);
+
+        am
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        try_join_all(
+            self.shards
+                .iter()
+                .map(|shard| shard.create_dir(path, args.clone())),
+        )
+        .await?;
+
+        Ok(RpCreateDir::default())
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let candidates = self.candidates(path);
+        if candidates.len() == 1 {
+            return self.shards[candidates[0]].read(path, args).await;
+        }
+
+        // Readers could be lazy, so we need to stat first to find out
+        // which shard stores this file.
+        let mut last_err = None;
+        for idx in candidates {
+            match self.shards[idx].stat(path, OpStat::new()).await {
+                Ok(_) => return self.shards[idx].read(path, args).await,
+                Err(err) if err.kind() == ErrorKind::NotFound => last_err = Some(err),
+                Err(err) => return Err(err),
+            }
+        }
+
+        Err(last_err.expect("candidates must not be empty"))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.owner(path).write(path, args).await
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let idx = self.ring.locate(from);
+        if idx == self.ring.locate(to) && self.shards[idx].info().full_capability().copy {
+            match self.shards[idx].copy(from, to, args).await {
+                Err(err) if err.kind() == ErrorKind::NotFound && self.rebalancing => {}
+                v => return v,
+            }
+        }
+
+        self.copy_across(from, to).await?;
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let idx = self.ring.locate(from);
+        if idx == self.ring.locate(to) && self.shards[idx].info().full_capability().rename {
+            match self.shards[idx].rename(from, to, args).await {
+                Err(err) if err.kind() == ErrorKind::NotFound && self.rebalancing => {}
+                v => return v,
+            }
+        }
+
+        self.copy_across(from, to).await?;
+        self.delete(from, OpDelete::default()).await?;
+        Ok(RpRename::default())
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let mut last_err = None;
+        for idx in self.candidates(path) {
+            match self.shards[idx].stat(path, args.clone()).await {
+                Err(err) if err.kind() == ErrorKind::NotFound => last_err = Some(err),
+                v => return v,
+            }
+        }
+
+        Err(last_err.expect("candidates must not be empty"))
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        try_join_all(
+            self.candidates(path)
+                .into_iter()
+                .map(|idx| self.shards[idx].delete(path, args.clone())),
+        )
+        .await?;
+
+        Ok(RpDelete::default())
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        let pagers = try_join_all(
+            self.shards
+                .iter()
+                .map(|shard| shard.list(path, args.clone())),
+        )
+        .await?
+        .into_iter()
+        .map(|(_, p)| p)
+        .collect();
+
+        Ok((RpList::default(), oio::into_merge_page(pagers)))
+    }
+}
+
+impl ShardingBackend {
+    fn owner(&self, path: &str) -> &FusedAccessor {
+        &self.shards[self.ring.locate(path)]
+    }
+
+    /// Returns the shards that could store this path, the owner comes first.
+    ///
+    /// Dirs are created on all shards, so all shards are candidates of dirs.
+    fn candidates(&self, path: &str) -> Vec<usize> {
+        if self.rebalancing || path.ends_with('/') {
+            self.ring.walk(path)
+        } else {
+            vec![self.ring.locate(path)]
+        }
+    }
+
+    /// Copy a file between shards by streaming its content from the shard
+    /// that stores `from` to the owner of `to`.
+    ///
+    /// The content will be buffered in memory and written at once if the
+    /// owner of `to` doesn't support multiple writes.
+    async fn copy_across(&self, from: &str, to: &str) -> Result<()> {
+        let owner = self.owner(to);
+        let (_, mut r) = self.read(from, OpRead::new()).await?;
+        let (_, mut w) = owner.write(to, OpWrite::new()).await?;
+
+        let res = async {
+            if owner.info().full_capability().write_can_multi {
+                while let Some(bs) = r.next().await {
+                    let mut bs = bs?;
+                    while bs.has_remaining() {
+                        let n = w.write(&bs).await?;
+                        bs.advance(n);
+                    }
+                }
+            } else {
+                let mut buf = Vec::new();
+                r.read_to_end(&mut buf).await?;
+                let mut bs = Bytes::from(buf);
+                while bs.has_remaining() {
+                    let n = w.write(&bs).await?;
+                    bs.advance(n);
+                }
+            }
+            w.close().await
+        }
+        .await;
+
+        if let Err(err) = res {
+            // Abort is best effort, the error of copy is more important.
+            let _ = w.abort().await;
+            return Err(err);
+        }
+
+        Ok(())
+    }
+}
+
+/// Build the identity of shard which is stable across restarts.
+fn shard_id(info: &AccessorInfo) -> String {
+    format!("{}://{}{}", info.scheme(), info.name(), info.root())
+}
+
+/// Consistent hash ring which maps hash values to the index of shards.
+#[derive(Debug, Clone)]
+struct HashRing {
+    nodes: BTreeMap<u64, usize>,
+    shards: usize,
+}
+
+impl HashRing {
+    fn new(shards: &[FusedAccessor], virtual_nodes: usize) -> Self {
+        let mut nodes = BTreeMap::new();
+        for (idx, shard) in shards.iter().enumerate() {
+            let id = shard_id(&shard.info());
+            for vn in 0..virtual_nodes {
+                nodes.insert(hash(&format!("{id}#{vn}")), idx);
+            }
+        }
+
+        Self {
+            nodes,
+            shards: shards.len(),
+        }
+    }
+
+    /// Find the owner of this path.
+    fn locate(&self, path: &str) -> usize {
+        let h = hash(path);
+
+        *self
+            .nodes
+            .range(h..)
+            .chain(self.nodes.range(..h))
+            .next()
+            .expect("hash ring must not be empty")
+            .1
+    }
+
+    /// Walk the ring from the owner of this path and returns all distinct shards.
+    fn walk(&self, path: &str) -> Vec<usize> {
+        let h = hash(path);
+
+        let mut visited = Vec::with_capacity(self.shards);
+        for (_, idx) in self.nodes.range(h..).chain(self.nodes.range(..h)) {
+            if !visited.contains(idx) {
+                visited.push(*idx);
+            }
+            if visited.len() == self.shards {
+                break;
+            }
+        }
+        visited
+    }
+}
+
+fn hash(v: &str) -> u64 {
+    let mut hasher = md5::Md5::new();
+    hasher.update(v.as_bytes());
+    let digest = hasher.finalize();
+
+    let mut bs = [0; 8];
+    bs.copy_from_slice(&digest[..8]);
+    u64::from_be_bytes(bs)
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::services::Memory;
+
+    fn new_shard() -> Operator {
+        Operator::new(Memory::default()).unwrap().finish()
+    }
+
+    #[test]
+    fn test_build_without_shards() {
+        let err = ShardingBuilder::default().build().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+
+    #[test]
+    fn test_hash_ring_is_stable() {
+        let shards: Vec<_> = (0..3).map(|_| new_shard().into_inner()).collect();
+        let ring = HashRing::new(&shards, DEFAULT_VIRTUAL_NODES);
+
+        // Remove the last shard, paths owned by others should not move.
+        let smaller = HashRing::new(&shards[..2], DEFAULT_VIRTUAL_NODES);
+        for i in 0..1000 {
+            let path = format!("path/to/file-{i}");
+            let idx = ring.locate(&path);
+            if idx != 2 {
+                assert_eq!(idx, smaller.locate(&path), "path {path} moved");
+            }
+            assert_eq!(ring.walk(&path).len(), 3);
+            assert_eq!(ring.walk(&path)[0], idx);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_with_rebalancing() -> Result<()> {
+        let shards: Vec<_> = (0..3).map(|_| new_shard()).collect();
+
+        // Write all files before adding the last shard.
+        let mut builder = ShardingBuilder::default();
+        builder.shard(shards[0].clone()).shard(shards[1].clone());
+        let op = Operator::new(builder)?.finish();
+        for i in 0..20 {
+            op.write(&format!("file-{i}"), "hello").await?;
+        }
+
+        let mut builder = ShardingBuilder::default();
+        for shard in shards.iter() {
+            builder.shard(shard.clone());
+        }
+        builder.rebalancing(true);
+        let op = Operator::new(builder)?.finish();
+        for i in 0..20 {
+            assert_eq!(op.read(&format!("file-{i}")).await?, b"hello");
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_merges_all_shards() -> Result<()> {
+        let shards: Vec<_> = (0..3).map(|_| new_shard()).collect();
+        let mut builder = ShardingBuilder::default();
+        for shard in shards.iter() {
+            builder.shard(shard.clone());
+        }
+        let op = Operator::new(builder)?.finish();
+
+        let mut expected = vec![];
+        for i in 0..20 {
+            let path = format!("dir/file-{i:02}");
+            op.write(&path, "hello").await?;
+            expected.push(path);
+        }
+        op.create_dir("dir/sub/").await?;
+        expected.push("dir/sub/".to_string());
+        expected.sort();
+
+        // Files should be distributed across shards.
+        for shard in shards.iter() {
+            let entries: Vec<_> = shard.list_with("dir/").await?;
+            assert!(entries.len() < 21);
+        }
+
+        let entries: Vec<String> = op
+            .lister("dir/")
+            .await?
+            .map_ok(|e| e.path().to_string())
+            .try_collect()
+            .await?;
+        assert_eq!(entries, expected);
+
+        op.rename("dir/file-00", "dir/renamed").await?;
+        assert_eq!(op.read("dir/renamed").await?, b"hello");
+        assert!(!op.is_exist("dir/file-00").await?);
+
+        Ok(())
+    }
+}
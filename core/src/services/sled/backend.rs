@@ -42,6 +42,7 @@ pub struct SledBuilder {
     datadir: Option<String>,
     root: Option<String>,
     tree: Option<String>,
+    enable_append_emulation: bool,
 }
 
 impl SledBuilder {
@@ -57,6 +58,16 @@ impl SledBuilder {
         self
     }
 
+    /// Enable append emulation for this backend.
+    ///
+    /// Append will be emulated by reading the old value and writing back the
+    /// joined one, which is NOT atomic. Concurrent writers to the same path
+    /// could lose data.
+    pub fn enable_append_emulation(&mut self) -> &mut Self {
+        self.enable_append_emulation = true;
+        self
+    }
+
     /// Set the tree for sled.
     pub fn tree(&mut self, tree: &str) -> &mut Self {
         self.tree = Some(tree.into());
@@ -74,6 +85,9 @@ impl Builder for SledBuilder {
         map.get("datadir").map(|v| builder.datadir(v));
         map.get("root").map(|v| builder.root(v));
         map.get("tree").map(|v| builder.tree(v));
+        map.get("enable_append_emulation")
+            .filter(|v| *v == "on" || *v == "true")
+            .map(|_| builder.enable_append_emulation());
 
         builder
     }
@@ -109,7 +123,8 @@ impl Builder for SledBuilder {
             datadir: datadir_path,
             tree,
         })
-        .with_root(self.root.as_deref().unwrap_or_default()))
+        .with_root(self.root.as_deref().unwrap_or_default())
+        .with_append_emulation(self.enable_append_emulation))
     }
 }
 
@@ -40,6 +40,7 @@ pub struct SwiftBuilder {
     container: Option<String>,
     root: Option<String>,
     token: Option<String>,
+    temp_url_key: Option<String>,
 }
 
 impl Debug for SwiftBuilder {
@@ -54,6 +55,9 @@ impl Debug for SwiftBuilder {
         if self.token.is_some() {
             ds.field("token", &"<redacted>");
         }
+        if self.temp_url_key.is_some() {
+            ds.field("temp_url_key", &"<redacted>");
+        }
 
         ds.finish()
     }
@@ -122,6 +126,17 @@ impl SwiftBuilder {
         }
         self
     }
+
+    /// Set the TempURL key of this backend.
+    ///
+    /// It's the `X-Account-Meta-Temp-URL-Key` (or container key) of the
+    /// account, presign will be available only if this key is set.
+    pub fn temp_url_key(&mut self, key: &str) -> &mut Self {
+        if !key.is_empty() {
+            self.temp_url_key = Some(key.to_string());
+        }
+        self
+    }
 }
 
 impl Builder for SwiftBuilder {
@@ -135,6 +150,7 @@ impl Builder for SwiftBuilder {
         map.get("account").map(|v| builder.account(v));
         map.get("container").map(|v| builder.container(v));
         map.get("token").map(|v| builder.token(v));
+        map.get("temp_url_key").map(|v| builder.temp_url_key(v));
 
         builder
     }
@@ -200,6 +216,7 @@ impl Builder for SwiftBuilder {
                 account,
                 container,
                 token,
+                temp_url_key: self.temp_url_key.take(),
                 client,
             }),
         })
@@ -239,6 +256,11 @@ impl Accessor for SwiftBackend {
                 list: true,
                 list_with_delimiter_slash: true,
 
+                presign: self.core.temp_url_key.is_some(),
+                presign_stat: self.core.temp_url_key.is_some(),
+                presign_read: self.core.temp_url_key.is_some(),
+                presign_write: self.core.temp_url_key.is_some(),
+
                 ..Default::default()
             });
         am
@@ -337,4 +359,30 @@ impl Accessor for SwiftBackend {
 
         Ok((RpList::default(), op))
     }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        let mut req = match args.operation() {
+            PresignOperation::Stat(_) => self.core.swift_get_metadata_request(path)?,
+            PresignOperation::Read(v) => self.core.swift_read_request(path, v)?,
+            PresignOperation::Write(_) => self
+                .core
+                .swift_create_object_request(path, AsyncBody::Empty)?,
+            PresignOperation::WritePart(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "swift doesn't support presign multipart upload",
+                ))
+            }
+        };
+
+        self.core.sign_query(&mut req, args.expire())?;
+
+        let (parts, _) = req.into_parts();
+
+        Ok(RpPresign::new(PresignedRequest::new(
+            parts.method,
+            parts.uri,
+            parts.headers,
+        )))
+    }
 }
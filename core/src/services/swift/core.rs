@@ -16,9 +16,17 @@
 // under the License.
 
 use std::fmt::Debug;
+use std::str::FromStr;
+use std::time::Duration;
 
+use chrono::Utc;
+use hmac::Hmac;
+use hmac::Mac;
+use http::HeaderValue;
 use http::Request;
 use http::Response;
+use http::Uri;
+use sha2::Sha256;
 
 use crate::raw::*;
 use crate::*;
@@ -29,6 +37,7 @@ pub struct SwiftCore {
     pub account: String,
     pub container: String,
     pub token: String,
+    pub temp_url_key: Option<String>,
     pub client: HttpClient,
 }
 
@@ -44,6 +53,54 @@ impl Debug for SwiftCore {
 }
 
 impl SwiftCore {
+    pub fn sign<T>(&self, req: &mut Request<T>) -> Result<()> {
+        let token = HeaderValue::from_str(&self.token).map_err(|err| {
+            Error::new(
+                ErrorKind::ConfigInvalid,
+                "token is not a valid header value",
+            )
+            .set_source(err)
+        })?;
+        req.headers_mut().insert("X-Auth-Token", token);
+
+        Ok(())
+    }
+
+    /// Sign the request as a TempURL which is valid for `expire`.
+    ///
+    /// # Reference
+    ///
+    /// https://docs.openstack.org/swift/latest/api/temporary_url_middleware.html
+    pub fn sign_query<T>(&self, req: &mut Request<T>, expire: Duration) -> Result<()> {
+        let key = self.temp_url_key.as_deref().ok_or_else(|| {
+            Error::new(
+                ErrorKind::ConfigInvalid,
+                "temp_url_key is required to presign request",
+            )
+        })?;
+
+        let expires = Utc::now().timestamp() + expire.as_secs() as i64;
+        // TempURL middleware signs the decoded path.
+        let path = percent_decode_path(req.uri().path());
+        let content = format!("{}\n{expires}\n{path}", req.method());
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(key.as_bytes()).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "creating hmac from temp url key").set_source(err)
+        })?;
+        mac.update(content.as_bytes());
+        let sig = format!("{:x}", mac.finalize().into_bytes());
+
+        let uri = format!(
+            "{}?temp_url_sig={sig}&temp_url_expires={expires}",
+            req.uri()
+        );
+        *req.uri_mut() = Uri::from_str(&uri).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "building presigned uri").set_source(err)
+        })?;
+
+        Ok(())
+    }
+
     pub async fn swift_delete(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
@@ -55,13 +112,11 @@ impl SwiftCore {
             percent_encode_path(&p)
         );
 
-        let mut req = Request::delete(&url);
-
-        req = req.header("X-Auth-Token", &self.token);
-
-        let body = AsyncBody::Empty;
+        let mut req = Request::delete(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
 
-        let req = req.body(body).map_err(new_request_build_error)?;
+        self.sign(&mut req)?;
 
         self.client.send(req).await
     }
@@ -84,22 +139,20 @@ impl SwiftCore {
             delimiter
         );
 
-        let mut req = Request::get(&url);
-
-        req = req.header("X-Auth-Token", &self.token);
-
-        let req = req
+        let mut req = Request::get(&url)
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        self.sign(&mut req)?;
+
         self.client.send(req).await
     }
 
-    pub async fn swift_create_object(
+    pub fn swift_create_object_request(
         &self,
         path: &str,
         body: AsyncBody,
-    ) -> Result<Response<IncomingAsyncBody>> {
+    ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -112,18 +165,26 @@ impl SwiftCore {
 
         let mut req = Request::put(&url);
 
-        req = req.header("X-Auth-Token", &self.token);
-
         if p.ends_with('/') {
             req = req.header("Content-Length", "0");
         }
 
-        let req = req.body(body).map_err(new_request_build_error)?;
+        req.body(body).map_err(new_request_build_error)
+    }
+
+    pub async fn swift_create_object(
+        &self,
+        path: &str,
+        body: AsyncBody,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.swift_create_object_request(path, body)?;
+
+        self.sign(&mut req)?;
 
         self.client.send(req).await
     }
 
-    pub async fn swift_read(&self, path: &str, arg: OpRead) -> Result<Response<IncomingAsyncBody>> {
+    pub fn swift_read_request(&self, path: &str, arg: &OpRead) -> Result<Request<AsyncBody>> {
         let range = arg.range();
 
         let p = build_abs_path(&self.root, path)
@@ -140,15 +201,17 @@ impl SwiftCore {
 
         let mut req = Request::get(&url);
 
-        req = req.header("X-Auth-Token", &self.token);
-
         if !range.is_full() {
             req = req.header("Range", &range.to_header());
         }
 
-        let req = req
-            .body(AsyncBody::Empty)
-            .map_err(new_request_build_error)?;
+        req.body(AsyncBody::Empty).map_err(new_request_build_error)
+    }
+
+    pub async fn swift_read(&self, path: &str, arg: OpRead) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.swift_read_request(path, &arg)?;
+
+        self.sign(&mut req)?;
 
         self.client.send(req).await
     }
@@ -182,7 +245,6 @@ impl SwiftCore {
         // Reference: https://docs.openstack.org/api-ref/object-store/#copy-object
         let mut req = Request::put(&url);
 
-        req = req.header("X-Auth-Token", &self.token);
         req = req.header("X-Copy-From", percent_encode_path(&src_p));
 
         // if use PUT method, we need to set the content-length to 0.
@@ -190,12 +252,14 @@ impl SwiftCore {
 
         let body = AsyncBody::Empty;
 
-        let req = req.body(body).map_err(new_request_build_error)?;
+        let mut req = req.body(body).map_err(new_request_build_error)?;
+
+        self.sign(&mut req)?;
 
         self.client.send(req).await
     }
 
-    pub async fn swift_get_metadata(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    pub fn swift_get_metadata_request(&self, path: &str) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -206,13 +270,15 @@ impl SwiftCore {
             percent_encode_path(&p)
         );
 
-        let mut req = Request::head(&url);
+        Request::head(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)
+    }
 
-        req = req.header("X-Auth-Token", &self.token);
+    pub async fn swift_get_metadata(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.swift_get_metadata_request(path)?;
 
-        let req = req
-            .body(AsyncBody::Empty)
-            .map_err(new_request_build_error)?;
+        self.sign(&mut req)?;
 
         self.client.send(req).await
     }
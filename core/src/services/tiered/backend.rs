@@ -0,0 +1,460 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Buf;
+
+use super::core::TieredCore;
+use super::pager::TieredPager;
+use super::writer::TieredWriter;
+use crate::raw::oio::ReadExt;
+use crate::raw::oio::WriteExt;
+use crate::raw::*;
+use crate::*;
+
+/// Tiered service support, combines a fast tier and a slow tier.
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct TieredBuilder {
+    fast: Option<Operator>,
+    slow: Option<Operator>,
+    capacity: Option<u64>,
+}
+
+impl Debug for TieredBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TieredBuilder")
+            .field("fast", &self.fast.as_ref().map(|v| v.info().scheme()))
+            .field("slow", &self.slow.as_ref().map(|v| v.info().scheme()))
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+impl TieredBuilder {
+    /// Set the fast tier, like `fs` or `moka`.
+    ///
+    /// All writes will go to the fast tier first.
+    pub fn fast(&mut self, op: Operator) -> &mut Self {
+        self.fast = Some(op);
+        self
+    }
+
+    /// Set the slow tier, like `s3`.
+    pub fn slow(&mut self, op: Operator) -> &mut Self {
+        self.slow = Some(op);
+        self
+    }
+
+    /// Set the max total bytes of files stored in the fast tier.
+    ///
+    /// Least recently used files will be evicted from fast tier once exceeded.
+    pub fn capacity(&mut self, capacity: u64) -> &mut Self {
+        self.capacity = Some(capacity);
+        self
+    }
+}
+
+impl Builder for TieredBuilder {
+    const SCHEME: Scheme = Scheme::Tiered;
+    type Accessor = TieredBackend;
+
+    fn from_map(map: HashMap<String, String>) -> Self {
+        let mut builder = Self::default();
+
+        map.get("capacity")
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(|v| builder.capacity(v));
+
+        builder
+    }
+
+    fn build(&mut self) -> Result<Self::Accessor> {
+        let fast = match self.fast.take() {
+            Some(v) => v.into_inner(),
+            None => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "fast is empty")
+                    .with_operation("Builder::build")
+                    .with_context("service", Scheme::Tiered))
+            }
+        };
+        let slow = match self.slow.take() {
+            Some(v) => v.into_inner(),
+            None => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "slow is empty")
+                    .with_operation("Builder::build")
+                    .with_context("service", Scheme::Tiered))
+            }
+        };
+
+        Ok(TieredBackend {
+            core: Arc::new(TieredCore::new(fast, slow, self.capacity)),
+        })
+    }
+}
+
+/// Backend for tiered services.
+#[derive(Debug, Clone)]
+pub struct TieredBackend {
+    core: Arc<TieredCore>,
+}
+
+#[async_trait]
+impl Accessor for TieredBackend {
+    type Reader = oio::Reader;
+    type BlockingReader = ();
+    type Writer = TieredWriter;
+    type BlockingWriter = ();
+    type Pager = TieredPager;
+    type BlockingPager = ();
+
+    fn info(&self) -> AccessorInfo {
+        let (fast, slow) = (self.core.fast.info(), self.core.slow.info());
+        let (fc, sc) = (fast.full_capability(), slow.full_capability());
+
+        let mut cap = Capability::default();
+        cap.stat = fc.stat && sc.stat;
+        cap.read = fc.read && sc.read;
+        cap.read_can_seek = fc.read_can_seek && sc.read_can_seek;
+        cap.read_can_next = fc.read_can_next && sc.read_can_next;
+        cap.read_with_range = fc.read_with_range && sc.read_with_range;
+        // Pending markers and flushing require read, write and delete on both tiers.
+        cap.write = cap.read && fc.write && fc.delete && sc.write;
+        cap.write_can_multi = cap.write && fc.write_can_multi;
+        cap.write_can_empty = cap.write && fc.write_can_empty && sc.write_can_empty;
+        cap.create_dir = fc.create_dir && sc.create_dir;
+        cap.delete = fc.delete && sc.delete;
+        cap.list = fc.list && sc.list;
+        // Both tiers have been completed, so we can list with any delimiter.
+        cap.list_with_delimiter_slash = cap.list;
+        cap.list_without_delimiter = cap.list;
+        cap.copy = cap.read && cap.write;
+        cap.rename = cap.copy && cap.delete;
+
+        let mut am = AccessorInfo::default();
+        am.set_scheme(Scheme::Tiered)
+            .set_root("/")
+            .set_name(&format!(
+                "{}://{}{}+{}://{}{}",
+                fast.scheme(),
+                fast.name(),
+                fast.root(),
+                slow.scheme(),
+                slow.name(),
+                slow.root()
+            ))
+            .set_native_capability(cap);
+
+        am
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.core.recover().await?;
+
+        self.core.fast.create_dir(path, args.clone()).await?;
+        self.core.slow.create_dir(path, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.core.recover().await?;
+
+        // Readers could be lazy, so we need to stat first to know whether
+        // this file exists in fast tier.
+        match self.core.fast.stat(path, OpStat::new()).await {
+            Ok(_) => {
+                self.core.touch(path);
+                return self.core.fast.read(path, args).await;
+            }
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+
+        let (rp, r) = self.core.slow.read(path, args.clone()).await?;
+        // Only promote the file if we know its total size.
+        let size = if args.range().is_full() {
+            rp.size()
+        } else {
+            None
+        };
+        self.core.promote(path, size).await;
+
+        Ok((rp, r))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.core.recover().await?;
+
+        let token = self.core.start_write(path).await?;
+        let (rp, w) = self.core.fast.write(path, args).await?;
+
+        Ok((rp, TieredWriter::new(self.core.clone(), path, token, w)))
+    }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        let (_, mut r) = self.read(from, OpRead::new()).await?;
+        let (_, mut w) = self.write(to, OpWrite::new()).await?;
+
+        let res = async {
+            while let Some(bs) = r.next().await {
+                let mut bs = bs?;
+                while bs.has_remaining() {
+                    let n = w.write(&bs).await?;
+                    bs.advance(n);
+                }
+            }
+            w.close().await
+        }
+        .await;
+
+        if let Err(err) = res {
+            // Abort is best effort, the error of copy is more important.
+            let _ = w.abort().await;
+            return Err(err);
+        }
+
+        Ok(RpCopy::default())
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        self.copy(from, to, OpCopy::new()).await?;
+        self.delete(from, OpDelete::new()).await?;
+
+        Ok(RpRename::default())
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.core.recover().await?;
+
+        match self.core.fast.stat(path, args.clone()).await {
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            v => return v,
+        }
+
+        self.core.slow.stat(path, args).await
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.core.recover().await?;
+
+        if !path.ends_with('/') {
+            self.core.forget(path).await?;
+        }
+        self.core.fast.delete(path, args.clone()).await?;
+        self.core.slow.delete(path, args).await?;
+
+        Ok(RpDelete::default())
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.core.recover().await?;
+
+        let (_, fast) = self.core.fast.list(path, args.clone()).await?;
+        let (_, slow) = self.core.slow.list(path, args).await?;
+
+        Ok((RpList::default(), TieredPager::new(fast, slow)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::services::Memory;
+
+    /// FailLayer makes the next write fail with a temporary error.
+    #[derive(Clone)]
+    struct FailLayer(Arc<AtomicBool>);
+
+    impl<A: Accessor> Layer<A> for FailLayer {
+        type LayeredAccessor = FailAccessor<A>;
+
+        fn layer(&self, inner: A) -> Self::LayeredAccessor {
+            FailAccessor {
+                inner,
+                fail: self.0.clone(),
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    struct FailAccessor<A: Accessor> {
+        inner: A,
+        fail: Arc<AtomicBool>,
+    }
+
+    #[async_trait]
+    impl<A: Accessor> LayeredAccessor for FailAccessor<A> {
+        type Inner = A;
+        type Reader = A::Reader;
+        type BlockingReader = A::BlockingReader;
+        type Writer = A::Writer;
+        type BlockingWriter = A::BlockingWriter;
+        type Pager = A::Pager;
+        type BlockingPager = A::BlockingPager;
+
+        fn inner(&self) -> &Self::Inner {
+            &self.inner
+        }
+
+        async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+            self.inner.read(path, args).await
+        }
+
+        async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+            if self.fail.swap(false, Ordering::SeqCst) {
+                return Err(Error::new(ErrorKind::Unexpected, "injected failure").set_temporary());
+            }
+            self.inner.write(path, args).await
+        }
+
+        async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+            self.inner.list(path, args).await
+        }
+
+        fn blocking_read(
+            &self,
+            path: &str,
+            args: OpRead,
+        ) -> Result<(RpRead, Self::BlockingReader)> {
+            self.inner.blocking_read(path, args)
+        }
+
+        fn blocking_write(
+            &self,
+            path: &str,
+            args: OpWrite,
+        ) -> Result<(RpWrite, Self::BlockingWriter)> {
+            self.inner.blocking_write(path, args)
+        }
+
+        fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+            self.inner.blocking_list(path, args)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_flush_and_promote() -> Result<()> {
+        let fast = Operator::new(Memory::default())?.finish();
+        let slow = Operator::new(Memory::default())?.finish();
+
+        let mut builder = TieredBuilder::default();
+        builder.fast(fast.clone()).slow(slow.clone()).capacity(10);
+        let op = Operator::new(builder)?.finish();
+
+        op.write("a", "hello").await?;
+        op.write("b", "world").await?;
+        assert_eq!(fast.read("a").await?, b"hello");
+
+        // Wait for background flush.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(slow.read("a").await?, b"hello");
+        assert_eq!(slow.read("b").await?, b"world");
+        assert!(fast
+            .list_with("/")
+            .delimiter("")
+            .await?
+            .iter()
+            .all(|e| !e.path().starts_with(".opendal_tiered/")));
+
+        // Exceed the capacity, `a` will be evicted.
+        op.write("c", "!").await?;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!fast.is_exist("a").await?);
+        assert_eq!(op.read("a").await?, b"hello");
+
+        // `a` should be promoted back to fast tier.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(fast.read("a").await?, b"hello");
+
+        let entries: Vec<_> = op
+            .list("/")
+            .await?
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        assert_eq!(entries, vec!["a", "b", "c"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_abort_and_delete() -> Result<()> {
+        let fast = Operator::new(Memory::default())?.finish();
+        let slow = Operator::new(Memory::default())?.finish();
+
+        let mut builder = TieredBuilder::default();
+        builder.fast(fast.clone()).slow(slow.clone());
+        let op = Operator::new(builder)?.finish();
+
+        let mut w = op.writer("a").await?;
+        w.write("hello").await?;
+        w.abort().await?;
+        assert!(fast
+            .list_with(".opendal_tiered/")
+            .delimiter("")
+            .await?
+            .is_empty());
+
+        op.write("b", "world").await?;
+        op.delete("b").await?;
+        // Wait for background flush, deleted file must not be flushed back.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!slow.is_exist("b").await?);
+        assert!(!op.is_exist("b").await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retry_failed_flush() -> Result<()> {
+        let fast = Operator::new(Memory::default())?.finish();
+        let slow = Operator::new(Memory::default())?.finish();
+        let failing = slow
+            .clone()
+            .layer(FailLayer(Arc::new(AtomicBool::new(true))));
+
+        let mut builder = TieredBuilder::default();
+        builder.fast(fast.clone()).slow(failing).capacity(5);
+        let op = Operator::new(builder)?.finish();
+
+        op.write("a", "hello").await?;
+        op.write("b", "world").await?;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        // The flush of `a` failed, it must not be evicted before flushed.
+        assert!(!slow.is_exist("a").await?);
+        assert_eq!(fast.read("a").await?, b"hello");
+
+        // Wait for the retry with backoff.
+        tokio::time::sleep(Duration::from_secs(3)).await;
+        assert_eq!(slow.read("a").await?, b"hello");
+        assert!(fast
+            .list_with(".opendal_tiered/")
+            .delimiter("")
+            .await?
+            .is_empty());
+
+        Ok(())
+    }
+}
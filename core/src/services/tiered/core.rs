@@ -0,0 +1,484 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::time::Duration;
+
+use backon::ExponentialBuilder;
+use backon::Retryable;
+use base64::engine::general_purpose;
+use base64::Engine;
+use bytes::Buf;
+use bytes::Bytes;
+use futures::Future;
+use futures::TryStreamExt;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use tokio::sync::OnceCell;
+
+use crate::raw::oio::ReadExt;
+use crate::raw::oio::WriteExt;
+use crate::raw::*;
+use crate::*;
+
+/// The dir in fast tier to store pending markers.
+pub const QUEUE_DIR: &str = ".opendal_tiered/";
+
+/// Failed flushes will be retried until succeeded, so that data won't be
+/// left in fast tier only.
+static FLUSH_BACKOFF: Lazy<ExponentialBuilder> = Lazy::new(|| {
+    ExponentialBuilder::default()
+        .with_jitter()
+        .with_max_delay(Duration::from_secs(60))
+        .with_max_times(usize::MAX)
+});
+
+pub struct TieredCore {
+    pub fast: FusedAccessor,
+    pub slow: FusedAccessor,
+    pub capacity: Option<u64>,
+
+    recovered: OnceCell<()>,
+    state: Mutex<State>,
+}
+
+impl Debug for TieredCore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TieredCore")
+            .field("fast", &self.fast.info().scheme())
+            .field("slow", &self.slow.info().scheme())
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TieredCore {
+    pub fn new(fast: FusedAccessor, slow: FusedAccessor, capacity: Option<u64>) -> Self {
+        Self {
+            fast,
+            slow,
+            capacity,
+            recovered: OnceCell::new(),
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Recover the state from fast tier at the first operation.
+    ///
+    /// - Account all existing files for eviction.
+    /// - Flush all files that have pending markers.
+    ///
+    /// Concurrent operations will wait for the recovery, and a failed recovery
+    /// will be retried by the next operation.
+    pub async fn recover(self: &Arc<Self>) -> Result<()> {
+        self.recovered
+            .get_or_try_init(|| self.recover_inner())
+            .await?;
+        Ok(())
+    }
+
+    async fn recover_inner(self: &Arc<Self>) -> Result<()> {
+        let mut lister = Lister::create(
+            self.fast.clone(),
+            "/",
+            OpList::new()
+                .with_delimiter("")
+                .with_metakey(Metakey::Mode | Metakey::ContentLength),
+        )
+        .await?;
+
+        let mut pending = vec![];
+        while let Some(entry) = lister.try_next().await? {
+            let (path, meta) = entry.into_parts();
+            if !meta.is_file() {
+                continue;
+            }
+
+            if let Some(marker) = path.strip_prefix(QUEUE_DIR) {
+                match decode_marker(marker) {
+                    Some(p) => pending.push(p),
+                    None => log::warn!("tiered: invalid pending marker {path}, ignored"),
+                }
+            } else {
+                self.state.lock().touch(&path, Some(meta.content_length()));
+            }
+        }
+
+        for path in pending {
+            self.state.lock().pending.insert(path.clone());
+            self.flush(path).await?;
+        }
+        self.evict().await
+    }
+
+    /// Mark this path as pending before writing into fast tier.
+    ///
+    /// Returns the token of this write.
+    pub async fn start_write(&self, path: &str) -> Result<String> {
+        let token = uuid::Uuid::new_v4().to_string();
+
+        write_all(
+            &self.fast,
+            &marker_path(path),
+            OpWrite::new(),
+            Bytes::from(token.clone()),
+        )
+        .await?;
+        self.state.lock().pending.insert(path.to_string());
+
+        Ok(token)
+    }
+
+    /// Called after the write has been aborted.
+    ///
+    /// The pending marker will be removed if there are no other writes, unless
+    /// the file still exists in fast tier which needs to be flushed.
+    pub async fn abort_write(self: &Arc<Self>, path: &str, token: &str) -> Result<()> {
+        let marker = marker_path(path);
+        match read_all(&self.fast, &marker).await {
+            Ok(v) if v == token.as_bytes() => {}
+            // Marker has been overwritten by other writes or removed.
+            Ok(_) => return Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err),
+        }
+
+        match self.fast.stat(path, OpStat::new()).await {
+            Ok(_) => self.flush(path.to_string()).await?,
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                self.fast.delete(&marker, OpDelete::new()).await?;
+                self.state.lock().pending.remove(path);
+            }
+            Err(err) => return Err(err),
+        }
+        Ok(())
+    }
+
+    /// Called after the file has been written into fast tier.
+    pub async fn finish_write(self: &Arc<Self>, path: &str, size: u64) -> Result<()> {
+        self.state.lock().touch(path, Some(size));
+        self.flush(path.to_string()).await?;
+        self.evict().await
+    }
+
+    /// Called after reading from fast tier.
+    pub fn touch(&self, path: &str) {
+        self.state.lock().touch(path, None);
+    }
+
+    /// Forget this path before deleting from both tiers.
+    ///
+    /// The pending marker is removed first, so that in-flight flushes will
+    /// notice the deletion and undo their copies.
+    pub async fn forget(&self, path: &str) -> Result<()> {
+        self.fast
+            .delete(&marker_path(path), OpDelete::new())
+            .await?;
+
+        let mut state = self.state.lock();
+        state.remove(path);
+        state.pending.remove(path);
+        Ok(())
+    }
+
+    /// Promote a file from slow tier into fast tier in background.
+    pub async fn promote(self: &Arc<Self>, path: &str, size: Option<u64>) {
+        if let (Some(capacity), Some(size)) = (self.capacity, size) {
+            if size > capacity {
+                return;
+            }
+        }
+
+        {
+            let mut state = self.state.lock();
+            // Never overwrite the files that haven't been flushed.
+            if state.pending.contains(path) || !state.promoting.insert(path.to_string()) {
+                return;
+            }
+        }
+
+        let core = self.clone();
+        let path = path.to_string();
+        spawn(async move {
+            let res = copy(&core.slow, &core.fast, &path).await;
+            core.state.lock().promoting.remove(&path);
+
+            match res {
+                Ok(size) => {
+                    core.state.lock().touch(&path, Some(size));
+                    if let Err(err) = core.evict().await {
+                        log::warn!("tiered: evict failed: {err}");
+                    }
+                }
+                Err(err) => log::warn!("tiered: promote {path} failed: {err}"),
+            }
+        })
+        .await
+    }
+
+    /// Flush a file from fast tier into slow tier in background.
+    ///
+    /// Failed flushes will be retried with backoff until succeeded or the
+    /// path has been forgotten. If there is no runtime to retry in background,
+    /// the flush will be performed inline and the error is returned instead.
+    async fn flush(self: &Arc<Self>, path: String) -> Result<()> {
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return self.flush_inner(&path).await;
+        };
+
+        let core = self.clone();
+        handle.spawn(async move {
+            let res = { || core.flush_inner(&path) }
+                .retry(&*FLUSH_BACKOFF)
+                // Stop retrying once the path has been deleted.
+                .when(|_| core.state.lock().pending.contains(&path))
+                .notify(|err, dur| {
+                    log::warn!("tiered: flush {path} failed, retry after {dur:?}: {err}")
+                })
+                .await;
+            if let Err(err) = res {
+                log::warn!("tiered: flush {path} failed: {err}");
+            }
+        });
+        Ok(())
+    }
+
+    async fn flush_inner(&self, path: &str) -> Result<()> {
+        let marker = marker_path(path);
+        let token = match read_all(&self.fast, &marker).await {
+            Ok(v) => v,
+            // Marker has been removed by other flushes or deletion.
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err),
+        };
+
+        let copied = match copy(&self.fast, &self.slow, path).await {
+            Ok(_) => true,
+            // File has been deleted from fast tier, nothing to flush.
+            Err(err) if err.kind() == ErrorKind::NotFound => false,
+            Err(err) => return Err(err),
+        };
+
+        match read_all(&self.fast, &marker).await {
+            // Only remove the marker if there are no new writes.
+            Ok(v) if v == token => {
+                self.fast.delete(&marker, OpDelete::new()).await?;
+                self.state.lock().pending.remove(path);
+            }
+            Ok(_) => {}
+            // The path has been deleted while flushing, undo our copy.
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                if copied {
+                    self.slow.delete(path, OpDelete::new()).await?;
+                }
+            }
+            Err(err) => return Err(err),
+        }
+        Ok(())
+    }
+
+    /// Evict least recently used files until total size is under capacity.
+    async fn evict(&self) -> Result<()> {
+        let Some(capacity) = self.capacity else {
+            return Ok(());
+        };
+
+        let victims = self.state.lock().evict(capacity);
+        for path in victims {
+            self.fast.delete(&path, OpDelete::new()).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Run the future on current tokio runtime, or run it inline if there
+/// is no runtime available.
+async fn spawn<F>(fut: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) => {
+            handle.spawn(fut);
+        }
+        Err(_) => fut.await,
+    }
+}
+
+fn marker_path(path: &str) -> String {
+    format!(
+        "{QUEUE_DIR}{}",
+        general_purpose::URL_SAFE_NO_PAD.encode(path.as_bytes())
+    )
+}
+
+fn decode_marker(marker: &str) -> Option<String> {
+    let bs = general_purpose::URL_SAFE_NO_PAD.decode(marker).ok()?;
+    String::from_utf8(bs).ok()
+}
+
+async fn read_all(acc: &FusedAccessor, path: &str) -> Result<Vec<u8>> {
+    let (_, mut r) = acc.read(path, OpRead::new()).await?;
+    let mut buf = Vec::new();
+    r.read_to_end(&mut buf).await?;
+    Ok(buf)
+}
+
+async fn write_all(acc: &FusedAccessor, path: &str, args: OpWrite, mut bs: Bytes) -> Result<()> {
+    let (_, mut w) = acc.write(path, args).await?;
+    while bs.has_remaining() {
+        let n = w.write(&bs).await?;
+        bs.advance(n);
+    }
+    w.close().await
+}
+
+/// Copy a file from one tier to another, returns the size of this file.
+async fn copy(from: &FusedAccessor, to: &FusedAccessor, path: &str) -> Result<u64> {
+    let (_, mut r) = from.read(path, OpRead::new()).await?;
+    let (_, mut w) = to.write(path, OpWrite::new()).await?;
+
+    let res = async {
+        let mut size = 0;
+        while let Some(bs) = r.next().await {
+            let mut bs = bs?;
+            size += bs.len() as u64;
+            while bs.has_remaining() {
+                let n = w.write(&bs).await?;
+                bs.advance(n);
+            }
+        }
+        w.close().await?;
+        Ok(size)
+    }
+    .await;
+
+    if res.is_err() {
+        // Abort is best effort, the error of copy is more important.
+        let _ = w.abort().await;
+    }
+    res
+}
+
+/// State of tiered, including the LRU accounting of fast tier.
+#[derive(Default)]
+struct State {
+    /// path => (size, tick)
+    entries: HashMap<String, (u64, u64)>,
+    /// tick => path, the smallest tick is the least recently used.
+    order: BTreeMap<u64, String>,
+    tick: u64,
+    total: u64,
+
+    /// Files that haven't been flushed into slow tier.
+    pending: HashSet<String>,
+    /// Files that are being promoted into fast tier.
+    promoting: HashSet<String>,
+}
+
+impl State {
+    /// Mark this path as most recently used.
+    ///
+    /// If size is `None`, only existing entry will be updated.
+    fn touch(&mut self, path: &str, size: Option<u64>) {
+        let size = match (self.entries.remove(path), size) {
+            (Some((old_size, tick)), size) => {
+                self.order.remove(&tick);
+                self.total -= old_size;
+                size.unwrap_or(old_size)
+            }
+            (None, Some(size)) => size,
+            (None, None) => return,
+        };
+
+        self.tick += 1;
+        self.entries.insert(path.to_string(), (size, self.tick));
+        self.order.insert(self.tick, path.to_string());
+        self.total += size;
+    }
+
+    fn remove(&mut self, path: &str) {
+        if let Some((size, tick)) = self.entries.remove(path) {
+            self.order.remove(&tick);
+            self.total -= size;
+        }
+    }
+
+    /// Pick the victims to make total size under capacity.
+    fn evict(&mut self, capacity: u64) -> Vec<String> {
+        let mut victims = vec![];
+        let mut total = self.total;
+        for path in self.order.values() {
+            if total <= capacity {
+                break;
+            }
+            if self.pending.contains(path) || self.promoting.contains(path) {
+                continue;
+            }
+            total -= self.entries[path].0;
+            victims.push(path.clone());
+        }
+
+        for path in victims.iter() {
+            self.remove(path);
+        }
+        victims
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_evict() {
+        let mut state = State::default();
+        state.touch("a", Some(10));
+        state.touch("b", Some(10));
+        state.touch("c", Some(10));
+        state.pending.insert("a".to_string());
+
+        // Touch without size for unknown path should be ignored.
+        state.touch("d", None);
+        assert_eq!(state.total, 30);
+
+        // `b` is the least recently used file that has been flushed.
+        assert_eq!(state.evict(20), vec!["b".to_string()]);
+        assert_eq!(state.total, 20);
+
+        state.pending.remove("a");
+        state.touch("c", None);
+        assert_eq!(state.evict(10), vec!["a".to_string()]);
+        assert_eq!(state.total, 10);
+    }
+
+    #[test]
+    fn test_marker_path() {
+        let path = "path/to/file";
+        let marker = marker_path(path);
+        assert!(marker.starts_with(QUEUE_DIR));
+        assert_eq!(
+            decode_marker(marker.strip_prefix(QUEUE_DIR).unwrap()),
+            Some(path.to_string())
+        );
+    }
+}
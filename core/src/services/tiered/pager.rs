@@ -0,0 +1,50 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use async_trait::async_trait;
+
+use super::core::QUEUE_DIR;
+use crate::raw::*;
+use crate::*;
+
+/// TieredPager merges the entries from both tiers and hides the pending
+/// markers.
+pub struct TieredPager {
+    inner: oio::MergePager<oio::Pager>,
+}
+
+impl TieredPager {
+    pub fn new(fast: oio::Pager, slow: oio::Pager) -> Self {
+        Self {
+            inner: oio::into_merge_page(vec![fast, slow]),
+        }
+    }
+}
+
+#[async_trait]
+impl oio::Page for TieredPager {
+    async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        let entries = self.inner.next().await?;
+
+        Ok(entries.map(|entries| {
+            entries
+                .into_iter()
+                .filter(|e| !e.path().starts_with(QUEUE_DIR))
+                .collect()
+        }))
+    }
+}
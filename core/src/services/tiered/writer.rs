@@ -0,0 +1,102 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
+use super::core::TieredCore;
+use crate::raw::*;
+use crate::*;
+
+/// TieredWriter writes into fast tier, and schedules the flush after closed.
+pub struct TieredWriter {
+    core: Arc<TieredCore>,
+    path: String,
+    /// The token in pending marker of this write.
+    token: String,
+
+    inner: oio::Writer,
+    size: u64,
+    finishing: Option<BoxFuture<'static, Result<()>>>,
+}
+
+impl TieredWriter {
+    pub fn new(core: Arc<TieredCore>, path: &str, token: String, inner: oio::Writer) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+            token,
+            inner,
+            size: 0,
+            finishing: None,
+        }
+    }
+}
+
+/// # Safety
+///
+/// We will only take `&mut Self` reference for TieredWriter.
+unsafe impl Sync for TieredWriter {}
+
+impl oio::Write for TieredWriter {
+    fn poll_write(&mut self, cx: &mut Context<'_>, bs: &dyn oio::WriteBuf) -> Poll<Result<usize>> {
+        let n = ready!(self.inner.poll_write(cx, bs))?;
+        self.size += n as u64;
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        if self.finishing.is_none() {
+            ready!(self.inner.poll_close(cx))?;
+
+            let core = self.core.clone();
+            let path = self.path.clone();
+            let size = self.size;
+            self.finishing = Some(Box::pin(
+                async move { core.finish_write(&path, size).await },
+            ));
+        }
+
+        let fut = self.finishing.as_mut().expect("finishing must be valid");
+        let res = ready!(fut.poll_unpin(cx));
+        self.finishing = None;
+        Poll::Ready(res)
+    }
+
+    fn poll_abort(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        if self.finishing.is_none() {
+            ready!(self.inner.poll_abort(cx))?;
+
+            let core = self.core.clone();
+            let path = self.path.clone();
+            let token = self.token.clone();
+            self.finishing = Some(Box::pin(
+                async move { core.abort_write(&path, &token).await },
+            ));
+        }
+
+        let fut = self.finishing.as_mut().expect("finishing must be valid");
+        let res = ready!(fut.poll_unpin(cx));
+        self.finishing = None;
+        Poll::Ready(res)
+    }
+}
@@ -163,6 +163,9 @@ impl Builder for TikvBuilder {
     }
 }
 
+/// The max number of keys returned by one scan request.
+const SCAN_LIMIT: u32 = 1000;
+
 /// Backend for TiKV service
 pub type TikvBackend = kv::Backend<Adapter>;
 
@@ -225,6 +228,7 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                list: true,
                 blocking: false,
                 ..Default::default()
             },
@@ -254,6 +258,49 @@ impl kv::Adapter for Adapter {
             .await
             .map_err(parse_tikv_error)
     }
+
+    async fn scan(&self, path: &str) -> Result<Vec<String>> {
+        let client = self.get_connection().await?;
+        let end = prefix_end(path.as_bytes());
+
+        let mut res = Vec::new();
+        let mut start = path.as_bytes().to_vec();
+        loop {
+            let keys = client
+                .scan_keys((start.clone(), end.clone()), SCAN_LIMIT)
+                .await
+                .map_err(parse_tikv_error)?;
+            let done = keys.len() < SCAN_LIMIT as usize;
+
+            for key in keys {
+                let key: Vec<u8> = key.into();
+                // Continue from the next key of the last one.
+                start = key.clone();
+                start.push(0);
+
+                res.push(String::from_utf8(key).map_err(|err| {
+                    Error::new(ErrorKind::Unexpected, "store key is not valid utf-8 string")
+                        .set_source(err)
+                })?);
+            }
+            if done {
+                return Ok(res);
+            }
+        }
+    }
+}
+
+/// Returns the smallest key that is larger than all keys with given prefix,
+/// `None` if there is no such key.
+fn prefix_end(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut end = prefix.to_vec();
+    while let Some(last) = end.pop() {
+        if last < u8::MAX {
+            end.push(last + 1);
+            return Some(end);
+        }
+    }
+    None
 }
 
 fn parse_tikv_error(e: tikv_client::Error) -> Error {
@@ -21,6 +21,7 @@ use std::collections::HashMap;
 use async_trait::async_trait;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
+use http::Method;
 use http::Request;
 use http::Response;
 use http::StatusCode;
@@ -30,6 +31,7 @@ use tokio::sync::OnceCell;
 use super::error::parse_error;
 use super::error::parse_error_msg;
 use super::message::BooleanResp;
+use super::message::ContentSummaryWrapper;
 use super::message::DirectoryListingWrapper;
 use super::message::FileStatusType;
 use super::message::FileStatusWrapper;
@@ -49,6 +51,7 @@ pub struct WebhdfsBuilder {
     endpoint: Option<String>,
     delegation: Option<String>,
     disable_list_batch: bool,
+    enable_presign: bool,
 }
 
 impl Debug for WebhdfsBuilder {
@@ -100,6 +103,10 @@ impl WebhdfsBuilder {
     /// # Note
     /// The builder prefers using delegation token over username.
     /// If both are set, delegation token will be used.
+    ///
+    /// The token is sent via the `delegation=` query parameter defined by
+    /// WebHDFS. Older versions sent `delegation_token=` which is not recognized
+    /// by WebHDFS.
     pub fn delegation(&mut self, delegation: &str) -> &mut Self {
         if !delegation.is_empty() {
             self.delegation = Some(delegation.to_string());
@@ -117,6 +124,19 @@ impl WebhdfsBuilder {
         self.disable_list_batch = true;
         self
     }
+
+    /// Enable presign with the delegation token.
+    ///
+    /// # Warning
+    ///
+    /// WebHDFS doesn't support signing a single request, so presigned urls
+    /// carry the delegation token as is. Anyone who gets the url has full
+    /// access to HDFS until the token expires, and `expire` of presign is
+    /// ignored. Only enable this for short-lived tokens issued for this purpose.
+    pub fn enable_presign(&mut self) -> &mut Self {
+        self.enable_presign = true;
+        self
+    }
 }
 
 impl Builder for WebhdfsBuilder {
@@ -132,6 +152,9 @@ impl Builder for WebhdfsBuilder {
         map.get("disable_list_batch")
             .filter(|v| v == &"true")
             .map(|_| builder.disable_list_batch());
+        map.get("enable_presign")
+            .filter(|v| v == &"true")
+            .map(|_| builder.enable_presign());
 
         builder
     }
@@ -162,10 +185,7 @@ impl Builder for WebhdfsBuilder {
         };
         debug!("backend use endpoint {}", endpoint);
 
-        let auth = self
-            .delegation
-            .take()
-            .map(|dt| format!("delegation_token={dt}"));
+        let auth = self.delegation.take().map(|dt| format!("delegation={dt}"));
 
         let client = HttpClient::new()?;
 
@@ -176,6 +196,7 @@ impl Builder for WebhdfsBuilder {
             client,
             root_checker: OnceCell::new(),
             disable_list_batch: self.disable_list_batch,
+            enable_presign: self.enable_presign,
         };
 
         Ok(backend)
@@ -191,6 +212,7 @@ pub struct WebhdfsBackend {
     root_checker: OnceCell<()>,
 
     pub disable_list_batch: bool,
+    enable_presign: bool,
     pub client: HttpClient,
 }
 
@@ -352,10 +374,33 @@ impl WebhdfsBackend {
         self.client.send(req).await
     }
 
-    async fn webhdfs_delete(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    async fn webhdfs_get_content_summary(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let mut url = format!(
+            "{}/webhdfs/v1/{}?op=GETCONTENTSUMMARY",
+            self.endpoint,
+            percent_encode_path(&p),
+        );
+
+        if let Some(auth) = &self.auth {
+            url += format!("&{auth}").as_str();
+        }
+
+        let req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.client.send(req).await
+    }
+
+    async fn webhdfs_delete(
+        &self,
+        path: &str,
+        recursive: bool,
+    ) -> Result<Response<IncomingAsyncBody>> {
         let p = build_abs_path(&self.root, path);
         let mut url = format!(
-            "{}/webhdfs/v1/{}?op=DELETE&recursive=false",
+            "{}/webhdfs/v1/{}?op=DELETE&recursive={recursive}",
             self.endpoint,
             percent_encode_path(&p),
         );
@@ -419,10 +464,19 @@ impl Accessor for WebhdfsBackend {
                 write: true,
                 create_dir: true,
                 delete: true,
+                delete_with_recursive: true,
 
                 list: true,
                 list_with_delimiter_slash: true,
 
+                summary: true,
+
+                // Presigned urls carry the delegation token, only enabled on demand.
+                presign: self.enable_presign && self.auth.is_some(),
+                presign_stat: self.enable_presign && self.auth.is_some(),
+                presign_read: self.enable_presign && self.auth.is_some(),
+                presign_write: self.enable_presign && self.auth.is_some(),
+
                 ..Default::default()
             });
         am
@@ -528,8 +582,8 @@ impl Accessor for WebhdfsBackend {
         }
     }
 
-    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
-        let resp = self.webhdfs_delete(path).await?;
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let resp = self.webhdfs_delete(path, args.recursive()).await?;
 
         match resp.status() {
             StatusCode::OK => {
@@ -591,4 +645,55 @@ impl Accessor for WebhdfsBackend {
             }
         }
     }
+
+    async fn summary(&self, path: &str, _: OpSummary) -> Result<RpSummary> {
+        let resp = self.webhdfs_get_content_summary(path).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+                let summary = serde_json::from_slice::<ContentSummaryWrapper>(&bs)
+                    .map_err(new_json_deserialize_error)?
+                    .content_summary;
+
+                Ok(RpSummary::new(summary.length, summary.file_count))
+            }
+            StatusCode::NOT_FOUND => Ok(RpSummary::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    /// The generated url embeds the delegation token, so it's valid until
+    /// the token expires and `expire` will be ignored.
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        let req = match args.operation() {
+            // `GETFILESTATUS` returns metadata in body, so we presign stat as a
+            // `HEAD` request of `OPEN` to carry content length in headers.
+            PresignOperation::Stat(_) => {
+                let mut req = self
+                    .webhdfs_open_request(path, &BytesRange::default())
+                    .await?;
+                *req.method_mut() = Method::HEAD;
+                req
+            }
+            PresignOperation::Read(v) => self.webhdfs_open_request(path, &v.range()).await?,
+            PresignOperation::Write(v) => {
+                self.webhdfs_create_object_request(path, None, v, AsyncBody::Empty)?
+            }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "operation is not supported by webhdfs presign",
+                ))
+            }
+        };
+
+        let (parts, _) = req.into_parts();
+
+        Ok(RpPresign::new(PresignedRequest::new(
+            parts.method,
+            parts.uri,
+            parts.headers,
+        )))
+    }
 }
@@ -80,6 +80,19 @@ pub enum FileStatusType {
     File,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct ContentSummaryWrapper {
+    pub content_summary: ContentSummary,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct ContentSummary {
+    pub file_count: u64,
+    pub length: u64,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -246,4 +259,27 @@ mod test {
             "bazfile"
         );
     }
+
+    #[test]
+    fn test_content_summary() {
+        let json = r#"
+{
+  "ContentSummary":
+  {
+    "directoryCount": 2,
+    "fileCount"     : 1,
+    "length"        : 24930,
+    "quota"         : -1,
+    "spaceConsumed" : 24930,
+    "spaceQuota"    : -1
+  }
+}
+"#;
+        let summary = serde_json::from_str::<ContentSummaryWrapper>(json)
+            .expect("must success")
+            .content_summary;
+
+        assert_eq!(summary.file_count, 1);
+        assert_eq!(summary.length, 24930);
+    }
 }
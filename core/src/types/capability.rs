@@ -70,6 +70,8 @@ pub struct Capability {
     pub read_with_override_content_disposition: bool,
     /// if operator supports read with override content type.
     pub read_with_override_content_type: bool,
+    /// If operator supports read with version.
+    pub read_with_version: bool,
 
     /// If operator supports write.
     pub write: bool,
@@ -85,6 +87,16 @@ pub struct Capability {
     pub write_with_content_disposition: bool,
     /// If operator supports write with cache control.
     pub write_with_cache_control: bool,
+    /// If operator supports write with storage class.
+    pub write_with_storage_class: bool,
+    /// If operator supports write with tags.
+    pub write_with_tags: bool,
+    /// If operator supports write at given offset.
+    pub write_with_offset: bool,
+    /// If operator supports resuming write from a checkpoint.
+    pub write_with_resume: bool,
+    /// If operator supports write only if the file doesn't exist.
+    pub write_with_if_not_exists: bool,
     /// write_multi_max_size is the max size that services support in write_multi.
     ///
     /// For example, AWS S3 supports 5GiB as max in write_multi.
@@ -107,6 +119,8 @@ pub struct Capability {
 
     /// If operator supports delete.
     pub delete: bool,
+    /// If operator supports delete with recursive.
+    pub delete_with_recursive: bool,
 
     /// If operator supports copy.
     pub copy: bool,
@@ -133,14 +147,40 @@ pub struct Capability {
     pub presign_stat: bool,
     /// If operator supports presign write.
     pub presign_write: bool,
+    /// If operator supports presign write with multipart upload.
+    pub presign_write_multipart: bool,
+    /// If operator supports presign post policy for form uploads.
+    pub presign_post: bool,
 
     /// If operator supports batch.
     pub batch: bool,
     /// If operator supports batch delete.
     pub batch_delete: bool,
+    /// If operator supports batch stat.
+    pub batch_stat: bool,
+    /// If operator supports batch copy.
+    pub batch_copy: bool,
     /// The max operations that operator supports in batch.
     pub batch_max_operations: Option<usize>,
 
+    /// If operator supports get tags.
+    pub get_tags: bool,
+    /// If operator supports set tags.
+    pub set_tags: bool,
+
+    /// If operator supports restore archived files.
+    pub restore: bool,
+
+    /// If operator supports list incomplete uploads.
+    pub list_uploads: bool,
+    /// If operator supports abort incomplete uploads.
+    pub abort_upload: bool,
+
+    /// If operator supports summary natively.
+    pub summary: bool,
+    /// If operator supports watch natively.
+    pub watch: bool,
+
     /// If operator supports blocking.
     pub blocking: bool,
 }
@@ -179,6 +219,21 @@ impl Debug for Capability {
         if self.batch {
             s.push("Batch");
         }
+        if self.get_tags {
+            s.push("GetTags");
+        }
+        if self.set_tags {
+            s.push("SetTags");
+        }
+        if self.restore {
+            s.push("Restore");
+        }
+        if self.summary {
+            s.push("Summary");
+        }
+        if self.watch {
+            s.push("Watch");
+        }
         if self.blocking {
             s.push("Blocking");
         }
@@ -0,0 +1,174 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use futures::stream;
+use futures::StreamExt;
+use futures::TryStreamExt;
+
+use crate::raw::*;
+use crate::*;
+
+/// The max number of requests in flight while summarizing.
+const CONCURRENT: usize = 16;
+
+/// DiskUsage is the summary of all files under a path.
+///
+/// Created by [`Operator::du`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiskUsage {
+    path: String,
+    content_length: u64,
+    file_count: u64,
+    children: Vec<DiskUsage>,
+}
+
+impl DiskUsage {
+    /// The path of this summary.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The total content length of all files under this path.
+    pub fn content_length(&self) -> u64 {
+        self.content_length
+    }
+
+    /// The number of files under this path.
+    pub fn file_count(&self) -> u64 {
+        self.file_count
+    }
+
+    /// The summaries of entries directly inside this dir.
+    ///
+    /// Summaries of children don't carry their own children.
+    pub fn children(&self) -> &[DiskUsage] {
+        &self.children
+    }
+}
+
+/// Summarize the path along with all entries directly inside it.
+pub(crate) async fn du(op: &Operator, path: &str) -> Result<DiskUsage> {
+    let path = normalize_path(path);
+
+    if !path.ends_with('/') {
+        let meta = op.stat(&path).await?;
+        return Ok(DiskUsage {
+            path,
+            content_length: meta.content_length(),
+            file_count: 1,
+            children: vec![],
+        });
+    }
+
+    let entries = op
+        .list_with(&path)
+        .metakey(Metakey::Mode | Metakey::ContentLength)
+        .concurrent(CONCURRENT)
+        .await?;
+    let children: Vec<DiskUsage> = stream::iter(entries)
+        .filter(|entry| futures::future::ready(entry.path() != path))
+        .map(|entry| async move {
+            if entry.metadata().is_dir() {
+                return summarize_dir(op, entry.path()).await;
+            }
+            Ok(DiskUsage {
+                content_length: entry.metadata().content_length(),
+                path: entry.path().to_string(),
+                file_count: 1,
+                children: vec![],
+            })
+        })
+        .buffered(CONCURRENT)
+        .try_collect()
+        .await?;
+
+    Ok(DiskUsage {
+        content_length: children.iter().map(|v| v.content_length).sum(),
+        file_count: children.iter().map(|v| v.file_count).sum(),
+        path,
+        children,
+    })
+}
+
+/// Summarize the dir via native summary if supported, otherwise list it
+/// recursively in one go if supported, or walk it at last.
+async fn summarize_dir(op: &Operator, dir: &str) -> Result<DiskUsage> {
+    let mut usage = DiskUsage {
+        path: dir.to_string(),
+        ..Default::default()
+    };
+
+    if op.info().full_capability().summary {
+        let rp = op.inner().summary(dir, OpSummary::new()).await?;
+        usage.content_length = rp.content_length();
+        usage.file_count = rp.file_count();
+        return Ok(usage);
+    }
+
+    let mut lister = if op.info().full_capability().list_without_delimiter {
+        op.lister_with(dir)
+            .delimiter("")
+            .metakey(Metakey::Mode | Metakey::ContentLength)
+            .concurrent(CONCURRENT)
+            .await?
+    } else {
+        op.walk_with(dir)
+            .metakey(Metakey::Mode | Metakey::ContentLength)
+            .concurrent(CONCURRENT)
+            .await?
+    };
+    while let Some(entry) = lister.try_next().await? {
+        if entry.metadata().is_file() {
+            usage.content_length += entry.metadata().content_length();
+            usage.file_count += 1;
+        }
+    }
+    Ok(usage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_du() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        op.write("a/b/1", vec![0; 10]).await?;
+        op.write("a/b/2", vec![0; 20]).await?;
+        op.write("a/3", vec![0; 30]).await?;
+        op.write("4", vec![0; 40]).await?;
+
+        let usage = op.du("/").await?;
+        assert_eq!(usage.content_length(), 100);
+        assert_eq!(usage.file_count(), 4);
+
+        let mut children: Vec<_> = usage
+            .children()
+            .iter()
+            .map(|v| (v.path(), v.content_length(), v.file_count()))
+            .collect();
+        children.sort();
+        assert_eq!(children, [("4", 40, 1), ("a/", 60, 3)]);
+
+        let usage = op.du("a/b/1").await?;
+        assert_eq!(usage.content_length(), 10);
+        assert_eq!(usage.file_count(), 1);
+
+        Ok(())
+    }
+}
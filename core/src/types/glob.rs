@@ -0,0 +1,553 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::raw::*;
+use crate::*;
+
+/// Pattern is a parsed glob pattern.
+///
+/// Supported syntax:
+///
+/// - `?` matches any single char except `/`.
+/// - `*` matches any sequence of chars except `/`.
+/// - `**` as a whole segment matches any number of segments.
+/// - `[abc]`, `[a-z]` and `[!a-z]` match a single char in (or not in) the set.
+/// - `\` escapes the next char.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Pattern {
+    /// The longest literal dir to start listing from.
+    base: String,
+    /// The segments after base.
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// `**`, matches any number of segments.
+    Any,
+    Tokens(Vec<Token>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Char(char),
+    /// `?`
+    One,
+    /// `*`
+    Many,
+    /// `[...]`
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+impl Segment {
+    fn parse(s: &str) -> Result<Segment> {
+        if s == "**" {
+            return Ok(Segment::Any);
+        }
+
+        let mut tokens = vec![];
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            let token = match c {
+                '?' => Token::One,
+                '*' => Token::Many,
+                '\\' => Token::Char(chars.next().ok_or_else(|| {
+                    Error::new(ErrorKind::InvalidInput, "glob pattern ends with escape")
+                })?),
+                '[' => {
+                    let mut negated = false;
+                    let mut ranges = vec![];
+                    let mut closed = false;
+                    let mut first = true;
+                    while let Some(c) = chars.next() {
+                        match c {
+                            '!' if first => negated = true,
+                            ']' if !first || !ranges.is_empty() => {
+                                closed = true;
+                                break;
+                            }
+                            _ => {
+                                let start = if c == '\\' {
+                                    chars.next().unwrap_or('\\')
+                                } else {
+                                    c
+                                };
+                                // Peek for `-` to build a range.
+                                let mut lookahead = chars.clone();
+                                match (lookahead.next(), lookahead.next()) {
+                                    (Some('-'), Some(end)) if end != ']' => {
+                                        chars = lookahead;
+                                        ranges.push((start, end));
+                                    }
+                                    _ => ranges.push((start, start)),
+                                }
+                            }
+                        }
+                        first = false;
+                    }
+                    if !closed {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            "glob pattern has unclosed char class",
+                        )
+                        .with_context("segment", s));
+                    }
+                    Token::Class { negated, ranges }
+                }
+                c => Token::Char(c),
+            };
+            tokens.push(token);
+        }
+        Ok(Segment::Tokens(tokens))
+    }
+
+    /// Returns the literal value of this segment, `None` if it contains wildcards.
+    fn literal(&self) -> Option<String> {
+        match self {
+            Segment::Any => None,
+            Segment::Tokens(tokens) => tokens
+                .iter()
+                .map(|t| match t {
+                    Token::Char(c) => Some(*c),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Check if the name (without `/`) matches this segment.
+    fn matches(&self, name: &str) -> bool {
+        match self {
+            Segment::Any => true,
+            Segment::Tokens(tokens) => {
+                let name: Vec<char> = name.chars().collect();
+                match_tokens(tokens, &name)
+            }
+        }
+    }
+}
+
+fn match_tokens(tokens: &[Token], name: &[char]) -> bool {
+    let (mut t, mut n) = (0, 0);
+    // The position to backtrack to for the last `*`.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        let matched = match tokens.get(t) {
+            Some(Token::Many) => {
+                backtrack = Some((t, n));
+                t += 1;
+                continue;
+            }
+            Some(Token::Char(c)) => *c == name[n],
+            Some(Token::One) => true,
+            Some(Token::Class { negated, ranges }) => {
+                ranges
+                    .iter()
+                    .any(|(start, end)| *start <= name[n] && name[n] <= *end)
+                    != *negated
+            }
+            None => false,
+        };
+
+        if matched {
+            t += 1;
+            n += 1;
+        } else if let Some((bt, bn)) = backtrack {
+            // Let the last `*` consume one more char.
+            t = bt + 1;
+            n = bn + 1;
+            backtrack = Some((bt, bn + 1));
+        } else {
+            return false;
+        }
+    }
+
+    tokens[t..].iter().all(|t| *t == Token::Many)
+}
+
+fn match_segments(segments: &[Segment], parts: &[&str]) -> bool {
+    match segments.first() {
+        None => parts.is_empty(),
+        Some(Segment::Any) => {
+            (0..=parts.len()).any(|skip| match_segments(&segments[1..], &parts[skip..]))
+        }
+        Some(seg) => match parts.first() {
+            Some(part) => seg.matches(part) && match_segments(&segments[1..], &parts[1..]),
+            None => false,
+        },
+    }
+}
+
+impl Pattern {
+    pub(crate) fn parse(pattern: &str) -> Result<Pattern> {
+        let pattern = pattern.trim_start_matches('/');
+        if pattern.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidInput, "glob pattern is empty"));
+        }
+
+        let mut base = String::new();
+        let mut segments = vec![];
+        let mut parts = pattern
+            .split('/')
+            .filter(|v| !v.is_empty())
+            .map(Segment::parse)
+            .peekable();
+        while let Some(seg) = parts.next() {
+            let seg = seg.map_err(|err| err.with_context("pattern", pattern))?;
+            // The last segment always needs to be matched against entries.
+            match seg.literal() {
+                Some(v) if segments.is_empty() && parts.peek().is_some() => {
+                    base.push_str(&v);
+                    base.push('/');
+                }
+                _ => segments.push(seg),
+            }
+        }
+        if base.is_empty() {
+            base.push('/');
+        }
+
+        Ok(Pattern { base, segments })
+    }
+
+    /// Check if given path matches this pattern.
+    pub(crate) fn matches(&self, path: &str) -> bool {
+        let rel = if self.base == "/" {
+            path
+        } else {
+            match path.strip_prefix(&self.base) {
+                Some(v) => v,
+                None => return false,
+            }
+        };
+        let parts: Vec<&str> = rel.split('/').filter(|v| !v.is_empty()).collect();
+        match_segments(&self.segments, &parts)
+    }
+}
+
+/// GlobPager lists entries that match the pattern.
+///
+/// Dirs are listed one by one and only dirs that could match will be
+/// visited. Once `**` is reached, the dir will be listed recursively.
+///
+/// Matched entries are returned page by page as they are listed.
+pub(crate) struct GlobPager {
+    acc: FusedAccessor,
+    pattern: Arc<Pattern>,
+
+    /// Dirs to list along with the index of segment to match their children.
+    dirs: VecDeque<(String, usize)>,
+    /// The dir that is being listed.
+    listing: Option<(String, usize, oio::Pager)>,
+    blocking_listing: Option<(String, usize, oio::BlockingPager)>,
+}
+
+/// # Safety
+///
+/// We will only take `&mut Self` reference for GlobPager.
+unsafe impl Sync for GlobPager {}
+
+impl GlobPager {
+    pub(crate) fn new(acc: FusedAccessor, pattern: Pattern) -> Self {
+        let dirs = VecDeque::from([(pattern.base.clone(), 0)]);
+
+        Self {
+            acc,
+            pattern: Arc::new(pattern),
+            dirs,
+            listing: None,
+            blocking_listing: None,
+        }
+    }
+
+    /// Check the list args for glob, only `metakey` and `concurrent` are
+    /// allowed since entries are filtered by the pattern.
+    pub(crate) fn check_args(args: &OpList) -> Result<()> {
+        if args.delimiter() != "/" || args.limit().is_some() || args.start_after().is_some() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "glob doesn't support delimiter, limit or start_after",
+            ));
+        }
+        Ok(())
+    }
+
+    fn list_args(&self, idx: usize) -> OpList {
+        let delimiter = match self.pattern.segments[idx] {
+            Segment::Any => "",
+            Segment::Tokens(_) => "/",
+        };
+        OpList::new().with_delimiter(delimiter)
+    }
+
+    /// Filter the entries listed in `dir`, and schedule the dirs to visit.
+    fn filter(&mut self, dir: &str, idx: usize, entries: Vec<oio::Entry>) -> Vec<oio::Entry> {
+        let segments = &self.pattern.segments;
+
+        let mut matched = vec![];
+        for entry in entries {
+            if entry.path() == dir {
+                continue;
+            }
+
+            match &segments[idx] {
+                Segment::Any => {
+                    if self.pattern.matches(entry.path()) {
+                        matched.push(entry);
+                    }
+                }
+                seg => {
+                    // Entries under root are listed without the leading `/`.
+                    let prefix = if dir == "/" { "" } else { dir };
+                    let name = entry
+                        .path()
+                        .strip_prefix(prefix)
+                        .unwrap_or(entry.path())
+                        .trim_end_matches('/');
+                    if !seg.matches(name) {
+                        continue;
+                    }
+                    if idx + 1 == segments.len() {
+                        matched.push(entry);
+                    } else if entry.mode().is_dir() {
+                        // `**` could match zero segments, so dirs matching
+                        // `prefix/**` are candidates too.
+                        if segments[idx + 1..].iter().all(|s| *s == Segment::Any) {
+                            matched.push(entry.clone());
+                        }
+                        self.dirs.push_back((entry.path().to_string(), idx + 1));
+                    }
+                }
+            }
+        }
+        matched
+    }
+}
+
+#[async_trait]
+impl oio::Page for GlobPager {
+    async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        loop {
+            let Some((dir, idx, mut pager)) = self.listing.take() else {
+                let Some((dir, idx)) = self.dirs.pop_front() else {
+                    return Ok(None);
+                };
+                match self.acc.list(&dir, self.list_args(idx)).await {
+                    Ok((_, pager)) => self.listing = Some((dir, idx, pager)),
+                    // Dirs could be removed while globbing.
+                    Err(err) if err.kind() == ErrorKind::NotFound => {}
+                    Err(err) => return Err(err),
+                }
+                continue;
+            };
+
+            let Some(entries) = pager.next().await? else {
+                continue;
+            };
+            let matched = self.filter(&dir, idx, entries);
+            self.listing = Some((dir, idx, pager));
+            if !matched.is_empty() {
+                return Ok(Some(matched));
+            }
+        }
+    }
+}
+
+impl oio::BlockingPage for GlobPager {
+    fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        loop {
+            let Some((dir, idx, mut pager)) = self.blocking_listing.take() else {
+                let Some((dir, idx)) = self.dirs.pop_front() else {
+                    return Ok(None);
+                };
+                match self.acc.blocking_list(&dir, self.list_args(idx)) {
+                    Ok((_, pager)) => self.blocking_listing = Some((dir, idx, pager)),
+                    // Dirs could be removed while globbing.
+                    Err(err) if err.kind() == ErrorKind::NotFound => {}
+                    Err(err) => return Err(err),
+                }
+                continue;
+            };
+
+            let Some(entries) = pager.next()? else {
+                continue;
+            };
+            let matched = self.filter(&dir, idx, entries);
+            self.blocking_listing = Some((dir, idx, pager));
+            if !matched.is_empty() {
+                return Ok(Some(matched));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::services::Memory;
+
+    #[test]
+    fn test_pattern_parse() -> Result<()> {
+        let p = Pattern::parse("data/2023-*/part-*.parquet")?;
+        assert_eq!(p.base, "data/");
+        assert_eq!(p.segments.len(), 2);
+
+        let p = Pattern::parse("data/file.txt")?;
+        assert_eq!(p.base, "data/");
+        assert_eq!(p.segments, vec![Segment::parse("file.txt")?]);
+
+        let p = Pattern::parse("*.txt")?;
+        assert_eq!(p.base, "/");
+
+        assert!(Pattern::parse("data/[a-").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_pattern_matches() -> Result<()> {
+        let cases = [
+            (
+                "data/2023-*/part-*.parquet",
+                "data/2023-01/part-0.parquet",
+                true,
+            ),
+            (
+                "data/2023-*/part-*.parquet",
+                "data/2023-01/x/part-0.parquet",
+                false,
+            ),
+            (
+                "data/2023-*/part-*.parquet",
+                "data/2022-01/part-0.parquet",
+                false,
+            ),
+            ("data/**/*.csv", "data/a.csv", true),
+            ("data/**/*.csv", "data/a/b/c.csv", true),
+            ("data/**/*.csv", "data/a/b/c.json", false),
+            ("data/file-?.txt", "data/file-1.txt", true),
+            ("data/file-?.txt", "data/file-10.txt", false),
+            ("data/file-[0-4].txt", "data/file-3.txt", true),
+            ("data/file-[!0-4].txt", "data/file-3.txt", false),
+            ("data/file-[!0-4].txt", "data/file-7.txt", true),
+            ("data/\\*.txt", "data/*.txt", true),
+            ("data/\\*.txt", "data/a.txt", false),
+            ("data/*", "data/dir/", true),
+        ];
+        for (pattern, path, expected) in cases {
+            assert_eq!(
+                Pattern::parse(pattern)?.matches(path),
+                expected,
+                "{pattern} vs {path}"
+            );
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_glob() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        for path in [
+            "data/2023-01/part-0.parquet",
+            "data/2023-01/part-1.parquet",
+            "data/2023-01/_SUCCESS",
+            "data/2023-02/part-0.parquet",
+            "data/2023-02/nested/part-0.parquet",
+            "data/2022-12/part-0.parquet",
+        ] {
+            op.write(path, "x").await?;
+        }
+
+        let mut paths: Vec<_> = op
+            .glob("data/2023-*/part-*.parquet")
+            .await?
+            .map_ok(|e| e.path().to_string())
+            .try_collect()
+            .await?;
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                "data/2023-01/part-0.parquet",
+                "data/2023-01/part-1.parquet",
+                "data/2023-02/part-0.parquet",
+            ]
+        );
+
+        let mut paths: Vec<_> = op
+            .blocking()
+            .glob("data/**/part-0.parquet")?
+            .map(|e| e.map(|e| e.path().to_string()))
+            .collect::<Result<_>>()?;
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                "data/2022-12/part-0.parquet",
+                "data/2023-01/part-0.parquet",
+                "data/2023-02/nested/part-0.parquet",
+                "data/2023-02/part-0.parquet",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_glob_root() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        for path in ["file-1.txt", "file-10.txt", "a.csv", "abc/d.txt"] {
+            op.write(path, "x").await?;
+        }
+
+        for (pattern, expected) in [
+            ("file-?.txt", vec!["file-1.txt"]),
+            ("a*", vec!["a.csv", "abc/"]),
+            ("*/*.txt", vec!["abc/d.txt"]),
+        ] {
+            let mut paths: Vec<_> = op
+                .glob(pattern)
+                .await?
+                .map_ok(|e| e.path().to_string())
+                .try_collect()
+                .await?;
+            paths.sort();
+            assert_eq!(paths, expected, "{pattern}");
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_glob_invalid_args() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        let res = op.glob_with("data/**").delimiter("").await;
+        assert_eq!(res.err().map(|e| e.kind()), Some(ErrorKind::InvalidInput));
+
+        Ok(())
+    }
+}
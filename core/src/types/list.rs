@@ -23,8 +23,10 @@ use std::task::Poll;
 
 use flagset::FlagSet;
 use futures::future::BoxFuture;
+use futures::stream::FuturesOrdered;
 use futures::FutureExt;
 use futures::Stream;
+use futures::StreamExt;
 
 use crate::raw::*;
 use crate::*;
@@ -32,7 +34,7 @@ use crate::*;
 /// Future constructed by listing.
 type ListFuture = BoxFuture<'static, (oio::Pager, Result<Option<Vec<oio::Entry>>>)>;
 /// Future constructed by stating.
-type StatFuture = BoxFuture<'static, (String, Result<RpStat>)>;
+type StatFuture = BoxFuture<'static, Result<Entry>>;
 
 /// Lister is designed to list entries at given path in an asynchronous
 /// manner.
@@ -44,11 +46,15 @@ pub struct Lister {
     acc: FusedAccessor,
     /// required_metakey is the metakey required by users.
     required_metakey: FlagSet<Metakey>,
+    /// concurrent is the max number of stat operations in flight.
+    concurrent: usize,
 
     buf: VecDeque<oio::Entry>,
     pager: Option<oio::Pager>,
     listing: Option<ListFuture>,
-    stating: Option<StatFuture>,
+    /// stating keeps the entries in order, entries that don't need stat
+    /// will be ready immediately.
+    stating: FuturesOrdered<StatFuture>,
 }
 
 /// # Safety
@@ -60,49 +66,70 @@ impl Lister {
     /// Create a new lister.
     pub(crate) async fn create(acc: FusedAccessor, path: &str, args: OpList) -> Result<Self> {
         let required_metakey = args.metakey();
+        let concurrent = args.concurrent();
         let (_, pager) = acc.list(path, args).await?;
 
         Ok(Self {
             acc,
             required_metakey,
+            concurrent,
 
             buf: VecDeque::new(),
             pager: Some(pager),
             listing: None,
-            stating: None,
+            stating: FuturesOrdered::new(),
         })
     }
+
+    /// Create a new lister from given pager.
+    pub(crate) fn from_pager(acc: FusedAccessor, pager: oio::Pager, args: OpList) -> Self {
+        Self {
+            acc,
+            required_metakey: args.metakey(),
+            concurrent: args.concurrent(),
+
+            buf: VecDeque::new(),
+            pager: Some(pager),
+            listing: None,
+            stating: FuturesOrdered::new(),
+        }
+    }
 }
 
 impl Stream for Lister {
     type Item = Result<Entry>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        if let Some(fut) = self.stating.as_mut() {
-            let (path, rp) = ready!(fut.poll_unpin(cx));
-
-            // Make sure we will not poll this future again.
-            self.stating = None;
-            let metadata = rp?.into_metadata();
-
-            return Poll::Ready(Some(Ok(Entry::new(path, metadata))));
-        }
+        // Fill the stating queue with entries in buffer.
+        while self.stating.len() < self.concurrent {
+            let Some(oe) = self.buf.pop_front() else {
+                break;
+            };
 
-        if let Some(oe) = self.buf.pop_front() {
             let (path, metadata) = oe.into_entry().into_parts();
             // TODO: we can optimize this by checking the provided metakey provided by services.
             if metadata.contains_metakey(self.required_metakey) {
-                return Poll::Ready(Some(Ok(Entry::new(path, metadata))));
+                // Return directly if there are no entries ahead of this one.
+                if self.stating.is_empty() {
+                    return Poll::Ready(Some(Ok(Entry::new(path, metadata))));
+                }
+
+                let entry = Entry::new(path, metadata);
+                self.stating.push_back(Box::pin(async move { Ok(entry) }));
+                continue;
             }
 
             let acc = self.acc.clone();
             let fut = async move {
-                let res = acc.stat(&path, OpStat::default()).await;
+                let rp = acc.stat(&path, OpStat::default()).await?;
 
-                (path, res)
+                Ok(Entry::new(path, rp.into_metadata()))
             };
-            self.stating = Some(Box::pin(fut));
-            return self.poll_next(cx);
+            self.stating.push_back(Box::pin(fut));
+        }
+
+        if !self.stating.is_empty() {
+            return self.stating.poll_next_unpin(cx);
         }
 
         if let Some(fut) = self.listing.as_mut() {
@@ -144,9 +171,13 @@ pub struct BlockingLister {
     acc: FusedAccessor,
     /// required_metakey is the metakey required by users.
     required_metakey: FlagSet<Metakey>,
+    /// concurrent is the max number of stat operations run in parallel.
+    concurrent: usize,
 
     pager: Option<oio::BlockingPager>,
     buf: VecDeque<oio::Entry>,
+    /// Entries that have been completed but not returned yet.
+    completed: VecDeque<Result<Entry>>,
 }
 
 /// # Safety
@@ -158,16 +189,67 @@ impl BlockingLister {
     /// Create a new lister.
     pub(crate) fn create(acc: FusedAccessor, path: &str, args: OpList) -> Result<Self> {
         let required_metakey = args.metakey();
+        let concurrent = args.concurrent();
         let (_, pager) = acc.blocking_list(path, args)?;
 
         Ok(Self {
             acc,
             required_metakey,
+            concurrent,
 
             buf: VecDeque::new(),
             pager: Some(pager),
+            completed: VecDeque::new(),
         })
     }
+
+    /// Create a new lister from given pager.
+    pub(crate) fn from_pager(acc: FusedAccessor, pager: oio::BlockingPager, args: OpList) -> Self {
+        Self {
+            acc,
+            required_metakey: args.metakey(),
+            concurrent: args.concurrent(),
+
+            buf: VecDeque::new(),
+            pager: Some(pager),
+            completed: VecDeque::new(),
+        }
+    }
+
+    /// Complete the metadata of next `concurrent` entries in buffer in parallel.
+    fn complete_in_parallel(&mut self) {
+        let size = self.buf.len().min(self.concurrent);
+        let entries: Vec<_> = self.buf.drain(..size).collect();
+
+        let acc = &self.acc;
+        let required_metakey = self.required_metakey;
+        std::thread::scope(|s| {
+            let handles: Vec<_> = entries
+                .into_iter()
+                .map(|oe| {
+                    let (path, metadata) = oe.into_entry().into_parts();
+                    if metadata.contains_metakey(required_metakey) {
+                        return Err(Entry::new(path, metadata));
+                    }
+
+                    Ok(s.spawn(move || {
+                        let rp = acc.blocking_stat(&path, OpStat::default())?;
+                        Ok(Entry::new(path, rp.into_metadata()))
+                    }))
+                })
+                .collect();
+
+            for handle in handles {
+                let res = match handle {
+                    Err(entry) => Ok(entry),
+                    Ok(handle) => handle.join().unwrap_or_else(|_| {
+                        Err(Error::new(ErrorKind::Unexpected, "stat thread panicked"))
+                    }),
+                };
+                self.completed.push_back(res);
+            }
+        });
+    }
 }
 
 /// TODO: we can implement next_chunk.
@@ -175,6 +257,15 @@ impl Iterator for BlockingLister {
     type Item = Result<Entry>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(res) = self.completed.pop_front() {
+            return Some(res);
+        }
+
+        if self.concurrent > 1 && !self.buf.is_empty() {
+            self.complete_in_parallel();
+            return self.next();
+        }
+
         if let Some(oe) = self.buf.pop_front() {
             let (path, metadata) = oe.into_entry().into_parts();
             // TODO: we can optimize this by checking the provided metakey provided by services.
@@ -214,9 +305,11 @@ impl Iterator for BlockingLister {
 mod tests {
     use futures::future;
     use futures::StreamExt;
+    use futures::TryStreamExt;
 
     use super::*;
     use crate::services::Azblob;
+    use crate::services::Memory;
 
     /// Inspired by <https://gist.github.com/kyle-mccarthy/1e6ae89cc34495d731b91ebf5eb5a3d9>
     ///
@@ -248,4 +341,38 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_list_with_concurrent_stat() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        for i in 0..10 {
+            op.write(&format!("dir/{i}"), vec![0; i]).await?;
+        }
+
+        let entries: Vec<_> = op
+            .lister_with("dir/")
+            .metakey(Metakey::ContentLength)
+            .concurrent(4)
+            .await?
+            .try_collect()
+            .await?;
+        let expected: Vec<_> = (0..10).map(|i| (format!("dir/{i}"), i as u64)).collect();
+        let actual: Vec<_> = entries
+            .iter()
+            .map(|e| (e.path().to_string(), e.metadata().content_length()))
+            .collect();
+        assert_eq!(actual, expected);
+
+        let actual: Vec<_> = op
+            .blocking()
+            .lister_with("dir/")
+            .metakey(Metakey::ContentLength)
+            .concurrent(4)
+            .call()?
+            .map(|e| e.map(|e| (e.path().to_string(), e.metadata().content_length())))
+            .collect::<Result<_>>()?;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
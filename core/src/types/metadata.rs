@@ -45,6 +45,9 @@ pub struct Metadata {
     etag: Option<String>,
     last_modified: Option<DateTime<Utc>>,
     version: Option<String>,
+    /// Storage class is set once and never mutated, store it as a boxed
+    /// str to avoid the extra capacity word of `String`.
+    storage_class: Option<Box<str>>,
 }
 
 impl Metadata {
@@ -71,6 +74,7 @@ impl Metadata {
             etag: None,
             content_disposition: None,
             version: None,
+            storage_class: None,
         }
     }
 
@@ -510,6 +514,41 @@ impl Metadata {
         self.metakey |= Metakey::Version;
         self
     }
+
+    /// Storage class of this entry.
+    ///
+    /// Storage class is the tier that this entry stored in, like `STANDARD`
+    /// and `GLACIER` in AWS S3 or `Hot` and `Archive` in Azure Blob.
+    ///
+    /// OpenDAL will return this value AS-IS.
+    ///
+    /// # Panics
+    ///
+    /// This value is only available when calling on result of `stat` or `list` with
+    /// [`Metakey::StorageClass`], otherwise it will panic.
+    pub fn storage_class(&self) -> Option<&str> {
+        debug_assert!(
+            self.metakey.contains(Metakey::StorageClass)
+                || self.metakey.contains(Metakey::Complete),
+            "visiting not set metadata: storage_class, maybe a bug"
+        );
+
+        self.storage_class.as_deref()
+    }
+
+    /// Set storage class of this entry.
+    pub fn with_storage_class(mut self, v: String) -> Self {
+        self.storage_class = Some(v.into_boxed_str());
+        self.metakey |= Metakey::StorageClass;
+        self
+    }
+
+    /// Set storage class of this entry.
+    pub fn set_storage_class(&mut self, v: &str) -> &mut Self {
+        self.storage_class = Some(v.into());
+        self.metakey |= Metakey::StorageClass;
+        self
+    }
 }
 
 flags! {
@@ -551,5 +590,7 @@ flags! {
         LastModified,
         /// Key for version.
         Version,
+        /// Key for storage class.
+        StorageClass,
     }
 }
@@ -33,10 +33,35 @@ mod writer;
 pub use writer::BlockingWriter;
 pub use writer::Writer;
 
+mod upload;
+pub use upload::Upload;
+pub use upload::UploadLister;
+pub use upload::WriteCheckpoint;
+
 mod list;
 pub use list::BlockingLister;
 pub use list::Lister;
 
+mod glob;
+
+mod walk;
+pub use walk::WalkOrder;
+
+mod read_ranges;
+
+mod remove;
+
+mod du;
+pub use du::DiskUsage;
+
+mod post_policy;
+pub use post_policy::PostConditions;
+
+mod watch;
+pub use watch::WatchEvent;
+pub use watch::WatchEventKind;
+pub use watch::Watcher;
+
 mod operator;
 pub use operator::operator_functions;
 pub use operator::operator_futures;
@@ -58,3 +83,10 @@ pub use scheme::Scheme;
 
 mod capability;
 pub use capability::Capability;
+
+mod snapshot;
+pub use snapshot::Snapshot;
+pub use snapshot::SnapshotEntry;
+
+mod transaction;
+pub use transaction::Transaction;
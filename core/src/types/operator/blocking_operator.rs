@@ -21,6 +21,10 @@ use super::operator_functions::*;
 use crate::raw::oio::BlockingRead;
 use crate::raw::oio::WriteBuf;
 use crate::raw::*;
+use crate::types::glob::GlobPager;
+use crate::types::glob::Pattern;
+use crate::types::walk::OpWalk;
+use crate::types::walk::WalkPager;
 use crate::*;
 
 /// BlockingOperator is the entry for all public blocking APIs.
@@ -90,7 +94,7 @@ pub struct BlockingOperator {
 }
 
 impl BlockingOperator {
-    pub(super) fn inner(&self) -> &FusedAccessor {
+    pub(crate) fn inner(&self) -> &FusedAccessor {
         &self.accessor
     }
 
@@ -425,6 +429,32 @@ impl BlockingOperator {
         self.write_with(path, bs).call()
     }
 
+    /// Write bytes into given path at the given offset.
+    ///
+    /// The file will be created if not exist, and the existing content outside
+    /// the written range will be kept.
+    ///
+    /// # Notes
+    ///
+    /// - Service could return `Unsupported` if `write_with_offset` is not supported.
+    /// - Only `fs`, `sftp` and `azfile` support it for now. `hdfs` only allows appending
+    ///   to files and `azblob` page blobs are not supported, so both of them will return
+    ///   `Unsupported`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io::Result;
+    /// # use opendal::BlockingOperator;
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// op.write_at("path/to/file", 4096, vec![0; 4096])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_at(&self, path: &str, offset: u64, bs: impl Into<Bytes>) -> Result<()> {
+        self.write_with(path, bs).offset(offset).call()
+    }
+
     /// Copy a file from `from` to `to`.
     ///
     /// # Notes
@@ -1139,6 +1169,141 @@ impl BlockingOperator {
             },
         ))
     }
+
+    /// List entries that match the glob pattern as an iterator.
+    ///
+    /// Read [`Operator::glob_with`] for the supported syntax.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::BlockingOperator;
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// for entry in op.glob("data/2023-*/part-*.parquet")? {
+    ///     println!("Handling file {}", entry?.path())
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn glob(&self, pattern: &str) -> Result<BlockingLister> {
+        self.glob_with(pattern).call()
+    }
+
+    /// List entries that match the glob pattern as an iterator with options.
+    ///
+    /// Read [`Operator::glob_with`] for the supported syntax.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::BlockingOperator;
+    /// use opendal::Metakey;
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// let ds = op
+    ///     .glob_with("data/**/*.parquet")
+    ///     .metakey(Metakey::ContentLength)
+    ///     .call()?;
+    /// for entry in ds {
+    ///     let entry = entry?;
+    ///     println!(
+    ///         "Handling file {} with size {}",
+    ///         entry.path(),
+    ///         entry.metadata().content_length()
+    ///     )
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn glob_with(&self, pattern: &str) -> FunctionLister {
+        FunctionLister(OperatorFunction::new(
+            self.inner().clone(),
+            pattern.to_string(),
+            OpList::default(),
+            |inner, pattern, args| {
+                let pattern = GlobPager::check_args(&args)
+                    .and_then(|_| Pattern::parse(&pattern))
+                    .map_err(|err| {
+                        err.with_operation("BlockingOperator::glob")
+                            .with_context("service", inner.info().scheme().into_static())
+                    })?;
+                let pager = GlobPager::new(inner.clone(), pattern);
+
+                Ok(BlockingLister::from_pager(inner, Box::new(pager), args))
+            },
+        ))
+    }
+
+    /// Walk all entries under the given dir recursively as an iterator.
+    ///
+    /// Read [`Operator::walk_with`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::BlockingOperator;
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// for entry in op.walk("path/to/dir/")? {
+    ///     println!("Handling entry {}", entry?.path())
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn walk(&self, path: &str) -> Result<BlockingLister> {
+        self.walk_with(path).call()
+    }
+
+    /// Walk all entries under the given dir recursively as an iterator with options.
+    ///
+    /// Read [`Operator::walk_with`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::BlockingOperator;
+    /// use opendal::WalkOrder;
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// let ds = op
+    ///     .walk_with("path/to/dir/")
+    ///     .max_depth(3)
+    ///     .order(WalkOrder::PostOrder)
+    ///     .filter(|entry| !entry.name().starts_with('.'))
+    ///     .concurrent(8)
+    ///     .call()?;
+    /// for entry in ds {
+    ///     println!("Handling entry {}", entry?.path())
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn walk_with(&self, path: &str) -> FunctionWalker {
+        let path = normalize_path(path);
+
+        FunctionWalker(OperatorFunction::new(
+            self.inner().clone(),
+            path,
+            OpWalk::default(),
+            |inner, path, args| {
+                if !validate_path(&path, EntryMode::DIR) {
+                    return Err(Error::new(
+                        ErrorKind::NotADirectory,
+                        "the path trying to walk should end with `/`",
+                    )
+                    .with_operation("BlockingOperator::walk")
+                    .with_context("service", inner.info().scheme().into_static())
+                    .with_context("path", &path));
+                }
+
+                let list = args.list_args();
+                let pager = WalkPager::new(inner.clone(), &path, args);
+
+                Ok(BlockingLister::from_pager(inner, Box::new(pager), list))
+            },
+        ))
+    }
 }
 
 impl From<BlockingOperator> for Operator {
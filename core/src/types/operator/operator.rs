@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
+use std::ops::Range;
 use std::time::Duration;
 
 use bytes::Buf;
@@ -28,6 +30,18 @@ use super::BlockingOperator;
 use crate::operator_futures::*;
 use crate::raw::oio::{ReadExt, WriteExt};
 use crate::raw::*;
+use crate::types::du;
+use crate::types::glob::GlobPager;
+use crate::types::glob::Pattern;
+use crate::types::read_ranges::read_ranges;
+use crate::types::read_ranges::OpReadRanges;
+use crate::types::remove::remove;
+use crate::types::remove::OpRemove;
+use crate::types::snapshot;
+use crate::types::transaction;
+use crate::types::walk::OpWalk;
+use crate::types::walk::WalkPager;
+use crate::types::watch::PollWatcher;
 use crate::*;
 
 /// Operator is the entry for all public async APIs.
@@ -72,7 +86,7 @@ pub struct Operator {
 
 /// # Operator basic API.
 impl Operator {
-    pub(super) fn inner(&self) -> &FusedAccessor {
+    pub(crate) fn inner(&self) -> &FusedAccessor {
         &self.accessor
     }
 
@@ -85,7 +99,7 @@ impl Operator {
         Self { accessor, limit }
     }
 
-    pub(super) fn into_inner(self) -> FusedAccessor {
+    pub(crate) fn into_inner(self) -> FusedAccessor {
         self.accessor
     }
 
@@ -265,6 +279,75 @@ impl Operator {
         }
     }
 
+    /// Get metadata of the given paths.
+    ///
+    /// Results are returned in the same order as `paths`, each path with its
+    /// own result, so a missing path won't fail the whole call.
+    ///
+    /// # Notes
+    ///
+    /// If underlying services support stat in batch, we will use batch
+    /// stat instead. Otherwise, paths will be stated concurrently with the
+    /// operator's limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let results = op
+    ///     .stat_many(vec!["abc".to_string(), "def".to_string()])
+    ///     .await?;
+    /// for (path, result) in results {
+    ///     match result {
+    ///         Ok(meta) => println!("{path}: {}", meta.content_length()),
+    ///         Err(err) => println!("{path}: {err}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stat_many(&self, paths: Vec<String>) -> Result<Vec<(String, Result<Metadata>)>> {
+        if !self.info().full_capability().batch_stat {
+            let results = stream::iter(paths)
+                .map(|path| async move {
+                    let result = self.stat(&path).await;
+                    (path, result)
+                })
+                .buffered(self.limit())
+                .collect()
+                .await;
+
+            return Ok(results);
+        }
+
+        let mut results = Vec::with_capacity(paths.len());
+        for chunk in paths.chunks(self.limit()) {
+            let ops = chunk
+                .iter()
+                .map(|path| (normalize_path(path), OpStat::new().into()))
+                .collect();
+
+            let replies = self.inner().batch(OpBatch::new(ops)).await?.into_results();
+
+            for (path, (_, result)) in chunk.iter().zip(replies) {
+                let result = result.and_then(|reply| match reply {
+                    BatchedReply::Stat(rp) => Ok(rp.into_metadata()),
+                    _ => Err(Error::new(
+                        ErrorKind::Unexpected,
+                        "batch stat returns unexpected reply",
+                    )),
+                });
+                results.push((path.clone(), result));
+            }
+        }
+
+        Ok(results)
+    }
+
     /// Create a dir at given path.
     ///
     /// # Notes
@@ -380,6 +463,86 @@ impl Operator {
         fut
     }
 
+    /// Read multiple ranges of the path at once.
+    ///
+    /// Returns the bytes of every range in the same order as input. Bytes could be
+    /// shorter than the range if it exceeds the end of file.
+    ///
+    /// Read [`Operator::read_ranges_with`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let bs = op
+    ///     .read_ranges("path/to/file", vec![0..1024, 4096..8192])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_ranges(&self, path: &str, ranges: Vec<Range<u64>>) -> Result<Vec<Bytes>> {
+        self.read_ranges_with(path, ranges).await
+    }
+
+    /// Read multiple ranges of the path at once with extra options.
+    ///
+    /// Ranges whose gap is no more than `gap` will be merged into one read, and
+    /// merged reads will be sent concurrently.
+    ///
+    /// # Notes
+    ///
+    /// Every merged read is a normal `read` with a single range. HTTP multi-range
+    /// requests (`multipart/byteranges`) are not used: object storage services like
+    /// s3, gcs and azblob ignore them, and the parts of a multipart response can't
+    /// be returned through the `read` of underlying services.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let bs = op
+    ///     .read_ranges_with("path/to/file", vec![0..1024, 4096..8192])
+    ///     .gap(64 * 1024)
+    ///     .concurrent(4)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_ranges_with(&self, path: &str, ranges: Vec<Range<u64>>) -> FutureReadRanges {
+        let path = normalize_path(path);
+
+        let fut = FutureReadRanges(OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpReadRanges::new(ranges),
+            |inner, path, args| {
+                let fut = async move {
+                    if !validate_path(&path, EntryMode::FILE) {
+                        return Err(Error::new(
+                            ErrorKind::IsADirectory,
+                            "read path is a directory",
+                        )
+                        .with_operation("Operator::read_ranges")
+                        .with_context("service", inner.info().scheme())
+                        .with_context("path", &path));
+                    }
+
+                    read_ranges(inner, &path, args).await
+                };
+
+                Box::pin(fut)
+            },
+        ));
+
+        fut
+    }
+
     /// Create a new reader which can read the whole path.
     ///
     /// # Examples
@@ -468,6 +631,33 @@ impl Operator {
         self.write_with(path, bs).await
     }
 
+    /// Write bytes into path at the given offset.
+    ///
+    /// The file will be created if not exist, and the existing content outside
+    /// the written range will be kept.
+    ///
+    /// # Notes
+    ///
+    /// - Service could return `Unsupported` if `write_with_offset` is not supported.
+    /// - Only `fs`, `sftp` and `azfile` support it for now. `hdfs` only allows appending
+    ///   to files and `azblob` page blobs are not supported, so both of them will return
+    ///   `Unsupported`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.write_at("path/to/file", 4096, vec![0; 4096]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_at(&self, path: &str, offset: u64, bs: impl Into<Bytes>) -> Result<()> {
+        self.write_with(path, bs).offset(offset).await
+    }
+
     /// Copy a file from `from` to `to`.
     ///
     /// # Notes
@@ -491,8 +681,107 @@ impl Operator {
     /// ```
     pub async fn copy(&self, from: &str, to: &str) -> Result<()> {
         let from = normalize_path(from);
+        let to = normalize_path(to);
 
-        if !validate_path(&from, EntryMode::FILE) {
+        self.validate_copy(&from, &to)?;
+
+        self.inner().copy(&from, &to, OpCopy::new()).await?;
+
+        Ok(())
+    }
+
+    /// Copy files in pairs of `(from, to)`.
+    ///
+    /// Results are returned in the same order as `pairs`, keyed by the
+    /// `from` path, so a failed copy won't fail the whole call.
+    ///
+    /// # Notes
+    ///
+    /// The same rules of [`Operator::copy`] apply to every pair.
+    ///
+    /// If underlying services support copy in batch, we will use batch
+    /// copy instead. Otherwise, files will be copied concurrently with the
+    /// operator's limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let results = op
+    ///     .copy_many(vec![("abc".to_string(), "abc.bak".to_string())])
+    ///     .await?;
+    /// for (_, result) in results {
+    ///     result?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn copy_many(
+        &self,
+        pairs: Vec<(String, String)>,
+    ) -> Result<Vec<(String, Result<()>)>> {
+        if !self.info().full_capability().batch_copy {
+            let results = stream::iter(pairs)
+                .map(|(from, to)| async move {
+                    let result = self.copy(&from, &to).await;
+                    (from, result)
+                })
+                .buffered(self.limit())
+                .collect()
+                .await;
+
+            return Ok(results);
+        }
+
+        let mut results = Vec::with_capacity(pairs.len());
+        for chunk in pairs.chunks(self.limit()) {
+            // Invalid pairs are answered directly without sending them out.
+            let mut slots = Vec::with_capacity(chunk.len());
+            let mut ops = Vec::with_capacity(chunk.len());
+            for (from, to) in chunk {
+                let (from, to) = (normalize_path(from), normalize_path(to));
+                match self.validate_copy(&from, &to) {
+                    Ok(()) => {
+                        slots.push(None);
+                        ops.push((from, BatchOperation::Copy(to, OpCopy::new())));
+                    }
+                    Err(err) => slots.push(Some(Err(err))),
+                }
+            }
+
+            let mut replies = if ops.is_empty() {
+                Vec::new()
+            } else {
+                self.inner().batch(OpBatch::new(ops)).await?.into_results()
+            }
+            .into_iter();
+
+            for ((from, _), slot) in chunk.iter().zip(slots) {
+                let result = match slot {
+                    Some(result) => result,
+                    None => match replies.next() {
+                        Some((_, Ok(BatchedReply::Copy(_)))) => Ok(()),
+                        Some((_, Err(err))) => Err(err),
+                        _ => Err(Error::new(
+                            ErrorKind::Unexpected,
+                            "batch copy returns unexpected reply",
+                        )),
+                    },
+                };
+                results.push((from.clone(), result));
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Check the normalized `from` and `to` of a copy.
+    fn validate_copy(&self, from: &str, to: &str) -> Result<()> {
+        if !validate_path(from, EntryMode::FILE) {
             return Err(
                 Error::new(ErrorKind::IsADirectory, "from path is a directory")
                     .with_operation("Operator::copy")
@@ -501,9 +790,7 @@ impl Operator {
             );
         }
 
-        let to = normalize_path(to);
-
-        if !validate_path(&to, EntryMode::FILE) {
+        if !validate_path(to, EntryMode::FILE) {
             return Err(
                 Error::new(ErrorKind::IsADirectory, "to path is a directory")
                     .with_operation("Operator::copy")
@@ -522,8 +809,6 @@ impl Operator {
             );
         }
 
-        self.inner().copy(&from, &to, OpCopy::new()).await?;
-
         Ok(())
     }
 
@@ -750,6 +1035,7 @@ impl Operator {
     /// # Notes
     ///
     /// - Deleting a file that does not exist won't return errors.
+    /// - Use `recursive(true)` to delete a dir along with all entries inside it.
     ///
     /// # Examples
     ///
@@ -761,6 +1047,14 @@ impl Operator {
     /// # #[tokio::main]
     /// # async fn test(op: Operator) -> Result<()> {
     /// op.delete_with("test").await?;
+    /// op.delete_with("path/to/dir/")
+    ///     .recursive(true)
+    ///     .progress(|path, result| {
+    ///         if let Err(err) = result {
+    ///             println!("failed to delete {path}: {err}")
+    ///         }
+    ///     })
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -770,9 +1064,13 @@ impl Operator {
         let fut = FutureDelete(OperatorFuture::new(
             self.inner().clone(),
             path,
-            OpDelete::default(),
-            |inner, path, args| {
+            (OpDelete::default(), OpRemove::new(self.limit())),
+            |inner, path, (args, opts)| {
                 let fut = async move {
+                    if args.recursive() {
+                        return remove(inner, path, args, opts).await;
+                    }
+
                     let _ = inner.delete(&path, args).await?;
                     Ok(())
                 };
@@ -835,7 +1133,8 @@ impl Operator {
     /// # }
     /// ```
     pub async fn remove_via(&self, input: impl Stream<Item = String> + Unpin) -> Result<()> {
-        if self.info().full_capability().batch {
+        let cap = self.info().full_capability();
+        if cap.batch && cap.batch_delete {
             let mut input = input
                 .map(|v| (v, OpDelete::default().into()))
                 .chunks(self.limit());
@@ -869,8 +1168,11 @@ impl Operator {
     ///
     /// # Notes
     ///
-    /// If underlying services support delete in batch, we will use batch
-    /// delete instead.
+    /// If underlying services support recursive delete, we will use it
+    /// directly. Otherwise, if they support delete in batch, we will use
+    /// batch delete instead.
+    ///
+    /// This is a shortcut of `delete_with(path).recursive(true)`.
     ///
     /// # Examples
     ///
@@ -886,53 +1188,7 @@ impl Operator {
     /// # }
     /// ```
     pub async fn remove_all(&self, path: &str) -> Result<()> {
-        let meta = match self.stat(path).await {
-            // If object exists.
-            Ok(metadata) => metadata,
-
-            // If object not found, return success.
-            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
-
-            // Pass on any other error.
-            Err(e) => return Err(e),
-        };
-
-        if meta.mode() != EntryMode::DIR {
-            return self.delete(path).await;
-        }
-
-        let obs = self.lister_with(path).delimiter("").await?;
-
-        if self.info().full_capability().batch {
-            let mut obs = obs.try_chunks(self.limit());
-
-            while let Some(batches) = obs.next().await {
-                let batches = batches
-                    .map_err(|err| err.1)?
-                    .into_iter()
-                    .map(|v| (v.path().to_string(), OpDelete::default().into()))
-                    .collect();
-
-                let results = self
-                    .inner()
-                    .batch(OpBatch::new(batches))
-                    .await?
-                    .into_results();
-
-                // TODO: return error here directly seems not a good idea?
-                for (_, result) in results {
-                    let _ = result?;
-                }
-            }
-        } else {
-            obs.try_for_each(|v| async move { self.delete(v.path()).await })
-                .await?;
-        }
-
-        // Remove the directory itself.
-        self.delete(path).await?;
-
-        Ok(())
+        self.delete_with(path).recursive(true).await
     }
 
     /// List entries within a given directory.
@@ -1270,86 +1526,487 @@ impl Operator {
         ));
         fut
     }
-}
 
-/// Operator presign API.
-impl Operator {
-    /// Presign an operation for stat(head).
+    /// List entries that match the glob pattern as a stream.
     ///
-    /// # Example
+    /// Read [`Operator::glob_with`] for the supported syntax.
+    ///
+    /// # Examples
     ///
     /// ```no_run
-    /// use anyhow::Result;
-    /// use futures::io;
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
     /// use opendal::Operator;
-    /// use std::time::Duration;
-    ///
-    /// #[tokio::main]
-    /// async fn test(op: Operator) -> Result<()> {
-    ///     let signed_req = op.presign_stat("test",Duration::from_secs(3600)).await?;
-    ///     let req = http::Request::builder()
-    ///         .method(signed_req.method())
-    ///         .uri(signed_req.uri())
-    ///         .body(())?;
-    ///
-    /// #    Ok(())
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut ds = op.glob("data/2023-*/part-*.parquet").await?;
+    /// while let Some(entry) = ds.try_next().await? {
+    ///     println!("Handling file {}", entry.path())
+    /// }
+    /// # Ok(())
     /// # }
     /// ```
-    pub async fn presign_stat(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
-        let path = normalize_path(path);
-
-        let op = OpPresign::new(OpStat::new(), expire);
-
-        let rp = self.inner().presign(&path, op).await?;
-        Ok(rp.into_presigned_request())
+    pub async fn glob(&self, pattern: &str) -> Result<Lister> {
+        self.glob_with(pattern).await
     }
 
-    /// Presign an operation for read.
-    ///
-    /// # Example
-    ///
-    /// ```no_run
-    /// use anyhow::Result;
-    /// use futures::io;
-    /// use opendal::Operator;
-    /// use std::time::Duration;
+    /// List entries that match the glob pattern as a stream with options.
     ///
-    /// #[tokio::main]
-    /// async fn test(op: Operator) -> Result<()> {
-    ///     let signed_req = op.presign_read("test.txt", Duration::from_secs(3600)).await?;
-    /// #    Ok(())
-    /// # }
-    /// ```
+    /// # Syntax
     ///
-    /// - `signed_req.method()`: `GET`
-    /// - `signed_req.uri()`: `https://s3.amazonaws.com/examplebucket/test.txt?X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential=access_key_id/20130721/us-east-1/s3/aws4_request&X-Amz-Date=20130721T201207Z&X-Amz-Expires=86400&X-Amz-SignedHeaders=host&X-Amz-Signature=<signature-value>`
-    /// - `signed_req.headers()`: `{ "host": "s3.amazonaws.com" }`
+    /// - `?` matches any single char except `/`.
+    /// - `*` matches any sequence of chars except `/`.
+    /// - `**` as a whole segment matches any number of dirs.
+    /// - `[abc]`, `[a-z]` and `[!a-z]` match a single char in (or not in) the set.
+    /// - `\` escapes the next char.
     ///
-    /// We can download this file via `curl` or other tools without credentials:
+    /// # Notes
     ///
-    /// ```shell
-    /// curl "https://s3.amazonaws.com/examplebucket/test.txt?X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential=access_key_id/20130721/us-east-1/s3/aws4_request&X-Amz-Date=20130721T201207Z&X-Amz-Expires=86400&X-Amz-SignedHeaders=host&X-Amz-Signature=<signature-value>" -O /tmp/test.txt
-    /// ```
-    pub async fn presign_read(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
-        let path = normalize_path(path);
-
-        let op = OpPresign::new(OpRead::new(), expire);
-
-        let rp = self.inner().presign(&path, op).await?;
-        Ok(rp.into_presigned_request())
-    }
-
-    /// Presign an operation for read option described in OpenDAL [RFC-1735][`crate::docs::rfcs::rfc_1735_operation_extension`].
+    /// Listing starts from the longest literal dir of the pattern. Dirs are
+    /// listed one level at a time and dirs that can't match are skipped. Once
+    /// a `**` segment is reached, the dir will be listed recursively.
     ///
-    /// You can pass `OpRead` to this method to specify the content disposition.
+    /// Only `metakey` and `concurrent` are supported for glob, other options
+    /// will return `InvalidInput` error. The order of entries is not
+    /// guaranteed.
     ///
-    /// # Example
+    /// # Examples
     ///
     /// ```no_run
-    /// use anyhow::Result;
-    /// use futures::io;
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use opendal::Metakey;
     /// use opendal::Operator;
-    /// use std::time::Duration;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut ds = op
+    ///     .glob_with("data/**/*.parquet")
+    ///     .metakey(Metakey::ContentLength)
+    ///     .await?;
+    /// while let Some(entry) = ds.try_next().await? {
+    ///     println!(
+    ///         "Handling file {} with size {}",
+    ///         entry.path(),
+    ///         entry.metadata().content_length()
+    ///     )
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn glob_with(&self, pattern: &str) -> FutureLister {
+        let fut = FutureLister(OperatorFuture::new(
+            self.inner().clone(),
+            pattern.to_string(),
+            OpList::default(),
+            |inner, pattern, args| {
+                let fut = async move {
+                    let pattern = GlobPager::check_args(&args)
+                        .and_then(|_| Pattern::parse(&pattern))
+                        .map_err(|err| {
+                            err.with_operation("Operator::glob")
+                                .with_context("service", inner.info().scheme().into_static())
+                        })?;
+                    let pager = GlobPager::new(inner.clone(), pattern);
+
+                    Ok(Lister::from_pager(inner, Box::new(pager), args))
+                };
+                Box::pin(fut)
+            },
+        ));
+        fut
+    }
+
+    /// Walk all entries under the given dir recursively as a stream.
+    ///
+    /// Read [`Operator::walk_with`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut ds = op.walk("path/to/dir/").await?;
+    /// while let Some(entry) = ds.try_next().await? {
+    ///     println!("Handling entry {}", entry.path())
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn walk(&self, path: &str) -> Result<Lister> {
+        self.walk_with(path).await
+    }
+
+    /// Walk all entries under the given dir recursively as a stream with options.
+    ///
+    /// # Notes
+    ///
+    /// Unlike listing with an empty delimiter, every dir is listed with `/`
+    /// as delimiter, so that subtrees can be skipped by `filter` and
+    /// `max_depth`, and sibling dirs can be listed concurrently by setting
+    /// `concurrent`.
+    ///
+    /// The given dir itself won't be yielded. Dirs are guaranteed to be
+    /// yielded before (or after in [`WalkOrder::PostOrder`]) all entries
+    /// inside them, but the order between siblings is not guaranteed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use opendal::Operator;
+    /// use opendal::WalkOrder;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut ds = op
+    ///     .walk_with("path/to/dir/")
+    ///     .max_depth(3)
+    ///     .order(WalkOrder::PostOrder)
+    ///     .filter(|entry| !entry.name().starts_with('.'))
+    ///     .concurrent(8)
+    ///     .await?;
+    /// while let Some(entry) = ds.try_next().await? {
+    ///     println!("Handling entry {}", entry.path())
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn walk_with(&self, path: &str) -> FutureWalker {
+        let path = normalize_path(path);
+
+        let fut = FutureWalker(OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpWalk::default(),
+            |inner, path, args| {
+                let fut = async move {
+                    if !validate_path(&path, EntryMode::DIR) {
+                        return Err(Error::new(
+                            ErrorKind::NotADirectory,
+                            "the path trying to walk should end with `/`",
+                        )
+                        .with_operation("Operator::walk")
+                        .with_context("service", inner.info().scheme().into_static())
+                        .with_context("path", &path));
+                    }
+
+                    let list = args.list_args();
+                    let pager = WalkPager::new(inner.clone(), &path, args);
+
+                    Ok(Lister::from_pager(inner, Box::new(pager), list))
+                };
+                Box::pin(fut)
+            },
+        ));
+        fut
+    }
+}
+
+/// Operator watch API.
+impl Operator {
+    /// Watch the changes of files under given dir as a stream.
+    ///
+    /// Read [`Operator::watch_with`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut watcher = op.watch("path/to/dir/").await?;
+    /// while let Some(event) = watcher.try_next().await? {
+    ///     println!("{:?} {}", event.kind(), event.path())
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn watch(&self, path: &str) -> Result<Watcher> {
+        self.watch_with(path).await
+    }
+
+    /// Watch the changes of files under given dir as a stream with options.
+    ///
+    /// # Notes
+    ///
+    /// Services that support watch natively (like `etcd`, `redis`, and `fs`
+    /// with feature `services-fs-watch` enabled) will push changes as soon as
+    /// they happen. For other services (like `tikv` which doesn't provide
+    /// change feeds), the dir will be listed every `poll_interval`, and
+    /// changes are found by comparing `etag`, `last_modified` and
+    /// `content_length` of files.
+    ///
+    /// Only changes happened after this future is ready will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use std::time::Duration;
+    ///
+    /// use futures::TryStreamExt;
+    /// use opendal::Operator;
+    /// use opendal::WatchEventKind;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut watcher = op
+    ///     .watch_with("path/to/dir/")
+    ///     .poll_interval(Duration::from_secs(30))
+    ///     .await?;
+    /// while let Some(event) = watcher.try_next().await? {
+    ///     if event.kind() == WatchEventKind::Create {
+    ///         println!("new file landed: {}", event.path())
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch_with(&self, path: &str) -> FutureWatcher {
+        let path = normalize_path(path);
+
+        let fut = FutureWatcher(OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpWatch::default(),
+            |inner, path, args| {
+                let fut = async move {
+                    if !validate_path(&path, EntryMode::DIR) {
+                        return Err(Error::new(
+                            ErrorKind::NotADirectory,
+                            "the path trying to watch should end with `/`",
+                        )
+                        .with_operation("Operator::watch")
+                        .with_context("service", inner.info().scheme().into_static())
+                        .with_context("path", &path));
+                    }
+
+                    if inner.info().full_capability().watch {
+                        let (_, w) = inner.watch(&path, args).await?;
+                        return Ok(Watcher::new(w));
+                    }
+
+                    let w = PollWatcher::new(inner, &path, args).await?;
+                    Ok(Watcher::new(Box::new(w)))
+                };
+                Box::pin(fut)
+            },
+        ));
+        fut
+    }
+}
+
+/// Operator summary API.
+impl Operator {
+    /// Summarize the total size and number of files under given path,
+    /// along with the breakdown of entries directly inside it.
+    ///
+    /// # Notes
+    ///
+    /// Services with native aggregate endpoints (like WebHDFS) will use them
+    /// to summarize dirs. Otherwise, dirs will be listed recursively in one
+    /// listing if the service supports listing without delimiter, or walked
+    /// in parallel, both with [`Metakey::ContentLength`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let usage = op.du("path/to/dir/").await?;
+    /// println!("{} bytes in {} files", usage.content_length(), usage.file_count());
+    /// for child in usage.children() {
+    ///     println!("{}: {} bytes", child.path(), child.content_length());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn du(&self, path: &str) -> Result<DiskUsage> {
+        du::du(self, path).await
+    }
+}
+
+/// Operator tagging and archive API.
+impl Operator {
+    /// Get the tags of given file.
+    ///
+    /// Tags are key/value pairs stored separately from the metadata of file,
+    /// which are mostly used by lifecycle rules and cost allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let tags = op.tags("path/to/file").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn tags(&self, path: &str) -> Result<HashMap<String, String>> {
+        let path = normalize_path(path);
+        self.check_file_path(&path, "Operator::tags")?;
+
+        let rp = self.inner().get_tags(&path, OpGetTags::new()).await?;
+        Ok(rp.into_tags())
+    }
+
+    /// Replace the tags of given file.
+    ///
+    /// All existing tags of the file will be replaced by given tags, pass
+    /// an empty map to remove all tags.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let tags = HashMap::from([("project".to_string(), "opendal".to_string())]);
+    /// op.set_tags("path/to/file", tags).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_tags(&self, path: &str, tags: HashMap<String, String>) -> Result<()> {
+        let path = normalize_path(path);
+        self.check_file_path(&path, "Operator::set_tags")?;
+
+        self.inner().set_tags(&path, OpSetTags::new(tags)).await?;
+        Ok(())
+    }
+
+    /// Restore an archived file, so that it could be read again.
+    ///
+    /// The restored copy will be kept for given days for services like
+    /// AWS S3 Glacier, OSS and COS archive.
+    ///
+    /// # Notes
+    ///
+    /// Restoring is asynchronous at service side, it could take hours
+    /// before the file is readable.
+    ///
+    /// `days` has no effect on services that restore files by changing their
+    /// storage class permanently. For example, Azblob rehydrates archived
+    /// blobs to the `Hot` tier, and returns an error for blobs that are not
+    /// archived.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.restore("path/to/archived/file", 7).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn restore(&self, path: &str, days: u32) -> Result<()> {
+        let path = normalize_path(path);
+        self.check_file_path(&path, "Operator::restore")?;
+
+        self.inner().restore(&path, OpRestore::new(days)).await?;
+        Ok(())
+    }
+
+    fn check_file_path(&self, path: &str, operation: &'static str) -> Result<()> {
+        if !validate_path(path, EntryMode::FILE) {
+            return Err(Error::new(ErrorKind::IsADirectory, "path is a directory")
+                .with_operation(operation)
+                .with_context("service", self.info().scheme().into_static())
+                .with_context("path", path));
+        }
+        Ok(())
+    }
+}
+
+/// Operator presign API.
+impl Operator {
+    /// Presign an operation for stat(head).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use futures::io;
+    /// use opendal::Operator;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op.presign_stat("test",Duration::from_secs(3600)).await?;
+    ///     let req = http::Request::builder()
+    ///         .method(signed_req.method())
+    ///         .uri(signed_req.uri())
+    ///         .body(())?;
+    ///
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn presign_stat(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
+        let path = normalize_path(path);
+
+        let op = OpPresign::new(OpStat::new(), expire);
+
+        let rp = self.inner().presign(&path, op).await?;
+        Ok(rp.into_presigned_request())
+    }
+
+    /// Presign an operation for read.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use futures::io;
+    /// use opendal::Operator;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op.presign_read("test.txt", Duration::from_secs(3600)).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    ///
+    /// - `signed_req.method()`: `GET`
+    /// - `signed_req.uri()`: `https://s3.amazonaws.com/examplebucket/test.txt?X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential=access_key_id/20130721/us-east-1/s3/aws4_request&X-Amz-Date=20130721T201207Z&X-Amz-Expires=86400&X-Amz-SignedHeaders=host&X-Amz-Signature=<signature-value>`
+    /// - `signed_req.headers()`: `{ "host": "s3.amazonaws.com" }`
+    ///
+    /// We can download this file via `curl` or other tools without credentials:
+    ///
+    /// ```shell
+    /// curl "https://s3.amazonaws.com/examplebucket/test.txt?X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential=access_key_id/20130721/us-east-1/s3/aws4_request&X-Amz-Date=20130721T201207Z&X-Amz-Expires=86400&X-Amz-SignedHeaders=host&X-Amz-Signature=<signature-value>" -O /tmp/test.txt
+    /// ```
+    pub async fn presign_read(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
+        let path = normalize_path(path);
+
+        let op = OpPresign::new(OpRead::new(), expire);
+
+        let rp = self.inner().presign(&path, op).await?;
+        Ok(rp.into_presigned_request())
+    }
+
+    /// Presign an operation for read option described in OpenDAL [RFC-1735][`crate::docs::rfcs::rfc_1735_operation_extension`].
+    ///
+    /// You can pass `OpRead` to this method to specify the content disposition.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use futures::io;
+    /// use opendal::Operator;
+    /// use std::time::Duration;
     ///
     /// #[tokio::main]
     /// async fn test(op: Operator) -> Result<()> {
@@ -1451,4 +2108,345 @@ impl Operator {
         ));
         fut
     }
+
+    /// Initiate a multipart upload and presign requests for each part.
+    ///
+    /// This allows clients like browsers to upload large files in parts
+    /// directly, and retry parts independently.
+    ///
+    /// # Notes
+    ///
+    /// - Requests for parts must be sent with body, and the etag in the
+    ///   response must be collected for completion.
+    /// - The upload must be completed via [`Operator::complete_multipart`]
+    ///   with etags of all parts in order.
+    /// - Services have limits on part size and number, for example, all
+    ///   parts except the last one must be at least 5 MiB on S3.
+    /// - `parts` must be between 1 and 10000, otherwise `InvalidInput` will
+    ///   be returned before initiating the upload.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let upload = op
+    ///         .presign_multipart("test", 3, Duration::from_secs(3600))
+    ///         .await?;
+    ///
+    ///     let mut etags = vec![];
+    ///     for req in upload.parts() {
+    ///         // Send `req` with the part's data, and read etag from response.
+    ///         # let etag = req.uri().to_string();
+    ///         etags.push(etag);
+    ///     }
+    ///
+    ///     op.complete_multipart("test", upload.upload_id(), etags)
+    ///         .await?;
+    /// #    Ok(())
+    /// }
+    /// ```
+    pub async fn presign_multipart(
+        &self,
+        path: &str,
+        parts: usize,
+        expire: Duration,
+    ) -> Result<PresignedMultipartUpload> {
+        let path = normalize_path(path);
+
+        // S3 and compatible services only allow part numbers in [1..=10000].
+        if parts == 0 || parts > 10000 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "the number of parts must be between 1 and 10000",
+            )
+            .with_operation("Operator::presign_multipart")
+            .with_context("service", self.inner().info().scheme())
+            .with_context("path", &path)
+            .with_context("parts", parts.to_string()));
+        }
+
+        let rp = self
+            .inner()
+            .initiate_multipart(&path, OpInitiateMultipart::new())
+            .await?;
+
+        let mut reqs = Vec::with_capacity(parts);
+        for part_number in 0..parts {
+            let op = OpPresign::new(OpWritePart::new(rp.upload_id(), part_number), expire);
+            match self.inner().presign(&path, op).await {
+                Ok(rp) => reqs.push(rp.into_presigned_request()),
+                Err(err) => {
+                    // Abort the initiated upload to avoid leaving it behind, the
+                    // presign error is returned since it's the root cause.
+                    let _ = self
+                        .inner()
+                        .abort_upload(&path, OpAbortUpload::new(rp.upload_id()))
+                        .await;
+                    return Err(err);
+                }
+            }
+        }
+
+        Ok(PresignedMultipartUpload::new(rp.upload_id(), reqs))
+    }
+
+    /// Complete a multipart upload initiated by [`Operator::presign_multipart`].
+    ///
+    /// `etags` are the etags returned by each part upload in order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// #[tokio::main]
+    /// async fn test(op: Operator, upload_id: &str, etags: Vec<String>) -> Result<()> {
+    ///     op.complete_multipart("test", upload_id, etags).await?;
+    /// #    Ok(())
+    /// }
+    /// ```
+    pub async fn complete_multipart(
+        &self,
+        path: &str,
+        upload_id: &str,
+        etags: Vec<String>,
+    ) -> Result<()> {
+        let path = normalize_path(path);
+
+        self.inner()
+            .complete_multipart(&path, OpCompleteMultipart::new(upload_id, etags))
+            .await?;
+
+        Ok(())
+    }
+
+    /// List all incomplete uploads whose path starts with `prefix`.
+    ///
+    /// Incomplete uploads are left behind by writers that are neither
+    /// closed nor aborted, they are still billed by most services.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use opendal::Operator;
+    ///
+    /// #[tokio::main]
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let mut uploads = op.list_uploads("path/to/dir/").await?;
+    ///     while let Some(upload) = uploads.try_next().await? {
+    ///         op.abort_upload(upload.path(), upload.upload_id()).await?;
+    ///     }
+    /// #    Ok(())
+    /// }
+    /// ```
+    pub async fn list_uploads(&self, prefix: &str) -> Result<UploadLister> {
+        let prefix = normalize_path(prefix);
+
+        let (_, pager) = self
+            .inner()
+            .list_uploads(&prefix, OpListUploads::new())
+            .await?;
+
+        Ok(UploadLister::new(pager))
+    }
+
+    /// Abort an incomplete upload and purge all uploaded data.
+    ///
+    /// The upload id could come from [`Operator::list_uploads`] or
+    /// [`Writer::checkpoint`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// #[tokio::main]
+    /// async fn test(op: Operator, upload_id: &str) -> Result<()> {
+    ///     op.abort_upload("test", upload_id).await?;
+    /// #    Ok(())
+    /// }
+    /// ```
+    pub async fn abort_upload(&self, path: &str, upload_id: &str) -> Result<()> {
+        let path = normalize_path(path);
+
+        self.inner()
+            .abort_upload(&path, OpAbortUpload::new(upload_id))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Presign a POST policy for browser form uploads under `prefix`.
+    ///
+    /// Uploaded keys must start with `prefix`, and the `conditions` like
+    /// content length range will be enforced by services.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    /// use opendal::PostConditions;
+    ///
+    /// #[tokio::main]
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let conditions = PostConditions::new().with_content_length_range(1, 10 * 1024 * 1024);
+    ///     let post = op
+    ///         .presign_post("uploads/", conditions, Duration::from_secs(3600))
+    ///         .await?;
+    ///
+    ///     // Post a multipart form to `post.uri()` with all fields and
+    ///     // the file content as the last field named `file`.
+    ///     for (k, v) in post.fields() {
+    ///         println!("{k}: {v}");
+    ///     }
+    /// #    Ok(())
+    /// }
+    /// ```
+    pub async fn presign_post(
+        &self,
+        prefix: &str,
+        conditions: PostConditions,
+        expire: Duration,
+    ) -> Result<PresignedPost> {
+        let prefix = normalize_path(prefix);
+
+        let rp = self
+            .inner()
+            .presign_post(&prefix, OpPresignPost::new(conditions, expire))
+            .await?;
+
+        Ok(rp.into_presigned_post())
+    }
+}
+
+/// Operator snapshot API.
+impl Operator {
+    /// Capture an immutable snapshot of all files under given prefix.
+    ///
+    /// # Notes
+    ///
+    /// - Files will be captured by version if underlying services support
+    ///   read with version, otherwise their contents will be copied.
+    /// - The manifest and copied contents are stored under
+    ///   `.opendal_snapshots/<id>/` via this operator.
+    /// - Files changed during capturing could be captured with either old
+    ///   or new content.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let snapshot = op.snapshot("path/to/dataset/").await?;
+    /// println!("snapshot {} created", snapshot.id());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn snapshot(&self, prefix: &str) -> Result<Snapshot> {
+        snapshot::create(self, prefix).await
+    }
+
+    /// Open a read-only operator which serves exactly the files in given
+    /// snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let view = op.open_snapshot("<snapshot id>").await?;
+    /// let bs = view.read("path/to/dataset/file").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn open_snapshot(&self, id: &str) -> Result<Operator> {
+        snapshot::open(self, id).await
+    }
+
+    /// Delete the snapshot and all contents copied by it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.delete_snapshot("<snapshot id>").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_snapshot(&self, id: &str) -> Result<()> {
+        snapshot::delete(self, id).await
+    }
+}
+
+/// Operator transaction API.
+impl Operator {
+    /// Start a new transaction to write and delete files together.
+    ///
+    /// Read [`Transaction`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut tx = op.transaction().await?;
+    /// tx.write("table/data/part-1", "data").await?;
+    /// tx.write("table/metadata", "v2").await?;
+    /// tx.delete("table/data/part-0")?;
+    /// tx.commit().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn transaction(&self) -> Result<Transaction> {
+        Transaction::begin(self.clone()).await
+    }
+
+    /// Finish all committed transactions that were interrupted, returns the
+    /// number of recovered transactions.
+    ///
+    /// # Notes
+    ///
+    /// Transactions that haven't been committed will not be touched, abort
+    /// them via [`Transaction::abort`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let recovered = op.recover_transactions().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn recover_transactions(&self) -> Result<usize> {
+        transaction::recover(self).await
+    }
 }
@@ -25,6 +25,7 @@ use bytes::Bytes;
 use flagset::FlagSet;
 
 use crate::raw::*;
+use crate::types::walk::OpWalk;
 use crate::*;
 
 /// OperatorFunction is the function generated by [`BlockingOperator`].
@@ -88,6 +89,20 @@ impl FunctionWrite {
         self
     }
 
+    /// Set the offset of op.
+    ///
+    /// If the offset is set, the data will be written at the given position of the
+    /// file without truncating it.
+    ///
+    /// # Notes
+    ///
+    /// Service could return `Unsupported` if the underlying storage does not support
+    /// positional write.
+    pub fn offset(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|(args, bs)| (args.with_offset(v), bs));
+        self
+    }
+
     /// Set the buffer size of op.
     ///
     /// If buffer size is set, the data will be buffered by the underlying writer.
@@ -154,6 +169,20 @@ impl FunctionWriter {
         self
     }
 
+    /// Set the offset of op.
+    ///
+    /// If the offset is set, the writer will start writing at the given position of
+    /// the file without truncating it.
+    ///
+    /// # Notes
+    ///
+    /// Service could return `Unsupported` if the underlying storage does not support
+    /// positional write.
+    pub fn offset(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|args| args.with_offset(v));
+        self
+    }
+
     /// Set the buffer size of op.
     ///
     /// If buffer size is set, the data will be buffered by the underlying writer.
@@ -241,6 +270,15 @@ impl FunctionList {
         self
     }
 
+    /// Change the max number of stat operations to run concurrently while
+    /// completing the metadata required by `metakey`. The default is `1`.
+    ///
+    /// The order of entries is preserved.
+    pub fn concurrent(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_concurrent(v));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<Vec<Entry>> {
@@ -278,6 +316,64 @@ impl FunctionLister {
         self
     }
 
+    /// Change the max number of stat operations to run concurrently while
+    /// completing the metadata required by `metakey`. The default is `1`.
+    ///
+    /// The order of entries is preserved.
+    pub fn concurrent(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_concurrent(v));
+        self
+    }
+
+    /// Call the function to consume all the input and generate a
+    /// result.
+    pub fn call(self) -> Result<BlockingLister> {
+        self.0.call()
+    }
+}
+
+/// Function that generated by [`BlockingOperator::walk_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FunctionWalker(pub(crate) OperatorFunction<OpWalk, BlockingLister>);
+
+impl FunctionWalker {
+    /// Set the max depth to walk. Entries directly inside the given dir
+    /// are at depth `1`. The default is unlimited.
+    pub fn max_depth(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_max_depth(v));
+        self
+    }
+
+    /// Set the order to yield dirs. The default is [`WalkOrder::PreOrder`].
+    pub fn order(mut self, v: WalkOrder) -> Self {
+        self.0 = self.0.map_args(|args| args.with_order(v));
+        self
+    }
+
+    /// Set the filter of entries. Entries that the filter returns `false`
+    /// will be skipped, along with all entries inside them if they are dirs.
+    ///
+    /// The entry passed to filter only carries the metadata returned by list.
+    pub fn filter(mut self, f: impl Fn(&Entry) -> bool + Send + Sync + 'static) -> Self {
+        self.0 = self.0.map_args(|args| args.with_filter(f));
+        self
+    }
+
+    /// Change the metakey. The default metakey is `Metakey::Mode`.
+    pub fn metakey(mut self, v: impl Into<FlagSet<Metakey>>) -> Self {
+        self.0 = self.0.map_args(|args| args.with_metakey(v));
+        self
+    }
+
+    /// Change the max number of dirs to list concurrently, which is also
+    /// used as the max number of stat operations while completing metadata.
+    /// The default is `1`.
+    pub fn concurrent(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_concurrent(v));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<BlockingLister> {
@@ -316,6 +412,18 @@ impl FunctionReader {
         self
     }
 
+    /// Set the read ahead window for this reader.
+    ///
+    /// Reader will read the next `size` bytes at once when the buffered data has
+    /// been consumed, and seeks within the window are served from memory instead
+    /// of starting new requests.
+    ///
+    /// Memory used by the reader is bounded by about twice of `size`.
+    pub fn read_ahead(mut self, size: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_read_ahead(size));
+        self
+    }
+
     /// Sets the content-disposition header that should be send back by the remote read operation.
     pub fn override_content_disposition(mut self, content_disposition: &str) -> Self {
         self.0 = self
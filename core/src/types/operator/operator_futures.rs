@@ -19,9 +19,11 @@
 //!
 //! By using futures, users can add more options for operation.
 
+use std::collections::HashMap;
 use std::mem;
 use std::ops::RangeBounds;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::task::Context;
 use std::task::Poll;
 use std::time::Duration;
@@ -33,6 +35,9 @@ use futures::Future;
 use futures::FutureExt;
 
 use crate::raw::*;
+use crate::types::read_ranges::OpReadRanges;
+use crate::types::remove::OpRemove;
+use crate::types::walk::OpWalk;
 use crate::*;
 
 /// OperatorFuture is the future generated by [`Operator`].
@@ -309,6 +314,36 @@ impl Future for FutureRead {
     }
 }
 
+/// Future that generated by [`Operator::read_ranges_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FutureReadRanges(pub(crate) OperatorFuture<OpReadRanges, Vec<Bytes>>);
+
+impl FutureReadRanges {
+    /// Set the max gap between ranges to merge them into one read.
+    ///
+    /// Merging nearby ranges reads some unused bytes but saves requests.
+    /// The default is 1 MiB.
+    pub fn gap(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|args| args.with_gap(v));
+        self
+    }
+
+    /// Set the max number of merged reads to send concurrently. The default is `8`.
+    pub fn concurrent(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_concurrent(v));
+        self
+    }
+}
+
+impl Future for FutureReadRanges {
+    type Output = Result<Vec<Bytes>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
+    }
+}
+
 /// Future that generated by [`Operator::reader_with`].
 ///
 /// Users can add more options by public functions provided by this struct.
@@ -356,6 +391,22 @@ impl FutureReader {
         self.0 = self.0.map_args(|args| args.with_if_none_match(v));
         self
     }
+
+    /// Set the read ahead window for this reader.
+    ///
+    /// Reader will fetch the next `size` bytes ahead of current position while serving
+    /// reads, and seeks within the window are served from memory instead of starting
+    /// new requests. This is useful for formats like parquet that issue many small
+    /// reads.
+    ///
+    /// With `rt-tokio` feature enabled, the read ahead is driven by a spawned task when
+    /// running inside a tokio runtime, so it keeps going while the reader is not polled.
+    ///
+    /// Memory used by the reader is bounded by about twice of `size`.
+    pub fn read_ahead(mut self, size: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_read_ahead(size));
+        self
+    }
 }
 
 impl Future for FutureReader {
@@ -384,6 +435,20 @@ impl FutureWrite {
         self
     }
 
+    /// Set the offset of op.
+    ///
+    /// If the offset is set, the data will be written at the given position of the
+    /// file without truncating it.
+    ///
+    /// # Notes
+    ///
+    /// Service could return `Unsupported` if the underlying storage does not support
+    /// positional write.
+    pub fn offset(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|(args, bs)| (args.with_offset(v), bs));
+        self
+    }
+
     /// Set the buffer size of op.
     ///
     /// If buffer size is set, the data will be buffered by the underlying writer.
@@ -420,6 +485,42 @@ impl FutureWrite {
             .map_args(|(args, bs)| (args.with_cache_control(v), bs));
         self
     }
+
+    /// Set the storage class of option, like `GLACIER_IR` for AWS S3.
+    ///
+    /// ## Notes
+    ///
+    /// Service could return `Unsupported` if the underlying storage does not support storage class.
+    pub fn storage_class(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_storage_class(v), bs));
+        self
+    }
+
+    /// Set the tags of option
+    ///
+    /// ## Notes
+    ///
+    /// Service could return `Unsupported` if the underlying storage does not support tags.
+    pub fn tags(mut self, v: HashMap<String, String>) -> Self {
+        self.0 = self.0.map_args(|(args, bs)| (args.with_tags(v), bs));
+        self
+    }
+
+    /// Only write if the file doesn't exist.
+    ///
+    /// ## Notes
+    ///
+    /// Write will fail with `ConditionNotMatch` if the file already exists.
+    /// Service could return `Unsupported` if the underlying storage does not
+    /// support conditional write.
+    pub fn if_not_exists(mut self, v: bool) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_if_not_exists(v), bs));
+        self
+    }
 }
 
 impl Future for FutureWrite {
@@ -448,6 +549,20 @@ impl FutureWriter {
         self
     }
 
+    /// Set the offset of op.
+    ///
+    /// If the offset is set, the writer will start writing at the given position of
+    /// the file without truncating it.
+    ///
+    /// ## Notes
+    ///
+    /// Service could return `Unsupported` if the underlying storage does not support
+    /// positional write.
+    pub fn offset(mut self, v: u64) -> Self {
+        self.0 = self.0.map_args(|args| args.with_offset(v));
+        self
+    }
+
     /// Set the buffer size of op.
     ///
     /// If buffer size is set, the data will be buffered by the underlying writer.
@@ -485,6 +600,52 @@ impl FutureWriter {
         self.0 = self.0.map_args(|args| args.with_cache_control(v));
         self
     }
+
+    /// Set the storage class of option, like `GLACIER_IR` for AWS S3.
+    ///
+    /// ## Notes
+    ///
+    /// Service could return `Unsupported` if the underlying storage does not support storage class.
+    pub fn storage_class(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_storage_class(v));
+        self
+    }
+
+    /// Set the tags of option
+    ///
+    /// ## Notes
+    ///
+    /// Service could return `Unsupported` if the underlying storage does not support tags.
+    pub fn tags(mut self, v: HashMap<String, String>) -> Self {
+        self.0 = self.0.map_args(|args| args.with_tags(v));
+        self
+    }
+
+    /// Only write if the file doesn't exist.
+    ///
+    /// ## Notes
+    ///
+    /// Write will fail with `ConditionNotMatch` if the file already exists.
+    /// Service could return `Unsupported` if the underlying storage does not
+    /// support conditional write.
+    pub fn if_not_exists(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_not_exists(v));
+        self
+    }
+
+    /// Resume an incomplete upload from the checkpoint returned by
+    /// [`Writer::checkpoint`].
+    ///
+    /// Data should be written from [`WriteCheckpoint::offset`], everything
+    /// before it has been uploaded already.
+    ///
+    /// ## Notes
+    ///
+    /// Service could return `Unsupported` if the underlying storage does not support resume.
+    pub fn resume(mut self, checkpoint: WriteCheckpoint) -> Self {
+        self.0 = self.0.map_args(|args| args.with_resume(checkpoint));
+        self
+    }
 }
 
 impl Future for FutureWriter {
@@ -498,12 +659,36 @@ impl Future for FutureWriter {
 /// Future that generated by [`Operator::delete_with`].
 ///
 /// Users can add more options by public functions provided by this struct.
-pub struct FutureDelete(pub(crate) OperatorFuture<OpDelete, ()>);
+pub struct FutureDelete(pub(crate) OperatorFuture<(OpDelete, OpRemove), ()>);
 
 impl FutureDelete {
     /// Change the version of this delete operation.
     pub fn version(mut self, v: &str) -> Self {
-        self.0 = self.0.map_args(|args| args.with_version(v));
+        self.0 = self.0.map_args(|(args, opts)| (args.with_version(v), opts));
+        self
+    }
+
+    /// Delete the dir and all entries inside it.
+    ///
+    /// Native recursive delete will be used if the service supports it,
+    /// otherwise entries will be listed and deleted one by one (or in batch).
+    /// Failed paths won't stop the deletion, the first failure will be
+    /// returned after all paths have been tried.
+    pub fn recursive(mut self, v: bool) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, opts)| (args.with_recursive(v), opts));
+        self
+    }
+
+    /// Set the progress callback of a recursive delete.
+    ///
+    /// The callback will be called with every path that has been tried and
+    /// its result, which can be used to collect errors of each path.
+    pub fn progress(mut self, f: impl Fn(&str, &Result<()>) + Send + Sync + 'static) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, opts)| (args, opts.with_progress(Arc::new(f))));
         self
     }
 }
@@ -545,6 +730,15 @@ impl FutureList {
         self.0 = self.0.map_args(|args| args.with_metakey(v));
         self
     }
+
+    /// Change the max number of stat operations to run concurrently while
+    /// completing the metadata required by `metakey`. The default is `1`.
+    ///
+    /// The order of entries is preserved.
+    pub fn concurrent(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_concurrent(v));
+        self
+    }
 }
 
 impl Future for FutureList {
@@ -584,6 +778,15 @@ impl FutureLister {
         self.0 = self.0.map_args(|args| args.with_metakey(v));
         self
     }
+
+    /// Change the max number of stat operations to run concurrently while
+    /// completing the metadata required by `metakey`. The default is `1`.
+    ///
+    /// The order of entries is preserved.
+    pub fn concurrent(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_concurrent(v));
+        self
+    }
 }
 
 impl Future for FutureLister {
@@ -593,3 +796,76 @@ impl Future for FutureLister {
         self.0.poll_unpin(cx)
     }
 }
+
+/// Future that generated by [`Operator::walk_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FutureWalker(pub(crate) OperatorFuture<OpWalk, Lister>);
+
+impl FutureWalker {
+    /// Set the max depth to walk. Entries directly inside the given dir
+    /// are at depth `1`. The default is unlimited.
+    pub fn max_depth(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_max_depth(v));
+        self
+    }
+
+    /// Set the order to yield dirs. The default is [`WalkOrder::PreOrder`].
+    pub fn order(mut self, v: WalkOrder) -> Self {
+        self.0 = self.0.map_args(|args| args.with_order(v));
+        self
+    }
+
+    /// Set the filter of entries. Entries that the filter returns `false`
+    /// will be skipped, along with all entries inside them if they are dirs.
+    ///
+    /// The entry passed to filter only carries the metadata returned by list.
+    pub fn filter(mut self, f: impl Fn(&Entry) -> bool + Send + Sync + 'static) -> Self {
+        self.0 = self.0.map_args(|args| args.with_filter(f));
+        self
+    }
+
+    /// Change the metakey. The default metakey is `Metakey::Mode`.
+    pub fn metakey(mut self, v: impl Into<FlagSet<Metakey>>) -> Self {
+        self.0 = self.0.map_args(|args| args.with_metakey(v));
+        self
+    }
+
+    /// Change the max number of dirs to list concurrently, which is also
+    /// used as the max number of stat operations while completing metadata.
+    /// The default is `1`.
+    pub fn concurrent(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_concurrent(v));
+        self
+    }
+}
+
+impl Future for FutureWalker {
+    type Output = Result<Lister>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
+    }
+}
+
+/// Future that generated by [`Operator::watch_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FutureWatcher(pub(crate) OperatorFuture<OpWatch, Watcher>);
+
+impl FutureWatcher {
+    /// Change the interval to poll changes while the service doesn't
+    /// support watch natively. The default is 10 seconds.
+    pub fn poll_interval(mut self, v: Duration) -> Self {
+        self.0 = self.0.map_args(|args| args.with_poll_interval(v));
+        self
+    }
+}
+
+impl Future for FutureWatcher {
+    type Output = Result<Watcher>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
+    }
+}
@@ -0,0 +1,78 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+/// PostConditions are the restrictions enforced by a presigned POST policy.
+///
+/// Uploaded objects must always start with the prefix passed to
+/// [`Operator::presign_post`], other conditions are optional.
+///
+/// # Examples
+///
+/// ```
+/// use opendal::PostConditions;
+///
+/// let conditions = PostConditions::new()
+///     .with_content_length_range(1, 10 * 1024 * 1024)
+///     .with_content_type_prefix("image/");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PostConditions {
+    content_length_range: Option<(u64, u64)>,
+    content_type: Option<String>,
+    content_type_prefix: Option<String>,
+}
+
+impl PostConditions {
+    /// Create a new PostConditions without any restrictions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restrict the size of uploaded content to `[min, max]` bytes.
+    pub fn with_content_length_range(mut self, min: u64, max: u64) -> Self {
+        self.content_length_range = Some((min, max));
+        self
+    }
+
+    /// Get the content length range of conditions.
+    pub fn content_length_range(&self) -> Option<(u64, u64)> {
+        self.content_length_range
+    }
+
+    /// Require the content type of uploaded content to be exactly `v`.
+    ///
+    /// The content type will be filled in the returned form fields.
+    pub fn with_content_type(mut self, v: &str) -> Self {
+        self.content_type = Some(v.to_string());
+        self
+    }
+
+    /// Get the exact content type of conditions.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Require the content type of uploaded content to start with `v`.
+    pub fn with_content_type_prefix(mut self, v: &str) -> Self {
+        self.content_type_prefix = Some(v.to_string());
+        self
+    }
+
+    /// Get the content type prefix of conditions.
+    pub fn content_type_prefix(&self) -> Option<&str> {
+        self.content_type_prefix.as_deref()
+    }
+}
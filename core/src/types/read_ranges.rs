@@ -0,0 +1,208 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::Range;
+
+use bytes::Bytes;
+use futures::stream;
+use futures::StreamExt;
+use futures::TryStreamExt;
+
+use crate::raw::oio::ReadExt;
+use crate::raw::*;
+use crate::*;
+
+/// Ranges with gap no more than this will be merged into one read by default.
+const DEFAULT_GAP: u64 = 1024 * 1024;
+/// The max number of reads to send concurrently by default.
+const DEFAULT_CONCURRENT: usize = 8;
+
+/// Args for read ranges operations.
+#[derive(Debug, Clone)]
+pub(crate) struct OpReadRanges {
+    ranges: Vec<Range<u64>>,
+    gap: u64,
+    concurrent: usize,
+}
+
+impl OpReadRanges {
+    pub(crate) fn new(ranges: Vec<Range<u64>>) -> Self {
+        Self {
+            ranges,
+            gap: DEFAULT_GAP,
+            concurrent: DEFAULT_CONCURRENT,
+        }
+    }
+
+    pub(crate) fn with_gap(mut self, gap: u64) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    pub(crate) fn with_concurrent(mut self, concurrent: usize) -> Self {
+        self.concurrent = concurrent.max(1);
+        self
+    }
+}
+
+/// Where to find the data of an input range in the merged reads.
+#[derive(Debug, PartialEq, Eq)]
+struct Slot {
+    /// The index of merged read.
+    read: usize,
+    /// The range inside the merged read.
+    range: Range<usize>,
+}
+
+/// Merge the ranges whose gap is no more than `gap` into one read.
+///
+/// Returns the merged ranges to read, and the slot of every input range.
+/// Empty ranges don't need to read and have no slot.
+fn merge_ranges(ranges: &[Range<u64>], gap: u64) -> (Vec<Range<u64>>, Vec<Option<Slot>>) {
+    let mut indexes: Vec<usize> = (0..ranges.len())
+        .filter(|&i| !ranges[i].is_empty())
+        .collect();
+    indexes.sort_by_key(|&i| ranges[i].start);
+
+    let mut merged: Vec<Range<u64>> = vec![];
+    let mut slots: Vec<Option<Slot>> = (0..ranges.len()).map(|_| None).collect();
+    for i in indexes {
+        let r = &ranges[i];
+        match merged.last_mut() {
+            Some(last) if r.start <= last.end.saturating_add(gap) => {
+                last.end = last.end.max(r.end);
+            }
+            _ => merged.push(r.clone()),
+        }
+
+        let read = merged.len() - 1;
+        let base = merged[read].start;
+        slots[i] = Some(Slot {
+            read,
+            range: (r.start - base) as usize..(r.end - base) as usize,
+        });
+    }
+
+    (merged, slots)
+}
+
+/// Read the given ranges of path, nearby ranges will be merged into one read
+/// and merged reads will be sent concurrently.
+///
+/// We don't send one HTTP request with multiple ranges here since most services
+/// don't support `multipart/byteranges` response and `Accessor::read` can only
+/// carry one range.
+pub(crate) async fn read_ranges(
+    acc: FusedAccessor,
+    path: &str,
+    args: OpReadRanges,
+) -> Result<Vec<Bytes>> {
+    if let Some(r) = args.ranges.iter().find(|r| r.start > r.end) {
+        return Err(
+            Error::new(ErrorKind::InvalidInput, "range start is larger than end")
+                .with_operation("Operator::read_ranges")
+                .with_context("path", path)
+                .with_context("range", format!("{r:?}")),
+        );
+    }
+
+    let (merged, slots) = merge_ranges(&args.ranges, args.gap);
+
+    let reads: Vec<Bytes> = stream::iter(merged)
+        .map(|r| {
+            let acc = acc.clone();
+            async move {
+                let op = OpRead::new().with_range(BytesRange::from(r));
+                let (_, mut s) = acc.read(path, op).await?;
+
+                let mut buf = Vec::new();
+                s.read_to_end(&mut buf).await?;
+                Ok::<_, Error>(Bytes::from(buf))
+            }
+        })
+        .buffered(args.concurrent)
+        .try_collect()
+        .await?;
+
+    // The data could be shorter than expected if range exceeds the end of file.
+    let result = slots
+        .into_iter()
+        .map(|slot| match slot {
+            Some(Slot { read, range }) => {
+                let bs = &reads[read];
+                let end = range.end.min(bs.len());
+                let start = range.start.min(end);
+                bs.slice(start..end)
+            }
+            None => Bytes::new(),
+        })
+        .collect();
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn test_merge_ranges() {
+        let cases = vec![
+            ("empty", vec![], 0, vec![], vec![]),
+            (
+                "no merge",
+                vec![0..10, 20..30],
+                5,
+                vec![0..10, 20..30],
+                vec![Some((0, 0..10)), Some((1, 0..10))],
+            ),
+            (
+                "merge by gap",
+                vec![20..30, 0..10],
+                10,
+                vec![0..30],
+                vec![Some((0, 20..30)), Some((0, 0..10))],
+            ),
+            (
+                "overlapped",
+                vec![0..10, 5..8, 4..20],
+                0,
+                vec![0..20],
+                vec![Some((0, 0..10)), Some((0, 5..8)), Some((0, 4..20))],
+            ),
+            (
+                "skip empty",
+                vec![0..10, 15..15, 100..110],
+                0,
+                vec![0..10, 100..110],
+                vec![Some((0, 0..10)), None, Some((1, 0..10))],
+            ),
+        ];
+
+        for (name, ranges, gap, expected_merged, expected_slots) in cases {
+            let (merged, slots) = merge_ranges(&ranges, gap);
+            let expected_slots: Vec<_> = expected_slots
+                .into_iter()
+                .map(|v| v.map(|(read, range)| Slot { read, range }))
+                .collect();
+
+            assert_eq!(merged, expected_merged, "{name}");
+            assert_eq!(slots, expected_slots, "{name}");
+        }
+    }
+}
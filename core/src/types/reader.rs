@@ -67,7 +67,13 @@ impl Reader {
     /// We don't want to expose those details to users so keep this function
     /// in crate only.
     pub(crate) async fn create(acc: FusedAccessor, path: &str, op: OpRead) -> Result<Self> {
+        let read_ahead = op.read_ahead();
+
         let (_, r) = acc.read(path, op).await?;
+        let r = match read_ahead {
+            Some(size) if size > 0 => Box::new(oio::PrefetchReader::new(r, size)),
+            _ => r,
+        };
 
         Ok(Reader {
             inner: r,
@@ -192,7 +198,13 @@ impl BlockingReader {
     /// We don't want to expose those details to users so keep this function
     /// in crate only.
     pub(crate) fn create(acc: FusedAccessor, path: &str, op: OpRead) -> Result<Self> {
+        let read_ahead = op.read_ahead();
+
         let (_, r) = acc.blocking_read(path, op)?;
+        let r = match read_ahead {
+            Some(size) if size > 0 => Box::new(oio::PrefetchReader::new(r, size)),
+            _ => r,
+        };
 
         Ok(BlockingReader { inner: r })
     }
@@ -0,0 +1,195 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use futures::StreamExt;
+use futures::TryStreamExt;
+
+use crate::raw::*;
+use crate::*;
+
+/// Callback invoked with every path a recursive delete has tried.
+pub(crate) type RemoveProgress = Arc<dyn Fn(&str, &Result<()>) + Send + Sync>;
+
+/// Operator-level args for recursive delete.
+#[derive(Clone)]
+pub(crate) struct OpRemove {
+    limit: usize,
+    progress: Option<RemoveProgress>,
+}
+
+impl Debug for OpRemove {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OpRemove")
+            .field("limit", &self.limit)
+            .field("progress", &self.progress.is_some())
+            .finish()
+    }
+}
+
+impl OpRemove {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            progress: None,
+        }
+    }
+
+    pub fn with_progress(mut self, progress: RemoveProgress) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+}
+
+/// Failures collected during a recursive delete.
+#[derive(Default)]
+struct Failures {
+    count: usize,
+    first: Option<Error>,
+}
+
+impl Failures {
+    fn record(&mut self, args: &OpRemove, path: &str, result: Result<()>) {
+        if let Some(progress) = &args.progress {
+            progress(path, &result);
+        }
+
+        if let Err(err) = result {
+            self.count += 1;
+            if self.first.is_none() {
+                self.first = Some(err.with_context("failed_path", path));
+            }
+        }
+    }
+
+    fn into_result(self) -> Result<()> {
+        match self.first {
+            None => Ok(()),
+            Some(err) => Err(err.with_context("failed", self.count.to_string())),
+        }
+    }
+}
+
+/// Delete the path and all entries inside it.
+///
+/// Native recursive delete will be used if supported. Otherwise, entries
+/// will be listed and deleted (in batch if supported). Failed paths don't
+/// stop the deletion, the first failure will be returned at the end.
+pub(crate) async fn remove(
+    acc: FusedAccessor,
+    path: String,
+    args: OpDelete,
+    opts: OpRemove,
+) -> Result<()> {
+    let mut failures = Failures::default();
+    let cap = acc.info().full_capability();
+
+    if cap.delete_with_recursive {
+        let result = acc.delete(&path, args).await.map(|_| ());
+        failures.record(&opts, &path, result);
+        return failures.into_result();
+    }
+
+    let args = args.with_recursive(false);
+    let op = Operator::from_inner(acc).with_limit(opts.limit);
+
+    let meta = match op.stat(&path).await {
+        Ok(meta) => meta,
+        // If object not found, return success.
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+
+    if !meta.is_dir() {
+        let result = op.inner().delete(&path, args).await.map(|_| ());
+        failures.record(&opts, &path, result);
+        return failures.into_result();
+    }
+
+    let mut lister = op.lister_with(&path).delimiter("").await?;
+
+    if cap.batch && cap.batch_delete {
+        let mut lister = lister.try_chunks(op.limit());
+
+        while let Some(entries) = lister.next().await {
+            let ops = entries
+                .map_err(|err| err.1)?
+                .into_iter()
+                .map(|v| (v.path().to_string(), OpDelete::new().into()))
+                .collect();
+
+            let results = op.inner().batch(OpBatch::new(ops)).await?.into_results();
+            for (path, result) in results {
+                failures.record(&opts, &path, result.map(|_| ()));
+            }
+        }
+    } else {
+        while let Some(entry) = lister.try_next().await? {
+            let result = op
+                .inner()
+                .delete(entry.path(), OpDelete::new())
+                .await
+                .map(|_| ());
+            failures.record(&opts, entry.path(), result);
+        }
+    }
+
+    // Remove the directory itself.
+    let result = op.inner().delete(&path, args).await.map(|_| ());
+    failures.record(&opts, &path, result);
+
+    failures.into_result()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_remove_with_progress() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        op.write("a/b/1", vec![0; 10]).await?;
+        op.write("a/2", vec![0; 20]).await?;
+        op.write("3", vec![0; 30]).await?;
+
+        let paths = Arc::new(Mutex::new(Vec::new()));
+        let cloned = paths.clone();
+        op.delete_with("a/")
+            .recursive(true)
+            .progress(move |path, result| {
+                assert!(result.is_ok());
+                cloned.lock().unwrap().push(path.to_string());
+            })
+            .await?;
+
+        let mut paths = paths.lock().unwrap().clone();
+        paths.sort();
+        assert_eq!(paths, ["a/", "a/2", "a/b/1"]);
+
+        assert!(!op.is_exist("a/b/1").await?);
+        assert!(!op.is_exist("a/2").await?);
+        assert!(op.is_exist("3").await?);
+
+        Ok(())
+    }
+}
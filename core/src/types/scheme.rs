@@ -32,6 +32,8 @@ use crate::Error;
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub enum Scheme {
+    /// [archive][crate::services::Archive]: Read-only zip and tar archives.
+    Archive,
     /// [atomicserver][crate::services::Atomicserver]: Atomicserver services.
     Atomicserver,
     /// [azblob][crate::services::Azblob]: Azure Storage Blob services.
@@ -54,6 +56,8 @@ pub enum Scheme {
     Foundationdb,
     /// [dbfs][crate::services::Dbfs]: DBFS backend support.
     Dbfs,
+    /// [dedup][crate::services::Dedup]: Dedup services.
+    Dedup,
     /// [fs][crate::services::Fs]: POSIX alike file system.
     Fs,
     /// [ftp][crate::services::Ftp]: FTP backend.
@@ -107,6 +111,8 @@ pub enum Scheme {
     S3,
     /// [sftp][crate::services::Sftp]: SFTP services
     Sftp,
+    /// [sharding][crate::services::Sharding]: Sharding services
+    Sharding,
     /// [sled][crate::services::Sled]: Sled services
     Sled,
     /// [Supabase][crate::services::Supabase]: Supabase storage service
@@ -123,6 +129,8 @@ pub enum Scheme {
     Redb,
     /// [tikv][crate::services::tikv]: Tikv Services
     Tikv,
+    /// [tiered][crate::services::Tiered]: Tiered services
+    Tiered,
     /// [azfile][crate::services::azfile]: Azfile Services
     Azfile,
     /// [mongodb](crate::services::mongodb): MongoDB Services
@@ -161,6 +169,8 @@ impl Scheme {
     /// ```
     pub fn enabled() -> HashSet<Scheme> {
         HashSet::from([
+            #[cfg(feature = "services-archive")]
+            Scheme::Archive,
             #[cfg(feature = "services-atomicserver")]
             Scheme::Atomicserver,
             #[cfg(feature = "services-azblob")]
@@ -175,6 +185,8 @@ impl Scheme {
             Scheme::Cos,
             #[cfg(feature = "services-dashmap")]
             Scheme::Dashmap,
+            #[cfg(feature = "services-dedup")]
+            Scheme::Dedup,
             #[cfg(feature = "services-dropbox")]
             Scheme::Dropbox,
             #[cfg(feature = "services-etcd")]
@@ -229,6 +241,8 @@ impl Scheme {
             Scheme::S3,
             #[cfg(feature = "services-sftp")]
             Scheme::Sftp,
+            #[cfg(feature = "services-sharding")]
+            Scheme::Sharding,
             #[cfg(feature = "services-sled")]
             Scheme::Sled,
             #[cfg(feature = "services-sqlite")]
@@ -237,6 +251,8 @@ impl Scheme {
             Scheme::Supabase,
             #[cfg(feature = "services-swift")]
             Scheme::Swift,
+            #[cfg(feature = "services-tiered")]
+            Scheme::Tiered,
             #[cfg(feature = "services-tikv")]
             Scheme::Tikv,
             #[cfg(feature = "services-vercel-artifacts")]
@@ -271,6 +287,7 @@ impl FromStr for Scheme {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let s = s.to_lowercase();
         match s.as_str() {
+            "archive" => Ok(Scheme::Archive),
             "atomicserver" => Ok(Scheme::Atomicserver),
             "azblob" => Ok(Scheme::Azblob),
             // Notes:
@@ -286,6 +303,7 @@ impl FromStr for Scheme {
             "dropbox" => Ok(Scheme::Dropbox),
             "etcd" => Ok(Scheme::Etcd),
             "dbfs" => Ok(Scheme::Dbfs),
+            "dedup" => Ok(Scheme::Dedup),
             "fs" => Ok(Scheme::Fs),
             "gcs" => Ok(Scheme::Gcs),
             "gdrive" => Ok(Scheme::Gdrive),
@@ -312,6 +330,7 @@ impl FromStr for Scheme {
             "rocksdb" => Ok(Scheme::Rocksdb),
             "s3" => Ok(Scheme::S3),
             "sftp" => Ok(Scheme::Sftp),
+            "sharding" => Ok(Scheme::Sharding),
             "sled" => Ok(Scheme::Sled),
             "supabase" => Ok(Scheme::Supabase),
             "swift" => Ok(Scheme::Swift),
@@ -319,6 +338,7 @@ impl FromStr for Scheme {
             "vercel_artifacts" => Ok(Scheme::VercelArtifacts),
             "webdav" => Ok(Scheme::Webdav),
             "webhdfs" => Ok(Scheme::Webhdfs),
+            "tiered" => Ok(Scheme::Tiered),
             "tikv" => Ok(Scheme::Tikv),
             "azfile" => Ok(Scheme::Azfile),
             "mongodb" => Ok(Scheme::Mongodb),
@@ -330,6 +350,7 @@ impl FromStr for Scheme {
 impl From<Scheme> for &'static str {
     fn from(v: Scheme) -> Self {
         match v {
+            Scheme::Archive => "archive",
             Scheme::Atomicserver => "atomicserver",
             Scheme::Azblob => "azblob",
             Scheme::Azdls => "azdls",
@@ -340,6 +361,7 @@ impl From<Scheme> for &'static str {
             Scheme::Dashmap => "dashmap",
             Scheme::Etcd => "etcd",
             Scheme::Dbfs => "dbfs",
+            Scheme::Dedup => "dedup",
             Scheme::Fs => "fs",
             Scheme::Gcs => "gcs",
             Scheme::Ghac => "ghac",
@@ -366,6 +388,7 @@ impl From<Scheme> for &'static str {
             Scheme::Rocksdb => "rocksdb",
             Scheme::S3 => "s3",
             Scheme::Sftp => "sftp",
+            Scheme::Sharding => "sharding",
             Scheme::Sled => "sled",
             Scheme::Supabase => "supabase",
             Scheme::Swift => "swift",
@@ -374,6 +397,7 @@ impl From<Scheme> for &'static str {
             Scheme::Webdav => "webdav",
             Scheme::Webhdfs => "webhdfs",
             Scheme::Redb => "redb",
+            Scheme::Tiered => "tiered",
             Scheme::Tikv => "tikv",
             Scheme::Azfile => "azfile",
             Scheme::Sqlite => "sqlite",
@@ -0,0 +1,467 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use futures::future;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::raw::*;
+use crate::*;
+
+/// The dir to store snapshot manifests and copied contents.
+pub(crate) const SNAPSHOT_DIR: &str = ".opendal_snapshots/";
+
+/// The max number of files to capture concurrently.
+const CONCURRENT_CAPTURES: usize = 16;
+
+/// Snapshot is an immutable record of all files under a prefix at a point in time.
+///
+/// Snapshots are created by [`Operator::snapshot`] and can be opened as a
+/// read-only [`Operator`] via [`Operator::open_snapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snapshot {
+    id: String,
+    prefix: String,
+    created_at: i64,
+    entries: Vec<SnapshotEntry>,
+}
+
+impl Snapshot {
+    /// The id of this snapshot.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The prefix captured by this snapshot.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// The time this snapshot was created at.
+    pub fn created_at(&self) -> DateTime<Utc> {
+        parse_datetime_from_from_timestamp_millis(self.created_at)
+            .expect("created_at must be valid timestamp")
+    }
+
+    /// All files in this snapshot, sorted by path.
+    pub fn entries(&self) -> &[SnapshotEntry] {
+        &self.entries
+    }
+}
+
+/// SnapshotEntry is a file captured in a [`Snapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotEntry {
+    path: String,
+    content_length: u64,
+    etag: Option<String>,
+    last_modified: Option<i64>,
+    version: Option<String>,
+    /// The path of copied content, `None` means content will be read by version.
+    copy: Option<String>,
+}
+
+impl SnapshotEntry {
+    /// The path of this file.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The content length of this file.
+    pub fn content_length(&self) -> u64 {
+        self.content_length
+    }
+
+    /// The etag of this file while captured.
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    /// The version of this file while captured.
+    ///
+    /// Only available if the content is served by version reads.
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    fn metadata(&self) -> Metadata {
+        let mut meta = Metadata::new(EntryMode::FILE).with_content_length(self.content_length);
+        if let Some(v) = &self.etag {
+            meta.set_etag(v);
+        }
+        if let Some(v) = self
+            .last_modified
+            .and_then(|v| parse_datetime_from_from_timestamp_millis(v).ok())
+        {
+            meta.set_last_modified(v);
+        }
+        if let Some(v) = &self.version {
+            meta.set_version(v);
+        }
+        meta
+    }
+}
+
+fn manifest_path(id: &str) -> String {
+    format!("{SNAPSHOT_DIR}{id}/manifest.json")
+}
+
+/// Capture all files under `prefix` into a new snapshot.
+pub(crate) async fn create(op: &Operator, prefix: &str) -> Result<Snapshot> {
+    let prefix = normalize_path(prefix);
+    if !validate_path(&prefix, EntryMode::DIR) {
+        return Err(Error::new(
+            ErrorKind::NotADirectory,
+            "snapshot prefix must be a directory",
+        )
+        .with_operation("Operator::snapshot")
+        .with_context("service", op.info().scheme())
+        .with_context("prefix", &prefix));
+    }
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let created_at = Utc::now().timestamp_millis();
+    let versioned = op.info().full_capability().read_with_version;
+
+    let lister = op
+        .lister_with(&prefix)
+        .delimiter("")
+        .metakey(
+            Metakey::Mode
+                | Metakey::ContentLength
+                | Metakey::Etag
+                | Metakey::LastModified
+                | Metakey::Version,
+        )
+        .await?;
+    let mut entries: Vec<SnapshotEntry> = lister
+        .try_filter(|e| {
+            future::ready(e.metadata().is_file() && !e.path().starts_with(SNAPSHOT_DIR))
+        })
+        .map(|res| {
+            let id = id.as_str();
+            async move { capture(op, id, versioned, res?).await }
+        })
+        .buffered(CONCURRENT_CAPTURES)
+        .try_collect()
+        .await?;
+    entries.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let snapshot = Snapshot {
+        id,
+        prefix,
+        created_at,
+        entries,
+    };
+
+    let bs = serde_json::to_vec(&snapshot).map_err(|err| {
+        Error::new(ErrorKind::Unexpected, "encode snapshot manifest").set_source(err)
+    })?;
+    op.write(&manifest_path(&snapshot.id), bs).await?;
+
+    Ok(snapshot)
+}
+
+/// Capture a file via version if possible, otherwise copy its content.
+async fn capture(op: &Operator, id: &str, versioned: bool, entry: Entry) -> Result<SnapshotEntry> {
+    let (path, meta) = entry.into_parts();
+
+    if versioned {
+        if let Some(version) = meta.version() {
+            return Ok(SnapshotEntry {
+                content_length: meta.content_length(),
+                etag: meta.etag().map(|v| v.to_string()),
+                last_modified: meta.last_modified().map(|v| v.timestamp_millis()),
+                version: Some(version.to_string()),
+                copy: None,
+                path,
+            });
+        }
+    }
+
+    let copy = format!("{SNAPSHOT_DIR}{id}/data/{path}");
+    if op.info().full_capability().copy {
+        op.copy(&path, &copy).await?;
+    } else {
+        let r = op.reader(&path).await?;
+        let mut w = op.writer(&copy).await?;
+        w.copy(r).await?;
+        w.close().await?;
+    }
+
+    // The file could be changed after listed, so we record the size of
+    // content that actually copied.
+    let copied = op.stat(&copy).await?;
+    Ok(SnapshotEntry {
+        content_length: copied.content_length(),
+        etag: meta.etag().map(|v| v.to_string()),
+        last_modified: meta.last_modified().map(|v| v.timestamp_millis()),
+        version: None,
+        copy: Some(copy),
+        path,
+    })
+}
+
+/// Open a read-only operator which serves exactly the given snapshot.
+pub(crate) async fn open(op: &Operator, id: &str) -> Result<Operator> {
+    let bs = op.read(&manifest_path(id)).await?;
+    let snapshot: Snapshot = serde_json::from_slice(&bs).map_err(|err| {
+        Error::new(ErrorKind::Unexpected, "decode snapshot manifest")
+            .with_operation("Operator::open_snapshot")
+            .with_context("id", id)
+            .set_source(err)
+    })?;
+
+    let acc = SnapshotAccessor {
+        inner: op.inner().clone(),
+        id: snapshot.id,
+        entries: Arc::new(
+            snapshot
+                .entries
+                .into_iter()
+                .map(|e| (e.path.clone(), e))
+                .collect(),
+        ),
+    };
+
+    Ok(OperatorBuilder::new(acc).finish())
+}
+
+/// Remove the manifest and all copied contents of a snapshot.
+pub(crate) async fn delete(op: &Operator, id: &str) -> Result<()> {
+    op.remove_all(&format!("{SNAPSHOT_DIR}{id}/")).await
+}
+
+/// SnapshotAccessor serves the files of a snapshot from the underlying
+/// accessor.
+#[derive(Clone)]
+struct SnapshotAccessor {
+    inner: FusedAccessor,
+    id: String,
+    entries: Arc<BTreeMap<String, SnapshotEntry>>,
+}
+
+impl Debug for SnapshotAccessor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SnapshotAccessor")
+            .field("inner", &self.inner)
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SnapshotAccessor {
+    fn entry(&self, path: &str) -> Result<&SnapshotEntry> {
+        self.entries.get(path).ok_or_else(|| {
+            Error::new(ErrorKind::NotFound, "file not found in snapshot")
+                .with_context("snapshot", &self.id)
+        })
+    }
+
+    /// Returns the path and args to read given entry from inner accessor.
+    fn read_args(&self, path: &str, args: OpRead) -> Result<(String, OpRead)> {
+        let entry = self.entry(path)?;
+        match (&entry.copy, &entry.version) {
+            (Some(copy), _) => Ok((copy.clone(), args)),
+            (None, Some(version)) => Ok((entry.path.clone(), args.with_version(version))),
+            (None, None) => Err(Error::new(
+                ErrorKind::Unexpected,
+                "snapshot entry has neither copy nor version",
+            )
+            .with_context("snapshot", &self.id)
+            .with_context("path", path)),
+        }
+    }
+
+    fn stat_entry(&self, path: &str) -> Result<RpStat> {
+        if path.ends_with('/') {
+            let exists = path == "/"
+                || self
+                    .entries
+                    .range(path.to_string()..)
+                    .next()
+                    .map(|(k, _)| k.starts_with(path))
+                    .unwrap_or_default();
+            return if exists {
+                Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
+            } else {
+                Err(Error::new(ErrorKind::NotFound, "dir not found in snapshot")
+                    .with_context("snapshot", &self.id))
+            };
+        }
+
+        Ok(RpStat::new(self.entry(path)?.metadata()))
+    }
+
+    fn list_entries(&self, path: &str, args: OpList) -> Result<(RpList, SnapshotPager)> {
+        if !args.delimiter().is_empty() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "snapshot doesn't support delimiter",
+            ));
+        }
+
+        let prefix = if path == "/" { "" } else { path };
+        let entries = self
+            .entries
+            .range(prefix.to_string()..)
+            .take_while(|(k, _)| k.starts_with(prefix))
+            .map(|(k, v)| oio::Entry::new(k, v.metadata()))
+            .collect();
+
+        Ok((RpList::default(), SnapshotPager(Some(entries))))
+    }
+}
+
+#[async_trait]
+impl Accessor for SnapshotAccessor {
+    type Reader = oio::Reader;
+    type BlockingReader = oio::BlockingReader;
+    type Writer = ();
+    type BlockingWriter = ();
+    type Pager = SnapshotPager;
+    type BlockingPager = SnapshotPager;
+
+    fn info(&self) -> AccessorInfo {
+        let inner = self.inner.info();
+        let ic = inner.full_capability();
+
+        let cap = Capability {
+            stat: true,
+            read: ic.read,
+            read_can_seek: ic.read_can_seek,
+            read_can_next: ic.read_can_next,
+            read_with_range: ic.read_with_range,
+            list: true,
+            list_without_delimiter: true,
+            blocking: ic.blocking,
+            ..Default::default()
+        };
+
+        let mut am = AccessorInfo::default();
+        am.set_scheme(inner.scheme())
+            .set_root(inner.root())
+            .set_name(inner.name())
+            .set_native_capability(cap);
+
+        am
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let (path, args) = self.read_args(path, args)?;
+        self.inner.read(&path, args).await
+    }
+
+    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        self.stat_entry(path)
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.list_entries(path, args)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let (path, args) = self.read_args(path, args)?;
+        self.inner.blocking_read(&path, args)
+    }
+
+    fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        self.stat_entry(path)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.list_entries(path, args)
+    }
+}
+
+struct SnapshotPager(Option<Vec<oio::Entry>>);
+
+#[async_trait]
+impl oio::Page for SnapshotPager {
+    async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        Ok(self.0.take())
+    }
+}
+
+impl oio::BlockingPage for SnapshotPager {
+    fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        Ok(self.0.take())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_snapshot_is_immutable() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        op.write("data/a", "hello").await?;
+        op.write("data/b/c", "world").await?;
+        op.write("other", "!").await?;
+
+        let snapshot = op.snapshot("data/").await?;
+        let paths: Vec<_> = snapshot.entries().iter().map(|e| e.path()).collect();
+        assert_eq!(paths, vec!["data/a", "data/b/c"]);
+
+        // Producers keep writing.
+        op.write("data/a", "changed").await?;
+        op.delete("data/b/c").await?;
+        op.write("data/d", "new").await?;
+
+        let view = op.open_snapshot(snapshot.id()).await?;
+        assert_eq!(view.read("data/a").await?, b"hello");
+        assert_eq!(view.read("data/b/c").await?, b"world");
+        assert_eq!(view.stat("data/a").await?.content_length(), 5);
+        assert_eq!(
+            view.stat("data/d").await.unwrap_err().kind(),
+            ErrorKind::NotFound
+        );
+        assert!(view.write("data/a", "x").await.is_err());
+
+        let entries: Vec<_> = view
+            .list("data/")
+            .await?
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        assert_eq!(entries, vec!["data/a", "data/b/"]);
+
+        // Snapshot data should be hidden from new snapshots.
+        let all = op.snapshot("/").await?;
+        assert!(all
+            .entries()
+            .iter()
+            .all(|e| !e.path().starts_with(SNAPSHOT_DIR)));
+
+        op.delete_snapshot(snapshot.id()).await?;
+        assert!(op.open_snapshot(snapshot.id()).await.is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,513 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+
+use bytes::Bytes;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::raw::*;
+use crate::*;
+
+/// The dir to store staged files and logs of transactions.
+pub(crate) const TRANSACTION_DIR: &str = ".opendal_txn/";
+
+/// The dir to store logs of committed transactions.
+const COMMIT_DIR: &str = ".opendal_txn/commits/";
+
+/// The hint of the latest applied version.
+const APPLIED_PATH: &str = ".opendal_txn/applied";
+
+/// Transaction stages writes and deletes, and commits them together.
+///
+/// Created by [`Operator::transaction`].
+///
+/// # Notes
+///
+/// - Writes are staged under `.opendal_txn/<id>/` and are invisible to
+///   others before commit.
+/// - Every commit is published as a log under `.opendal_txn/commits/`
+///   named by its version. The log is written only if the next version
+///   doesn't exist, which is the commit point: once the log is written,
+///   the changes will be applied even if the process crashed in the middle.
+///   Services without conditional write are rejected.
+/// - Transactions are serialized by versions: commit fails with
+///   [`ErrorKind::ConditionNotMatch`] if another transaction has been
+///   committed since this one started. Start a new transaction to retry.
+/// - Changes are applied via `rename` if supported, otherwise `copy`.
+///   [`Transaction::read`] resolves files from committed logs that are
+///   still being applied, so readers that require atomic visibility
+///   should read via a transaction. Plain reads could observe part of the
+///   changes while they are being applied.
+/// - Files changed outside of transactions after they have been committed
+///   are kept as is.
+/// - Later changes to the same path override earlier ones.
+pub struct Transaction {
+    op: Operator,
+    id: String,
+    version: u64,
+    committed: BTreeMap<String, Change>,
+    changes: BTreeMap<String, Change>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum Change {
+    /// Write the staged file into path.
+    ///
+    /// `expect` is the fingerprint of path when committing, `None` means
+    /// path doesn't exist.
+    Write {
+        staged: String,
+        expect: Option<String>,
+    },
+    /// Delete path.
+    Delete { expect: Option<String> },
+}
+
+/// The log of a committed transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Log {
+    id: String,
+    version: u64,
+    changes: BTreeMap<String, Change>,
+}
+
+fn log_path(version: u64) -> String {
+    format!("{COMMIT_DIR}{version:020}.json")
+}
+
+fn staging_dir(id: &str) -> String {
+    format!("{TRANSACTION_DIR}{id}/")
+}
+
+impl Transaction {
+    pub(crate) async fn begin(op: Operator) -> Result<Self> {
+        if !op.info().full_capability().write_with_if_not_exists {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "transaction requires service to support write with if not exists",
+            )
+            .with_operation("Operator::transaction")
+            .with_context("service", op.info().scheme()));
+        }
+
+        let pending = pending(&op).await?;
+        let version = match pending.last() {
+            Some(log) => log.version,
+            None => applied(&op).await?,
+        };
+        let mut committed = BTreeMap::new();
+        for log in pending {
+            committed.extend(log.changes);
+        }
+
+        Ok(Self {
+            op,
+            id: uuid::Uuid::new_v4().to_string(),
+            version,
+            committed,
+            changes: BTreeMap::new(),
+        })
+    }
+
+    /// The id of this transaction.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The version this transaction started at.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    fn check_path(&self, path: &str, operation: &'static str) -> Result<String> {
+        let path = normalize_path(path);
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "transaction only supports files")
+                    .with_operation(operation)
+                    .with_context("service", self.op.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+        if path.starts_with(TRANSACTION_DIR) {
+            return Err(
+                Error::new(ErrorKind::InvalidInput, "path is reserved for transactions")
+                    .with_operation(operation)
+                    .with_context("service", self.op.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+        Ok(path)
+    }
+
+    /// Stage the content to be written into path.
+    pub async fn write(&mut self, path: &str, bs: impl Into<Bytes>) -> Result<()> {
+        let path = self.check_path(path, "Transaction::write")?;
+
+        let staged = format!("{}{path}", staging_dir(&self.id));
+        self.op.write(&staged, bs).await?;
+        self.changes.insert(
+            path,
+            Change::Write {
+                staged,
+                expect: None,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Stage the deletion of path.
+    pub fn delete(&mut self, path: &str) -> Result<()> {
+        let path = self.check_path(path, "Transaction::delete")?;
+        self.changes.insert(path, Change::Delete { expect: None });
+
+        Ok(())
+    }
+
+    /// Read the content of path as of the version this transaction started
+    /// at, including changes staged in this transaction.
+    pub async fn read(&self, path: &str) -> Result<Vec<u8>> {
+        let path = self.check_path(path, "Transaction::read")?;
+
+        let change = self
+            .changes
+            .get(&path)
+            .or_else(|| self.committed.get(&path));
+        match change {
+            Some(Change::Write { staged, .. }) => match self.op.read(staged).await {
+                // Staged files of committed transactions could have been
+                // renamed into place.
+                Err(err) if err.kind() == ErrorKind::NotFound => self.op.read(&path).await,
+                res => res,
+            },
+            Some(Change::Delete { .. }) => Err(Error::new(
+                ErrorKind::NotFound,
+                "file has been deleted in transaction",
+            )
+            .with_operation("Transaction::read")
+            .with_context("path", path)),
+            None => self.op.read(&path).await,
+        }
+    }
+
+    /// Commit all staged changes.
+    ///
+    /// Returns [`ErrorKind::ConditionNotMatch`] if another transaction has
+    /// been committed since this one started, staged files will be removed
+    /// in this case.
+    pub async fn commit(self) -> Result<()> {
+        let log = match self.publish().await {
+            Ok(log) => log,
+            Err(err) if err.kind() == ErrorKind::ConditionNotMatch => {
+                self.op.remove_all(&staging_dir(&self.id)).await?;
+                return Err(err);
+            }
+            Err(err) => return Err(err),
+        };
+        apply(&self.op, &log).await
+    }
+
+    /// Abort this transaction and remove all staged files.
+    pub async fn abort(self) -> Result<()> {
+        self.op.remove_all(&staging_dir(&self.id)).await
+    }
+
+    /// Publish the log of this transaction as the next version, which is
+    /// the commit point.
+    async fn publish(&self) -> Result<Log> {
+        // Make sure all committed changes have been applied before taking
+        // fingerprints of files.
+        for log in pending(&self.op).await? {
+            if log.version > self.version {
+                return Err(new_conflict_error(&self.id, self.version));
+            }
+            apply(&self.op, &log).await?;
+        }
+
+        let mut changes = self.changes.clone();
+        for (path, change) in changes.iter_mut() {
+            let fp = fingerprint(&self.op, path).await?;
+            match change {
+                Change::Write { expect, .. } | Change::Delete { expect } => *expect = fp,
+            }
+        }
+        let log = Log {
+            id: self.id.clone(),
+            version: self.version + 1,
+            changes,
+        };
+
+        let bs = serde_json::to_vec(&log).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "encode transaction log").set_source(err)
+        })?;
+        match self
+            .op
+            .write_with(&log_path(log.version), bs)
+            .if_not_exists(true)
+            .await
+        {
+            Err(err) if err.kind() == ErrorKind::ConditionNotMatch => {
+                Err(new_conflict_error(&self.id, self.version).set_source(err))
+            }
+            res => res.map(|_| log),
+        }
+    }
+}
+
+fn new_conflict_error(id: &str, version: u64) -> Error {
+    Error::new(
+        ErrorKind::ConditionNotMatch,
+        "another transaction has been committed",
+    )
+    .with_operation("Transaction::commit")
+    .with_context("transaction", id)
+    .with_context("version", version.to_string())
+}
+
+/// Returns the fingerprint of the file to detect changes, `None` if the
+/// file doesn't exist.
+async fn fingerprint(op: &Operator, path: &str) -> Result<Option<String>> {
+    let meta = match op.stat(path).await {
+        Ok(meta) => meta,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+
+    let fp = match meta.etag() {
+        Some(etag) => etag.to_string(),
+        None => format!(
+            "{}:{}",
+            meta.content_length(),
+            meta.last_modified()
+                .map(|v| v.timestamp_millis())
+                .unwrap_or_default()
+        ),
+    };
+    Ok(Some(fp))
+}
+
+/// Returns the hint of the latest applied version.
+async fn applied(op: &Operator) -> Result<u64> {
+    let bs = match op.read(APPLIED_PATH).await {
+        Ok(bs) => bs,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(0),
+        Err(err) => return Err(err),
+    };
+
+    String::from_utf8_lossy(&bs).trim().parse().map_err(|err| {
+        Error::new(
+            ErrorKind::Unexpected,
+            "parse applied version of transactions",
+        )
+        .with_context("path", APPLIED_PATH)
+        .set_source(err)
+    })
+}
+
+/// Returns the logs that have been committed after the applied version,
+/// in order.
+async fn pending(op: &Operator) -> Result<Vec<Log>> {
+    let mut logs = vec![];
+
+    let mut version = applied(op).await? + 1;
+    loop {
+        let path = log_path(version);
+        let bs = match op.read(&path).await {
+            Ok(bs) => bs,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(logs),
+            Err(err) => return Err(err),
+        };
+        let log: Log = serde_json::from_slice(&bs).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "decode transaction log")
+                .with_context("path", &path)
+                .set_source(err)
+        })?;
+        logs.push(log);
+        version += 1;
+    }
+}
+
+/// Apply all changes in the log and clean it up.
+///
+/// It's safe to apply the same log for many times: changes are skipped if
+/// the file has been changed since commit.
+async fn apply(op: &Operator, log: &Log) -> Result<()> {
+    let can_rename = op.info().full_capability().rename;
+
+    for (path, change) in &log.changes {
+        match change {
+            Change::Write { staged, expect } => {
+                // Staged files could have been renamed by a previous apply.
+                if !op.is_exist(staged).await? {
+                    continue;
+                }
+                if &fingerprint(op, path).await? != expect {
+                    continue;
+                }
+                let res = if can_rename {
+                    op.rename(staged, path).await
+                } else {
+                    op.copy(staged, path).await
+                };
+                match res {
+                    // Others could be applying the same log.
+                    Err(err) if err.kind() == ErrorKind::NotFound => continue,
+                    res => res?,
+                }
+            }
+            Change::Delete { expect } => {
+                if expect.is_some() && &fingerprint(op, path).await? == expect {
+                    op.delete(path).await?
+                }
+            }
+        }
+    }
+
+    op.remove_all(&staging_dir(&log.id)).await?;
+
+    // Only move the hint forward, it's fine to lose an update.
+    if applied(op).await? < log.version {
+        op.write(APPLIED_PATH, log.version.to_string()).await?;
+    }
+    Ok(())
+}
+
+/// Apply all committed transactions that haven't been finished, returns
+/// the number of recovered transactions.
+pub(crate) async fn recover(op: &Operator) -> Result<usize> {
+    let logs = pending(op).await?;
+    for log in &logs {
+        apply(op, log).await?;
+    }
+
+    Ok(logs.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_commit() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        op.write("a", "old").await?;
+        op.write("b", "old").await?;
+
+        let mut tx = op.transaction().await?;
+        let id = tx.id().to_string();
+        tx.write("a", "new").await?;
+        tx.write("c", "new").await?;
+        tx.delete("b")?;
+
+        // Staged changes are only visible in transaction.
+        assert_eq!(op.read("a").await?, b"old");
+        assert!(!op.is_exist("c").await?);
+        assert_eq!(tx.read("a").await?, b"new");
+        assert_eq!(tx.read("b").await.unwrap_err().kind(), ErrorKind::NotFound);
+
+        tx.commit().await?;
+        assert_eq!(op.read("a").await?, b"new");
+        assert_eq!(op.read("c").await?, b"new");
+        assert!(!op.is_exist("b").await?);
+        assert!(op
+            .list_with(&staging_dir(&id))
+            .delimiter("")
+            .await?
+            .is_empty());
+        assert_eq!(op.transaction().await?.version(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_commit_conflict() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+
+        let mut first = op.transaction().await?;
+        let mut second = op.transaction().await?;
+        let id = second.id().to_string();
+        first.write("a", "first").await?;
+        second.write("a", "second").await?;
+
+        first.commit().await?;
+        let err = second.commit().await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+        assert_eq!(op.read("a").await?, b"first");
+        assert!(op
+            .list_with(&staging_dir(&id))
+            .delimiter("")
+            .await?
+            .is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_recover_committed() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+
+        // Crashed after the log has been published.
+        let mut tx = op.transaction().await?;
+        tx.write("a", "new").await?;
+        tx.publish().await?;
+        drop(tx);
+
+        // Aborted transactions leave nothing.
+        let mut aborted = op.transaction().await?;
+        aborted.write("b", "new").await?;
+        aborted.abort().await?;
+
+        // Committed changes are visible to transactions before applied.
+        assert!(!op.is_exist("a").await?);
+        assert_eq!(op.transaction().await?.read("a").await?, b"new");
+
+        assert_eq!(op.recover_transactions().await?, 1);
+        assert_eq!(op.read("a").await?, b"new");
+        assert!(!op.is_exist("b").await?);
+        assert_eq!(op.recover_transactions().await?, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_recover_skip_changed() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        op.write("a", "old").await?;
+        op.write("b", "old").await?;
+
+        let mut tx = op.transaction().await?;
+        tx.write("a", "new").await?;
+        tx.delete("b")?;
+        tx.publish().await?;
+        drop(tx);
+
+        // Files changed after commit should be kept.
+        op.write("a", "changed").await?;
+        op.write("b", "changed").await?;
+
+        assert_eq!(op.recover_transactions().await?, 1);
+        assert_eq!(op.read("a").await?, b"changed");
+        assert_eq!(op.read("b").await?, b"changed");
+
+        Ok(())
+    }
+}
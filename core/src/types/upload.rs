@@ -0,0 +1,188 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use chrono::DateTime;
+use chrono::Utc;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use futures::Stream;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::raw::*;
+use crate::*;
+
+/// WriteCheckpoint is the persisted state of an in-progress upload.
+///
+/// Fetched via [`Writer::checkpoint`] and passed back by
+/// [`FutureWriter::resume`](crate::operator_futures::FutureWriter::resume)
+/// to continue the upload from another writer, process or machine.
+///
+/// The checkpoint is serializable so that users can store it anywhere they like.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WriteCheckpoint {
+    upload_id: String,
+    parts: Vec<(usize, String)>,
+    offset: u64,
+}
+
+impl WriteCheckpoint {
+    /// Create a new checkpoint.
+    pub fn new(upload_id: &str, parts: Vec<(usize, String)>, offset: u64) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+            parts,
+            offset,
+        }
+    }
+
+    /// The id of this upload.
+    ///
+    /// For multipart uploads, it's the upload id. For range based uploads
+    /// like gcs, it's the session location.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// The part numbers and etags of completed parts, in the order of upload.
+    ///
+    /// Part numbers are the ones used by services. Always empty for range
+    /// based uploads.
+    pub fn parts(&self) -> &[(usize, String)] {
+        &self.parts
+    }
+
+    /// The number of bytes that have been uploaded durably.
+    ///
+    /// Users must continue writing from this offset after resuming, data
+    /// written after it has been lost and must be sent again.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+}
+
+/// Upload is an incomplete upload returned by [`Operator::list_uploads`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Upload {
+    path: String,
+    upload_id: String,
+    initiated: Option<DateTime<Utc>>,
+}
+
+impl Upload {
+    /// Create a new upload.
+    pub fn new(path: &str, upload_id: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            upload_id: upload_id.to_string(),
+            initiated: None,
+        }
+    }
+
+    /// Set the initiated time of this upload.
+    pub fn with_initiated(mut self, v: DateTime<Utc>) -> Self {
+        self.initiated = Some(v);
+        self
+    }
+
+    /// The path of the object being uploaded.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The id of this upload, could be passed to [`Operator::abort_upload`].
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// The time when this upload was initiated.
+    pub fn initiated(&self) -> Option<DateTime<Utc>> {
+        self.initiated
+    }
+}
+
+/// Future constructed by listing uploads.
+type UploadFuture = BoxFuture<'static, (oio::UploadPager, Result<Option<Vec<Upload>>>)>;
+
+/// UploadLister is designed to list incomplete uploads under given path
+/// page by page.
+///
+/// Users can construct UploadLister by [`Operator::list_uploads`].
+///
+/// User can use lister as `Stream<Item = Result<Upload>>`.
+pub struct UploadLister {
+    buf: VecDeque<Upload>,
+    pager: Option<oio::UploadPager>,
+    listing: Option<UploadFuture>,
+}
+
+/// # Safety
+///
+/// UploadLister will only be accessed by `&mut Self`
+unsafe impl Sync for UploadLister {}
+
+impl UploadLister {
+    pub(crate) fn new(pager: oio::UploadPager) -> Self {
+        Self {
+            buf: VecDeque::new(),
+            pager: Some(pager),
+            listing: None,
+        }
+    }
+}
+
+impl Stream for UploadLister {
+    type Item = Result<Upload>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(upload) = self.buf.pop_front() {
+                return Poll::Ready(Some(Ok(upload)));
+            }
+
+            if let Some(fut) = self.listing.as_mut() {
+                let (p, res) = ready!(fut.poll_unpin(cx));
+                self.listing = None;
+                self.pager = Some(p);
+
+                match res {
+                    Ok(Some(uploads)) => self.buf = uploads.into(),
+                    Ok(None) => {
+                        self.pager = None;
+                        return Poll::Ready(None);
+                    }
+                    Err(err) => return Poll::Ready(Some(Err(err))),
+                }
+                continue;
+            }
+
+            let Some(mut p) = self.pager.take() else {
+                return Poll::Ready(None);
+            };
+            self.listing = Some(Box::pin(async move {
+                let res = p.next().await;
+                (p, res)
+            }));
+        }
+    }
+}
@@ -0,0 +1,415 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use flagset::FlagSet;
+use futures::future::BoxFuture;
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+
+use crate::raw::*;
+use crate::*;
+
+/// The order to yield dirs while walking.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WalkOrder {
+    /// Yield a dir before all entries inside it.
+    #[default]
+    PreOrder,
+    /// Yield a dir after all entries inside it.
+    PostOrder,
+}
+
+type WalkFilter = Arc<dyn Fn(&Entry) -> bool + Send + Sync>;
+
+/// Args for walk operations.
+#[derive(Clone, Default)]
+pub(crate) struct OpWalk {
+    list: OpList,
+    max_depth: Option<usize>,
+    order: WalkOrder,
+    filter: Option<WalkFilter>,
+}
+
+impl OpWalk {
+    pub(crate) fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    pub(crate) fn with_order(mut self, order: WalkOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    pub(crate) fn with_filter(
+        mut self,
+        f: impl Fn(&Entry) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.filter = Some(Arc::new(f));
+        self
+    }
+
+    pub(crate) fn with_metakey(mut self, metakey: impl Into<FlagSet<Metakey>>) -> Self {
+        self.list = self.list.with_metakey(metakey);
+        self
+    }
+
+    pub(crate) fn with_concurrent(mut self, concurrent: usize) -> Self {
+        self.list = self.list.with_concurrent(concurrent);
+        self
+    }
+
+    /// The args to complete the metadata of walked entries.
+    pub(crate) fn list_args(&self) -> OpList {
+        self.list.clone()
+    }
+}
+
+/// A dir in post-order walk that is waiting for the entries inside it.
+struct Pending {
+    /// The number of listings inside this dir that haven't been finished,
+    /// including the listing of itself.
+    remaining: usize,
+    entry: Option<oio::Entry>,
+    parent: Option<String>,
+}
+
+/// The future to list a dir, returns the dir and its depth along with the result.
+type ListDirFuture = BoxFuture<'static, (String, usize, Result<Vec<oio::Entry>>)>;
+
+/// WalkPager visits all entries under the given dir.
+///
+/// Every dir will be listed with `/` as delimiter, and sibling dirs could
+/// be listed concurrently.
+pub(crate) struct WalkPager {
+    acc: FusedAccessor,
+    args: OpWalk,
+    concurrent: usize,
+
+    /// Dirs to list along with their depth.
+    dirs: Vec<(String, usize)>,
+    /// Dirs that are waiting for their entries in post-order walk.
+    pending: HashMap<String, Pending>,
+    listing: FuturesUnordered<ListDirFuture>,
+}
+
+/// # Safety
+///
+/// We will only take `&mut Self` reference for WalkPager.
+unsafe impl Sync for WalkPager {}
+
+impl WalkPager {
+    pub(crate) fn new(acc: FusedAccessor, path: &str, args: OpWalk) -> Self {
+        let mut dirs = vec![];
+        if args.max_depth != Some(0) {
+            dirs.push((path.to_string(), 0));
+        }
+
+        let mut pending = HashMap::new();
+        if args.order == WalkOrder::PostOrder {
+            pending.insert(
+                path.to_string(),
+                Pending {
+                    remaining: 1,
+                    entry: None,
+                    parent: None,
+                },
+            );
+        }
+
+        Self {
+            acc,
+            concurrent: args.list.concurrent(),
+            args,
+            dirs,
+            pending,
+            listing: FuturesUnordered::new(),
+        }
+    }
+
+    /// Handle the entries listed in `dir`, returns the entries to yield.
+    fn visit(&mut self, dir: String, depth: usize, entries: Vec<oio::Entry>) -> Vec<oio::Entry> {
+        let mut output = vec![];
+
+        for entry in entries {
+            if entry.path() == dir {
+                continue;
+            }
+            if let Some(filter) = &self.args.filter {
+                if !filter(&entry.clone().into_entry()) {
+                    continue;
+                }
+            }
+
+            let depth = depth + 1;
+            if !entry.mode().is_dir() || self.args.max_depth.map_or(false, |v| depth >= v) {
+                output.push(entry);
+                continue;
+            }
+
+            let path = entry.path().to_string();
+            match self.args.order {
+                WalkOrder::PreOrder => output.push(entry),
+                WalkOrder::PostOrder => {
+                    self.pending.insert(
+                        path.clone(),
+                        Pending {
+                            remaining: 1,
+                            entry: Some(entry),
+                            parent: Some(dir.clone()),
+                        },
+                    );
+                    if let Some(p) = self.pending.get_mut(&dir) {
+                        p.remaining += 1;
+                    }
+                }
+            }
+            self.dirs.push((path, depth));
+        }
+
+        if self.args.order == WalkOrder::PostOrder {
+            self.finish(dir, &mut output);
+        }
+        output
+    }
+
+    /// Mark one listing inside `dir` as finished, and yield all dirs that
+    /// have been fully visited.
+    fn finish(&mut self, mut dir: String, output: &mut Vec<oio::Entry>) {
+        loop {
+            let Some(p) = self.pending.get_mut(&dir) else {
+                return;
+            };
+            p.remaining -= 1;
+            if p.remaining > 0 {
+                return;
+            }
+
+            let p = self.pending.remove(&dir).expect("pending dir must exist");
+            output.extend(p.entry);
+            match p.parent {
+                Some(parent) => dir = parent,
+                None => return,
+            }
+        }
+    }
+}
+
+async fn list_dir(acc: FusedAccessor, dir: &str) -> Result<Vec<oio::Entry>> {
+    let (_, mut pager) = match acc.list(dir, OpList::new()).await {
+        Ok(v) => v,
+        // Dirs could be removed while walking.
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err),
+    };
+
+    let mut entries = vec![];
+    while let Some(v) = pager.next().await? {
+        entries.extend(v);
+    }
+    Ok(entries)
+}
+
+fn blocking_list_dir(acc: &FusedAccessor, dir: &str) -> Result<Vec<oio::Entry>> {
+    let (_, mut pager) = match acc.blocking_list(dir, OpList::new()) {
+        Ok(v) => v,
+        // Dirs could be removed while walking.
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err),
+    };
+
+    let mut entries = vec![];
+    while let Some(v) = pager.next()? {
+        entries.extend(v);
+    }
+    Ok(entries)
+}
+
+#[async_trait]
+impl oio::Page for WalkPager {
+    async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        loop {
+            while self.listing.len() < self.concurrent {
+                let Some((dir, depth)) = self.dirs.pop() else {
+                    break;
+                };
+                let acc = self.acc.clone();
+                self.listing.push(Box::pin(async move {
+                    let res = list_dir(acc, &dir).await;
+                    (dir, depth, res)
+                }));
+            }
+
+            let Some((dir, depth, res)) = self.listing.next().await else {
+                return Ok(None);
+            };
+            let entries = self.visit(dir, depth, res?);
+            if !entries.is_empty() {
+                return Ok(Some(entries));
+            }
+        }
+    }
+}
+
+impl oio::BlockingPage for WalkPager {
+    fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        loop {
+            let size = self.dirs.len().min(self.concurrent);
+            if size == 0 {
+                return Ok(None);
+            }
+            let dirs = self.dirs.split_off(self.dirs.len() - size);
+
+            let acc = &self.acc;
+            let listed: Vec<_> = std::thread::scope(|s| {
+                let handles: Vec<_> = dirs
+                    .into_iter()
+                    .map(|(dir, depth)| {
+                        s.spawn(move || {
+                            let res = blocking_list_dir(acc, &dir);
+                            (dir, depth, res)
+                        })
+                    })
+                    .collect();
+
+                handles
+                    .into_iter()
+                    .map(|handle| {
+                        handle
+                            .join()
+                            .map_err(|_| Error::new(ErrorKind::Unexpected, "list thread panicked"))
+                    })
+                    .collect()
+            });
+
+            let mut output = vec![];
+            for res in listed {
+                let (dir, depth, res) = res?;
+                output.extend(self.visit(dir, depth, res?));
+            }
+            if !output.is_empty() {
+                return Ok(Some(output));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::services::Memory;
+
+    async fn walk(op: &Operator, args: OpWalk) -> Result<Vec<String>> {
+        let acc = op.inner().clone();
+        let list = args.list_args();
+        let pager = WalkPager::new(acc.clone(), "/", args);
+        let entries: Vec<Entry> = Lister::from_pager(acc, Box::new(pager), list)
+            .try_collect()
+            .await?;
+        Ok(entries.into_iter().map(|e| e.path().to_string()).collect())
+    }
+
+    #[tokio::test]
+    async fn test_walk() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        for path in ["a/b/c/1", "a/b/2", "a/3", "d/4", "5"] {
+            op.write(path, "x").await?;
+        }
+
+        let mut paths = walk(&op, OpWalk::default().with_concurrent(4)).await?;
+        paths.sort();
+        assert_eq!(
+            paths,
+            ["5", "a/", "a/3", "a/b/", "a/b/2", "a/b/c/", "a/b/c/1", "d/", "d/4"]
+        );
+
+        let mut paths = walk(&op, OpWalk::default().with_max_depth(2)).await?;
+        paths.sort();
+        assert_eq!(paths, ["5", "a/", "a/3", "a/b/", "d/", "d/4"]);
+
+        let mut paths = walk(
+            &op,
+            OpWalk::default().with_filter(|e: &Entry| e.path() != "a/b/"),
+        )
+        .await?;
+        paths.sort();
+        assert_eq!(paths, ["5", "a/", "a/3", "d/", "d/4"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_walk_order() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        for path in ["a/b/c/1", "a/b/2", "a/3", "d/4"] {
+            op.write(path, "x").await?;
+        }
+
+        for order in [WalkOrder::PreOrder, WalkOrder::PostOrder] {
+            let paths = walk(&op, OpWalk::default().with_order(order).with_concurrent(4)).await?;
+            assert_eq!(paths.len(), 8);
+
+            for (idx, path) in paths.iter().enumerate() {
+                if !path.ends_with('/') {
+                    continue;
+                }
+                let inside = |v: &String| v.starts_with(path.as_str()) && v != path;
+                match order {
+                    WalkOrder::PreOrder => {
+                        let first = paths.iter().position(inside).unwrap();
+                        assert!(idx < first, "{path} in {paths:?}");
+                    }
+                    WalkOrder::PostOrder => {
+                        let last = paths.iter().rposition(inside).unwrap();
+                        assert!(idx > last, "{path} in {paths:?}");
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_blocking_walk() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        let op = op.blocking();
+        for path in ["a/b/c/1", "a/b/2", "a/3"] {
+            op.write(path, "x")?;
+        }
+
+        let acc = op.inner().clone();
+        let args = OpWalk::default()
+            .with_order(WalkOrder::PostOrder)
+            .with_concurrent(4);
+        let list = args.list_args();
+        let pager = WalkPager::new(acc.clone(), "/", args);
+        let paths = BlockingLister::from_pager(acc, Box::new(pager), list)
+            .map(|e| e.map(|e| e.path().to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(paths.last().map(String::as_str), Some("a/"));
+        assert_eq!(paths.len(), 6);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,323 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use futures::future::BoxFuture;
+use futures::stream;
+use futures::FutureExt;
+use futures::Stream;
+use futures::StreamExt;
+use futures::TryStreamExt;
+
+use crate::raw::*;
+use crate::*;
+
+/// The kind of [`WatchEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WatchEventKind {
+    /// The file has been created.
+    Create,
+    /// The content of file has been changed.
+    Modify,
+    /// The file has been deleted.
+    Delete,
+}
+
+/// WatchEvent is the change of a file returned by [`Watcher`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchEvent {
+    kind: WatchEventKind,
+    path: String,
+}
+
+impl WatchEvent {
+    /// Create a new event.
+    pub fn new(kind: WatchEventKind, path: &str) -> Self {
+        Self {
+            kind,
+            path: path.to_string(),
+        }
+    }
+
+    /// The kind of this event.
+    pub fn kind(&self) -> WatchEventKind {
+        self.kind
+    }
+
+    /// The path of changed file, which is relative to the root of operator.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+/// Future constructed by watching.
+type WatchFuture = BoxFuture<'static, (oio::Watcher, Result<Option<Vec<WatchEvent>>>)>;
+
+/// Watcher is designed to receive changes under given dir in an
+/// asynchronous manner.
+///
+/// Users can construct Watcher by [`Operator::watch`].
+///
+/// User can use watcher as `Stream<Item = Result<WatchEvent>>`.
+pub struct Watcher {
+    buf: VecDeque<WatchEvent>,
+    watcher: Option<oio::Watcher>,
+    watching: Option<WatchFuture>,
+}
+
+/// # Safety
+///
+/// Watcher will only be accessed by `&mut Self`
+unsafe impl Sync for Watcher {}
+
+impl Watcher {
+    pub(crate) fn new(watcher: oio::Watcher) -> Self {
+        Self {
+            buf: VecDeque::new(),
+            watcher: Some(watcher),
+            watching: None,
+        }
+    }
+}
+
+impl Stream for Watcher {
+    type Item = Result<WatchEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(event) = self.buf.pop_front() {
+                return Poll::Ready(Some(Ok(event)));
+            }
+
+            if let Some(fut) = self.watching.as_mut() {
+                let (w, res) = ready!(fut.poll_unpin(cx));
+                self.watching = None;
+                self.watcher = Some(w);
+
+                match res {
+                    Ok(Some(events)) => self.buf = events.into(),
+                    Ok(None) => {
+                        self.watcher = None;
+                        return Poll::Ready(None);
+                    }
+                    Err(err) => return Poll::Ready(Some(Err(err))),
+                }
+                continue;
+            }
+
+            let Some(mut w) = self.watcher.take() else {
+                return Poll::Ready(None);
+            };
+            self.watching = Some(Box::pin(async move {
+                let res = w.next().await;
+                (w, res)
+            }));
+        }
+    }
+}
+
+/// The version of a file, which will be changed if the content is changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Version {
+    content_length: Option<u64>,
+    etag: Option<String>,
+    last_modified: Option<DateTime<Utc>>,
+}
+
+impl Version {
+    fn from_metadata(meta: &Metadata) -> Option<Version> {
+        let metakey = meta.metakey();
+        let has = |key| metakey.contains(key) || metakey.contains(Metakey::Complete);
+
+        let version = Version {
+            content_length: has(Metakey::ContentLength).then(|| meta.content_length()),
+            etag: if has(Metakey::Etag) {
+                meta.etag().map(|v| v.to_string())
+            } else {
+                None
+            },
+            last_modified: if has(Metakey::LastModified) {
+                meta.last_modified()
+            } else {
+                None
+            },
+        };
+
+        if version.content_length.is_none()
+            && version.etag.is_none()
+            && version.last_modified.is_none()
+        {
+            return None;
+        }
+        Some(version)
+    }
+}
+
+/// The max number of stats in flight while scanning.
+const CONCURRENT_STATS: usize = 16;
+
+/// PollWatcher lists the dir periodically, and compares the `etag`,
+/// `last_modified` and `content_length` of files to find out changes.
+///
+/// Files that list doesn't return any of them will be stat-ed.
+pub(crate) struct PollWatcher {
+    acc: FusedAccessor,
+    path: String,
+    interval: Duration,
+
+    files: BTreeMap<String, Version>,
+}
+
+impl PollWatcher {
+    pub(crate) async fn new(acc: FusedAccessor, path: &str, args: OpWatch) -> Result<Self> {
+        let files = scan(&acc, path).await?;
+
+        Ok(Self {
+            acc,
+            path: path.to_string(),
+            interval: args.poll_interval(),
+            files,
+        })
+    }
+}
+
+/// List all files under path along with their versions.
+///
+/// Versions are taken from the listed metadata, only files that list
+/// doesn't return any version for will be stat-ed.
+async fn scan(acc: &FusedAccessor, path: &str) -> Result<BTreeMap<String, Version>> {
+    let mut files = BTreeMap::new();
+    let mut unknown = vec![];
+
+    let (_, mut pager) = acc.list(path, OpList::new().with_delimiter("")).await?;
+    while let Some(entries) = pager.next().await? {
+        for entry in entries {
+            if !entry.mode().is_file() {
+                continue;
+            }
+
+            let (path, meta) = entry.into_entry().into_parts();
+            match Version::from_metadata(&meta) {
+                Some(v) => {
+                    files.insert(path, v);
+                }
+                None => unknown.push(path),
+            }
+        }
+    }
+
+    let mut stats = stream::iter(unknown)
+        .map(|path| async move {
+            match acc.stat(&path, OpStat::new()).await {
+                Ok(rp) => Ok(Version::from_metadata(&rp.into_metadata()).map(|v| (path, v))),
+                // Files could be removed while scanning.
+                Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+                Err(err) => Err(err),
+            }
+        })
+        .buffer_unordered(CONCURRENT_STATS);
+    while let Some(v) = stats.try_next().await? {
+        if let Some((path, version)) = v {
+            files.insert(path, version);
+        }
+    }
+
+    Ok(files)
+}
+
+#[async_trait]
+impl oio::Watch for PollWatcher {
+    async fn next(&mut self) -> Result<Option<Vec<WatchEvent>>> {
+        loop {
+            tokio::time::sleep(self.interval).await;
+
+            let files = scan(&self.acc, &self.path).await?;
+
+            let mut events = vec![];
+            for (path, version) in &files {
+                match self.files.get(path) {
+                    None => events.push(WatchEvent::new(WatchEventKind::Create, path)),
+                    Some(v) if v != version => {
+                        events.push(WatchEvent::new(WatchEventKind::Modify, path))
+                    }
+                    Some(_) => {}
+                }
+            }
+            for path in self.files.keys() {
+                if !files.contains_key(path) {
+                    events.push(WatchEvent::new(WatchEventKind::Delete, path));
+                }
+            }
+
+            self.files = files;
+            if !events.is_empty() {
+                return Ok(Some(events));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_poll_watch() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        op.write("dir/a", "1").await?;
+        op.write("dir/b", "1").await?;
+        op.write("other", "1").await?;
+
+        let mut watcher = op
+            .watch_with("dir/")
+            .poll_interval(Duration::from_millis(10))
+            .await?;
+
+        op.write("dir/a", "22").await?;
+        op.delete("dir/b").await?;
+        op.write("dir/c", "1").await?;
+        op.write("other", "22").await?;
+
+        let mut events = vec![];
+        while events.len() < 3 {
+            let event = watcher.next().await.expect("watcher must not be closed")?;
+            events.push((event.kind(), event.path().to_string()));
+        }
+        events.sort_by(|a, b| a.1.cmp(&b.1));
+        assert_eq!(
+            events,
+            [
+                (WatchEventKind::Modify, "dir/a".to_string()),
+                (WatchEventKind::Delete, "dir/b".to_string()),
+                (WatchEventKind::Create, "dir/c".to_string()),
+            ]
+        );
+
+        Ok(())
+    }
+}
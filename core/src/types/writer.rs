@@ -207,6 +207,50 @@ impl Writer {
     pub async fn close(&mut self) -> Result<()> {
         self.inner.close().await
     }
+
+    /// Get the checkpoint of current upload.
+    ///
+    /// The checkpoint could be persisted and passed to
+    /// [`FutureWriter::resume`](crate::operator_futures::FutureWriter::resume)
+    /// to continue this upload after failures or restarts.
+    ///
+    /// Returns `None` if the service doesn't support resume or no data has
+    /// been uploaded durably yet.
+    ///
+    /// ## Notes
+    ///
+    /// Only data that has been uploaded durably is included in the checkpoint.
+    /// Data still buffered by this writer is not, and will be lost once the
+    /// writer is dropped. Users must resume writing from
+    /// [`WriteCheckpoint::offset`] and send the source data after it again.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// #[tokio::main]
+    /// async fn resume_example(op: Operator) -> Result<()> {
+    ///     let content = vec![0; 20 * 1024 * 1024];
+    ///
+    ///     let mut w = op.writer_with("path/to/file").buffer(8 * 1024 * 1024).await?;
+    ///     w.write(content.clone()).await?;
+    ///     let checkpoint = w.checkpoint().expect("upload must be started");
+    ///     // Buffered data after `checkpoint.offset()` is lost with the writer.
+    ///     drop(w);
+    ///
+    ///     // Continue the upload in another writer from the offset of checkpoint.
+    ///     let offset = checkpoint.offset() as usize;
+    ///     let mut w = op.writer_with("path/to/file").resume(checkpoint).await?;
+    ///     w.write(content[offset..].to_vec()).await?;
+    ///     w.close().await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        self.inner.checkpoint()
+    }
 }
 
 impl AsyncWrite for Writer {
@@ -36,7 +36,8 @@ pub fn behavior_copy_tests(op: &Operator) -> Vec<Trial> {
         test_copy_target_dir,
         test_copy_self,
         test_copy_nested,
-        test_copy_overwrite
+        test_copy_overwrite,
+        test_copy_many
     )
 }
 
@@ -201,3 +202,45 @@ pub async fn test_copy_overwrite(op: Operator) -> Result<()> {
     op.delete(&target_path).await.expect("delete must succeed");
     Ok(())
 }
+
+/// Copy many pairs should copy valid ones and report errors for others.
+pub async fn test_copy_many(op: Operator) -> Result<()> {
+    let source_path = uuid::Uuid::new_v4().to_string();
+    let (source_content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&source_path, source_content.clone()).await?;
+
+    let target_path = uuid::Uuid::new_v4().to_string();
+    let not_exist = uuid::Uuid::new_v4().to_string();
+
+    let results = op
+        .copy_many(vec![
+            (source_path.clone(), target_path.clone()),
+            (source_path.clone(), source_path.clone()),
+            (not_exist.clone(), uuid::Uuid::new_v4().to_string()),
+        ])
+        .await?;
+    assert_eq!(results.len(), 3);
+
+    assert_eq!(results[0].0, source_path);
+    assert!(results[0].1.is_ok());
+    assert_eq!(
+        results[1].1.as_ref().unwrap_err().kind(),
+        ErrorKind::IsSameFile
+    );
+    assert_eq!(results[2].0, not_exist);
+    assert_eq!(
+        results[2].1.as_ref().unwrap_err().kind(),
+        ErrorKind::NotFound
+    );
+
+    let target_content = op.read(&target_path).await.expect("read must succeed");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(target_content)),
+        format!("{:x}", Sha256::digest(&source_content)),
+    );
+
+    op.delete(&source_path).await.expect("delete must succeed");
+    op.delete(&target_path).await.expect("delete must succeed");
+    Ok(())
+}
@@ -17,6 +17,8 @@
 
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::Mutex;
 
 use anyhow::Result;
 use futures::stream::FuturesUnordered;
@@ -48,7 +50,8 @@ pub fn behavior_list_tests(op: &Operator) -> Vec<Trial> {
         test_list_with_start_after,
         test_scan,
         test_scan_root,
-        test_remove_all
+        test_remove_all,
+        test_delete_with_recursive
     )
 }
 
@@ -446,3 +449,39 @@ pub async fn test_remove_all(op: Operator) -> Result<()> {
     }
     Ok(())
 }
+
+/// Delete with recursive should remove all in this path and report progress.
+pub async fn test_delete_with_recursive(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    let expected = ["x/", "x/y", "x/x/", "x/x/y", "x/x/x/y"];
+    for path in expected.iter() {
+        if path.ends_with('/') {
+            op.create_dir(&format!("{parent}/{path}")).await?;
+        } else {
+            op.write(&format!("{parent}/{path}"), "test_delete").await?;
+        }
+    }
+
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let cloned = reported.clone();
+    op.delete_with(&format!("{parent}/x/"))
+        .recursive(true)
+        .progress(move |path, result| {
+            assert!(result.is_ok(), "delete {path} should succeed");
+            cloned.lock().unwrap().push(path.to_string());
+        })
+        .await?;
+
+    assert!(reported.lock().unwrap().contains(&format!("{parent}/x/")));
+    for path in expected.iter() {
+        if path.ends_with('/') {
+            continue;
+        }
+        assert!(
+            !op.is_exist(&format!("{parent}/{path}")).await?,
+            "{parent}/{path} should be removed"
+        )
+    }
+    Ok(())
+}
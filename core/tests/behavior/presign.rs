@@ -22,6 +22,7 @@ use anyhow::Result;
 use http::header;
 use log::debug;
 use opendal::raw;
+use opendal::PostConditions;
 use reqwest::Url;
 use sha2::Digest;
 use sha2::Sha256;
@@ -35,7 +36,14 @@ pub fn behavior_presign_tests(op: &Operator) -> Vec<Trial> {
         return vec![];
     }
 
-    async_trials!(op, test_presign_write, test_presign_read, test_presign_stat)
+    async_trials!(
+        op,
+        test_presign_write,
+        test_presign_read,
+        test_presign_stat,
+        test_presign_write_multipart,
+        test_presign_post
+    )
 }
 
 /// Presign write should succeed.
@@ -135,3 +143,132 @@ pub async fn test_presign_read(op: Operator) -> Result<()> {
     op.delete(&path).await.expect("delete must succeed");
     Ok(())
 }
+
+/// Presign multipart upload should succeed.
+pub async fn test_presign_write_multipart(op: Operator) -> Result<()> {
+    if !op.info().full_capability().presign_write_multipart {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    // All parts except the last one must be at least 5 MiB.
+    let contents = vec![vec![1; 5 * 1024 * 1024], vec![2; 1024]];
+
+    // Invalid number of parts should be rejected before initiating.
+    for parts in [0, 10001] {
+        let res = op
+            .presign_multipart(&path, parts, Duration::from_secs(3600))
+            .await;
+        assert_eq!(
+            res.err().map(|e| e.kind()),
+            Some(ErrorKind::InvalidInput),
+            "parts: {parts}"
+        );
+    }
+
+    let upload = op
+        .presign_multipart(&path, contents.len(), Duration::from_secs(3600))
+        .await?;
+    assert_eq!(upload.parts().len(), contents.len());
+
+    let client = reqwest::Client::new();
+    let mut etags = vec![];
+    for (signed_req, content) in upload.parts().iter().zip(contents.clone()) {
+        let mut req = client.request(
+            signed_req.method().clone(),
+            Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
+        );
+        for (k, v) in signed_req.header() {
+            req = req.header(k, v);
+        }
+        req = req.header(header::CONTENT_LENGTH, content.len());
+        req = req.body(reqwest::Body::from(content));
+
+        let resp = req.send().await.expect("send request must succeed");
+        let etag = resp
+            .headers()
+            .get(header::ETAG)
+            .expect("etag must be returned")
+            .to_str()
+            .expect("etag must be valid")
+            .to_string();
+        etags.push(etag);
+    }
+
+    op.complete_multipart(&path, upload.upload_id(), etags)
+        .await?;
+
+    let bs = op.read(&path).await.expect("read must succeed");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(contents.concat())),
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Presign post should upload with form and enforce conditions.
+pub async fn test_presign_post(op: Operator) -> Result<()> {
+    if !op.info().full_capability().presign_post {
+        return Ok(());
+    }
+
+    let prefix = format!("{}/", uuid::Uuid::new_v4());
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    let post = op
+        .presign_post(
+            &prefix,
+            PostConditions::new().with_content_length_range(1, size as u64),
+            Duration::from_secs(3600),
+        )
+        .await?;
+    debug!("Generated post: {post:?}");
+
+    let send = |content: Vec<u8>| {
+        let boundary = uuid::Uuid::new_v4().to_string();
+        let mut body = Vec::new();
+        for (k, v) in post.fields() {
+            body.extend_from_slice(
+                format!(
+                    "--{boundary}\r\nContent-Disposition: form-data; name=\"{k}\"\r\n\r\n{v}\r\n"
+                )
+                .as_bytes(),
+            );
+        }
+        body.extend_from_slice(
+            format!("--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"test\"\r\n\r\n").as_bytes(),
+        );
+        body.extend_from_slice(&content);
+        body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+
+        reqwest::Client::new()
+            .post(post.uri().to_string())
+            .header(
+                header::CONTENT_TYPE,
+                format!("multipart/form-data; boundary={boundary}"),
+            )
+            .body(body)
+            .send()
+    };
+
+    let resp = send(content.clone())
+        .await
+        .expect("send request must succeed");
+    assert!(resp.status().is_success(), "post must succeed");
+
+    let path = format!("{prefix}test");
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), size as u64);
+
+    // Content larger than the range must be rejected.
+    let resp = send(vec![0; size + 1])
+        .await
+        .expect("send request must succeed");
+    assert!(resp.status().is_client_error(), "oversize post must fail");
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
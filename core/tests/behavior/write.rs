@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::str::FromStr;
 use std::time::Duration;
 
@@ -27,6 +28,7 @@ use futures::stream;
 use futures::AsyncReadExt;
 use futures::AsyncSeekExt;
 use futures::StreamExt;
+use futures::TryStreamExt;
 use http::StatusCode;
 use log::debug;
 use log::warn;
@@ -54,6 +56,10 @@ pub fn behavior_write_tests(op: &Operator) -> Vec<Trial> {
         test_write_with_cache_control,
         test_write_with_content_type,
         test_write_with_content_disposition,
+        test_write_with_tags,
+        test_set_tags,
+        test_write_at,
+        test_write_at_not_exist,
         test_stat_file,
         test_stat_dir,
         test_stat_with_special_chars,
@@ -62,12 +68,15 @@ pub fn behavior_write_tests(op: &Operator) -> Vec<Trial> {
         test_stat_with_if_match,
         test_stat_with_if_none_match,
         test_stat_root,
+        test_stat_many,
         test_read_full,
         test_read_range,
         test_read_large_range,
+        test_read_ranges,
         test_reader_range,
         test_reader_from,
         test_reader_tail,
+        test_reader_with_read_ahead,
         test_read_not_exist,
         test_read_with_if_match,
         test_read_with_if_none_match,
@@ -89,6 +98,8 @@ pub fn behavior_write_tests(op: &Operator) -> Vec<Trial> {
         test_writer_sink,
         test_writer_copy,
         test_writer_abort,
+        test_writer_resume,
+        test_list_and_abort_uploads,
         test_writer_futures_copy,
         test_fuzz_unsized_writer,
         test_invalid_reader_seek
@@ -217,6 +228,89 @@ pub async fn test_write_with_cache_control(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Write a single file with tags should succeed.
+pub async fn test_write_with_tags(op: Operator) -> Result<()> {
+    let cap = op.info().full_capability();
+    if !cap.write_with_tags || !cap.get_tags {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(cap);
+
+    let tags = HashMap::from([
+        ("project".to_string(), "opendal".to_string()),
+        ("tier".to_string(), "hot data".to_string()),
+    ]);
+    op.write_with(&path, content).tags(tags.clone()).await?;
+
+    assert_eq!(op.tags(&path).await?, tags);
+
+    op.delete(&path).await.expect("delete must succeed");
+
+    Ok(())
+}
+
+/// Write at offset should overwrite the given range and keep others.
+pub async fn test_write_at(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_offset {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+
+    op.write(&path, "Hello, World!").await?;
+    op.write_at(&path, 7, "OpenDAL!").await?;
+
+    let bs = op.read(&path).await?;
+    assert_eq!(bs, b"Hello, OpenDAL!");
+
+    op.delete(&path).await.expect("delete must succeed");
+
+    Ok(())
+}
+
+/// Write at offset of a not exist file should create it with zero filled.
+pub async fn test_write_at_not_exist(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_offset {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+
+    op.write_at(&path, 4, "data").await?;
+
+    let bs = op.read(&path).await?;
+    assert_eq!(bs, b"\0\0\0\0data");
+
+    op.delete(&path).await.expect("delete must succeed");
+
+    Ok(())
+}
+
+/// Set tags of a file should replace all existing tags.
+pub async fn test_set_tags(op: Operator) -> Result<()> {
+    let cap = op.info().full_capability();
+    if !cap.set_tags || !cap.get_tags {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(cap);
+    op.write(&path, content).await?;
+
+    let tags = HashMap::from([("project".to_string(), "opendal".to_string())]);
+    op.set_tags(&path, tags.clone()).await?;
+    assert_eq!(op.tags(&path).await?, tags);
+
+    op.set_tags(&path, HashMap::new()).await?;
+    assert!(op.tags(&path).await?.is_empty());
+
+    op.delete(&path).await.expect("delete must succeed");
+
+    Ok(())
+}
+
 /// Write a single file with content type should succeed.
 pub async fn test_write_with_content_type(op: Operator) -> Result<()> {
     if !op.info().full_capability().write_with_content_type {
@@ -431,6 +525,32 @@ pub async fn test_stat_root(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Stat many paths should return results in order.
+pub async fn test_stat_many(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes(op.info().full_capability());
+    op.write(&path, content).await.expect("write must succeed");
+
+    let not_exist = uuid::Uuid::new_v4().to_string();
+
+    let results = op.stat_many(vec![path.clone(), not_exist.clone()]).await?;
+    assert_eq!(results.len(), 2);
+
+    assert_eq!(results[0].0, path);
+    let meta = results[0].1.as_ref().expect("stat must succeed");
+    assert_eq!(meta.mode(), EntryMode::FILE);
+    assert_eq!(meta.content_length(), size as u64);
+
+    assert_eq!(results[1].0, not_exist);
+    assert_eq!(
+        results[1].1.as_ref().unwrap_err().kind(),
+        ErrorKind::NotFound
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
 /// Read full content should match.
 pub async fn test_read_full(op: Operator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
@@ -514,6 +634,49 @@ pub async fn test_read_large_range(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Read ranges should match the content of every range.
+pub async fn test_read_ranges(op: Operator) -> Result<()> {
+    if !op.info().full_capability().read_with_range {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let ranges: Vec<_> = (0..8)
+        .map(|_| {
+            let (offset, length) = gen_offset_length(size);
+            offset..offset + length
+        })
+        .collect();
+
+    let bs = op
+        .read_ranges_with(&path, ranges.clone())
+        .gap(1024)
+        .concurrent(4)
+        .await?;
+
+    assert_eq!(bs.len(), ranges.len());
+    for (bs, range) in bs.iter().zip(ranges) {
+        assert_eq!(
+            format!("{:x}", Sha256::digest(bs)),
+            format!(
+                "{:x}",
+                Sha256::digest(&content[range.start as usize..range.end as usize])
+            ),
+            "read content of {range:?}"
+        );
+    }
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
 /// Read range content should match.
 pub async fn test_reader_range(op: Operator) -> Result<()> {
     if !op.info().full_capability().read_with_range {
@@ -578,6 +741,39 @@ pub async fn test_reader_from(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Reader with read ahead should match content while seeking around.
+pub async fn test_reader_with_read_ahead(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let mut r = op.reader_with(&path).read_ahead(4096).await?;
+
+    for _ in 0..16 {
+        let (offset, length) = gen_offset_length(size);
+        r.seek(std::io::SeekFrom::Start(offset)).await?;
+
+        let mut bs = vec![0; length as usize];
+        r.read_exact(&mut bs).await?;
+
+        assert_eq!(
+            format!("{:x}", Sha256::digest(&bs)),
+            format!(
+                "{:x}",
+                Sha256::digest(&content[offset as usize..(offset + length) as usize])
+            ),
+            "read content at {offset}"
+        );
+    }
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
 /// Read range tail should match.
 pub async fn test_reader_tail(op: Operator) -> Result<()> {
     if !op.info().full_capability().read_with_range {
@@ -1043,6 +1239,74 @@ pub async fn test_writer_abort(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Resume writer from checkpoint should succeed.
+pub async fn test_writer_resume(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_resume {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let size = 5 * 1024 * 1024;
+    let content = gen_fixed_bytes(3 * size);
+
+    let mut w = op.writer_with(&path).buffer(size).await?;
+    w.write(content[..2 * size].to_vec()).await?;
+    w.write(content[2 * size..].to_vec()).await?;
+    let checkpoint = w.checkpoint().expect("checkpoint must exist");
+    drop(w);
+
+    let offset = checkpoint.offset() as usize;
+    assert!(offset > 0 && offset < content.len());
+
+    let mut w = op
+        .writer_with(&path)
+        .buffer(size)
+        .resume(checkpoint)
+        .await?;
+    w.write(content[offset..].to_vec()).await?;
+    w.close().await?;
+
+    let bs = op.read(&path).await?;
+    assert_eq!(bs.len(), content.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// List and abort incomplete uploads should succeed.
+pub async fn test_list_and_abort_uploads(op: Operator) -> Result<()> {
+    let cap = op.info().full_capability();
+    if !(cap.write_with_resume && cap.list_uploads && cap.abort_upload) {
+        return Ok(());
+    }
+
+    let dir = format!("{}/", uuid::Uuid::new_v4());
+    let path = format!("{dir}{}", uuid::Uuid::new_v4());
+    let size = 5 * 1024 * 1024;
+
+    let mut w = op.writer_with(&path).buffer(size).await?;
+    w.write(gen_fixed_bytes(2 * size)).await?;
+    w.write(gen_fixed_bytes(size)).await?;
+    let checkpoint = w.checkpoint().expect("checkpoint must exist");
+    drop(w);
+
+    let uploads: Vec<_> = op.list_uploads(&dir).await?.try_collect().await?;
+    assert!(uploads
+        .iter()
+        .any(|v| v.path() == path && v.upload_id() == checkpoint.upload_id()));
+
+    op.abort_upload(&path, checkpoint.upload_id()).await?;
+
+    let uploads: Vec<_> = op.list_uploads(&dir).await?.try_collect().await?;
+    assert!(uploads.is_empty(), "all uploads must be aborted");
+    Ok(())
+}
+
 /// Delete existing file should succeed.
 pub async fn test_delete_file(op: Operator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
@@ -119,6 +119,18 @@ impl ObjectStore for OpendalStore {
         Ok(Bytes::from(bs))
     }
 
+    async fn get_ranges(&self, location: &Path, ranges: &[Range<usize>]) -> Result<Vec<Bytes>> {
+        let ranges = ranges
+            .iter()
+            .map(|r| r.start as u64..r.end as u64)
+            .collect();
+
+        self.inner
+            .read_ranges(location.as_ref(), ranges)
+            .await
+            .map_err(|err| format_object_store_error(err, location.as_ref()))
+    }
+
     async fn head(&self, location: &Path) -> Result<ObjectMeta> {
         let meta = self
             .inner